@@ -406,7 +406,7 @@ async fn start_acp_session(
 
     tokio::task::spawn_local(async move {
         if let Err(e) = io_future.await {
-            eprintln!("ACP I/O error: {e}");
+            tracing::warn!("ACP I/O error: {e}");
         }
     });
 
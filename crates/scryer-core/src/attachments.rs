@@ -0,0 +1,180 @@
+//! On-disk storage for contract images.
+//!
+//! In memory (and over IPC/MCP) an image carries its base64 `data`. On disk the
+//! bytes live in a sibling `<model>.attachments/` directory and the JSON keeps
+//! only a `path` relative to it, so `.scry` files stay small and diffable.
+
+use base64::Engine;
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::Path;
+
+/// File extension for a supported image mime type.
+pub fn extension_for_mime(mime: &str) -> &'static str {
+    match mime {
+        "image/png" => "png",
+        "image/jpeg" | "image/jpg" => "jpg",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        "image/svg+xml" => "svg",
+        _ => "bin",
+    }
+}
+
+/// Mime type for a supported image file extension (case-insensitive).
+pub fn mime_for_extension(ext: &str) -> Option<&'static str> {
+    match ext.to_ascii_lowercase().as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        "svg" => Some("image/svg+xml"),
+        _ => None,
+    }
+}
+
+/// Whether `mime` is an image type attachments may carry.
+pub fn is_supported_mime(mime: &str) -> bool {
+    extension_for_mime(mime) != "bin"
+}
+
+/// FNV-1a — stable across Rust versions, unlike `DefaultHasher`, so the same
+/// bytes always map to the same file name.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for b in bytes {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Call `f` on every contract image object (`{ text, image: {...} }`).
+fn for_each_image(
+    val: &mut Value,
+    f: &mut impl FnMut(&mut Map<String, Value>) -> Result<(), String>,
+) -> Result<(), String> {
+    match val {
+        Value::Object(map) => {
+            if map.contains_key("text") {
+                if let Some(Value::Object(img)) = map.get_mut("image") {
+                    f(img)?;
+                }
+            }
+            for (_, v) in map.iter_mut() {
+                for_each_image(v, f)?;
+            }
+        }
+        Value::Array(arr) => {
+            for v in arr.iter_mut() {
+                for_each_image(v, f)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Move inline image data into `dir`, replacing `data` with a relative `path`.
+/// Returns whether anything was moved.
+pub(crate) fn externalize(val: &mut Value, dir: &Path) -> Result<bool, String> {
+    let mut moved = false;
+    for_each_image(val, &mut |img| {
+        let Some(Value::String(b64)) = img.get("data") else {
+            return Ok(());
+        };
+        if b64.is_empty() {
+            return Ok(());
+        }
+        let mime = img
+            .get("mimeType")
+            .and_then(|v| v.as_str())
+            .unwrap_or("image/png");
+        if !is_supported_mime(mime) {
+            return Err(format!(
+                "Unsupported attachment type '{}' (expected png, jpeg, gif, webp, or svg)",
+                mime
+            ));
+        }
+        let max = crate::limits::max_attachment_bytes();
+        // Reject on the encoded length first so oversized data is never decoded
+        if let Some(max) = max {
+            if b64.len() / 4 * 3 > max + 2 {
+                return Err(format!("Attachment exceeds {} byte limit", max));
+            }
+        }
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(b64)
+            .map_err(|e| format!("Invalid attachment data: {}", e))?;
+        if let Some(max) = max {
+            if bytes.len() > max {
+                return Err(format!(
+                    "Attachment is {} bytes, exceeding the {} byte limit",
+                    bytes.len(),
+                    max
+                ));
+            }
+        }
+        let file_name = format!("{:016x}.{}", fnv1a(&bytes), extension_for_mime(mime));
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        let path = dir.join(&file_name);
+        if !path.exists() {
+            fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+        }
+        img.remove("data");
+        img.insert("path".to_string(), Value::String(file_name));
+        moved = true;
+        Ok(())
+    })?;
+    Ok(moved)
+}
+
+/// Load externally stored images from `dir` back into inline `data`.
+/// Missing files leave the reference in place. Images still stored inline
+/// (files that predate external storage) are left as they are; the next
+/// write moves them out.
+pub(crate) fn inline(val: &mut Value, dir: &Path) {
+    let _ = for_each_image(val, &mut |img| {
+        if matches!(img.get("data"), Some(Value::String(s)) if !s.is_empty()) {
+            return Ok(());
+        }
+        let Some(Value::String(rel)) = img.get("path") else {
+            return Ok(());
+        };
+        // Only plain file names — never follow a path out of the attachments dir
+        if rel.contains('/') || rel.contains('\\') {
+            return Ok(());
+        }
+        if let Ok(bytes) = fs::read(dir.join(rel)) {
+            let b64 = base64::engine::general_purpose::STANDARD.encode(bytes);
+            img.remove("path");
+            img.insert("data".to_string(), Value::String(b64));
+        }
+        Ok(())
+    });
+}
+
+/// Delete files in `dir` that no image in `val` (the model as stored on disk)
+/// refers to. Returns how many were removed.
+pub(crate) fn prune(val: &Value, dir: &Path) -> Result<usize, String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(0);
+    };
+    let mut referenced = std::collections::HashSet::new();
+    let mut val = val.clone();
+    for_each_image(&mut val, &mut |img| {
+        if let Some(Value::String(rel)) = img.get("path") {
+            referenced.insert(rel.clone());
+        }
+        Ok(())
+    })?;
+    let mut removed = 0;
+    for entry in entries.filter_map(|e| e.ok()) {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if entry.path().is_file() && !referenced.contains(&name) {
+            fs::remove_file(entry.path()).map_err(|e| e.to_string())?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
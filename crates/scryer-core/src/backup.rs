@@ -0,0 +1,166 @@
+//! Zip backups of the global models directory.
+//!
+//! An archive mirrors the layout of `~/.scryer/`: `<name>.scry` (or
+//! `<name>.scry.gz`) at the top level, optionally `<name>.baseline.scry`,
+//! and attachment files under `<name>.attachments/`. Project-local models
+//! live in their repos and are not included.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::models_dir;
+
+/// Outcome of [`restore_from`].
+#[derive(Debug, Default, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreReport {
+    /// Models written to the models directory.
+    pub restored: Vec<String>,
+    /// Models left alone because a model with the same name already exists.
+    pub skipped: Vec<String>,
+}
+
+/// The model an archive entry belongs to, or `None` for anything that isn't
+/// a model file, baseline, or attachment.
+fn owner_of(entry: &str) -> Option<&str> {
+    let name = match entry.split_once('/') {
+        Some((dir, file)) => {
+            if file.is_empty() || file.starts_with('.') || file.contains(['/', '\\']) {
+                return None;
+            }
+            dir.strip_suffix(".attachments")?
+        }
+        None => {
+            let stem = entry
+                .strip_suffix(".scry.gz")
+                .or_else(|| entry.strip_suffix(".scry"))?;
+            stem.strip_suffix(".baseline").unwrap_or(stem)
+        }
+    };
+    (!name.is_empty() && !name.starts_with('.') && !name.contains('\\')).then_some(name)
+}
+
+/// Zip every global model (with its attachments) into `path`. Baselines are
+/// included only when `include_baselines` is set. Returns the number of
+/// models archived.
+pub fn backup_to(path: &Path, include_baselines: bool) -> Result<usize, String> {
+    let dir = models_dir();
+    let file = fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut entries: Vec<_> = fs::read_dir(&dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(|e| e.ok())
+        .collect();
+    entries.sort_by_key(|e| e.file_name());
+
+    let mut count = 0;
+    for entry in entries {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let path = entry.path();
+        if path.is_dir() {
+            if owner_of(&format!("{}/_", file_name)).is_none() {
+                continue;
+            }
+            let mut files: Vec<_> = fs::read_dir(&path)
+                .map_err(|e| e.to_string())?
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().is_file())
+                .collect();
+            files.sort_by_key(|e| e.file_name());
+            for f in files {
+                let rel = format!("{}/{}", file_name, f.file_name().to_string_lossy());
+                let bytes = fs::read(f.path()).map_err(|e| e.to_string())?;
+                zip.start_file(rel, options).map_err(|e| e.to_string())?;
+                zip.write_all(&bytes).map_err(|e| e.to_string())?;
+            }
+            continue;
+        }
+        if owner_of(&file_name).is_none() {
+            continue;
+        }
+        let is_baseline = file_name.ends_with(".baseline.scry");
+        if is_baseline && !include_baselines {
+            continue;
+        }
+        let bytes = fs::read(&path).map_err(|e| e.to_string())?;
+        zip.start_file(file_name.as_str(), options)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(&bytes).map_err(|e| e.to_string())?;
+        if !is_baseline {
+            count += 1;
+        }
+    }
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(count)
+}
+
+/// Unpack a backup made by [`backup_to`] into the models directory. Models
+/// whose name is already taken are skipped unless `overwrite` is set, so the
+/// caller can ask the user and retry.
+pub fn restore_from(path: &Path, overwrite: bool) -> Result<RestoreReport, String> {
+    let dir = models_dir();
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let file = fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("Not a valid backup: {}", e))?;
+
+    let names: Vec<String> = archive.file_names().map(|n| n.to_string()).collect();
+    let mut report = RestoreReport::default();
+    let mut skipped: HashSet<String> = HashSet::new();
+    for name in &names {
+        let Some(model) = crate::model_file_stem(name) else {
+            continue;
+        };
+        if owner_of(name).is_none() {
+            continue;
+        }
+        let existing = crate::ModelRef::Global(model.to_string()).stored_path();
+        if !overwrite && existing.exists() {
+            skipped.insert(model.to_string());
+            report.skipped.push(model.to_string());
+        } else {
+            report.restored.push(model.to_string());
+        }
+    }
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(|e| e.to_string())?;
+        if entry.is_dir() {
+            continue;
+        }
+        let name = entry.name().to_string();
+        let Some(owner) = owner_of(&name) else {
+            continue;
+        };
+        if skipped.contains(owner) {
+            continue;
+        }
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+        let target = dir.join(&name);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(&target, bytes).map_err(|e| e.to_string())?;
+        // Don't leave the model in the other format shadowing the restored one
+        if let Some(model) = crate::model_file_stem(&name) {
+            let r = crate::ModelRef::Global(model.to_string());
+            let other = if target == r.gz_path() {
+                r.model_path()
+            } else {
+                r.gz_path()
+            };
+            let _ = fs::remove_file(other);
+        }
+    }
+
+    report.restored.sort();
+    report.skipped.sort();
+    Ok(report)
+}
@@ -0,0 +1,257 @@
+//! Compact text form of a model: one line per node and edge, flows as an
+//! indented step outline. Used for LLM prompts and `get_model`'s compact mode,
+//! where structure matters more than every field.
+
+use crate::{C4Kind, C4ModelData, Flow, FlowStep};
+use std::collections::{HashMap, VecDeque};
+
+fn name_of<'a>(id: &'a str, model: &'a C4ModelData) -> &'a str {
+    model
+        .nodes
+        .iter()
+        .find(|n| n.id == id)
+        .map(|n| n.data.name.as_str())
+        .unwrap_or(id)
+}
+
+/// Convert a C4 model to a compact text representation for LLM consumption.
+pub fn serialize_diagram(model: &C4ModelData) -> String {
+    let mut out = String::with_capacity(2048);
+
+    out.push_str("NODES:\n");
+    for node in &model.nodes {
+        let d = &node.data;
+        let prefix = match d.kind {
+            C4Kind::Person => "[P]",
+            C4Kind::System if d.external.unwrap_or(false) => "[S!]",
+            C4Kind::System => "[S]",
+            C4Kind::Container => "[C]",
+            C4Kind::Component => "[K]",
+            C4Kind::Operation => "[M]",
+            C4Kind::Process => "[Pr]",
+            C4Kind::Model => "[Md]",
+        };
+
+        out.push_str(prefix);
+        out.push(' ');
+        out.push_str(&node.id);
+        out.push_str(" \"");
+        out.push_str(&d.name);
+        out.push_str("\" (");
+        out.push_str(kind_str(&d.kind));
+        if d.external.unwrap_or(false) {
+            out.push_str(",external");
+        }
+        if let Some(pid) = &node.parent_id {
+            out.push_str(",parent=");
+            out.push_str(name_of(pid, model));
+        }
+        out.push(')');
+        if let Some(tech) = &d.technology {
+            if !tech.is_empty() {
+                out.push_str(" tech=");
+                out.push_str(tech);
+            }
+        }
+        if let Some(ref status) = d.status {
+            out.push_str(" status=");
+            out.push_str(status.as_str());
+        }
+        if !d.tags.is_empty() {
+            out.push_str(" tags=");
+            out.push_str(&d.tags.join(","));
+        }
+        if !d.description.is_empty() {
+            out.push_str(" | \"");
+            // Truncate long descriptions to 80 characters (not bytes)
+            match d.description.char_indices().nth(80) {
+                Some((cut, _)) => {
+                    out.push_str(&d.description[..cut]);
+                    out.push_str("...");
+                }
+                None => out.push_str(&d.description),
+            }
+            out.push('"');
+        }
+        out.push('\n');
+    }
+
+    out.push_str("EDGES:\n");
+    for edge in &model.edges {
+        let label = edge
+            .data
+            .as_ref()
+            .map(|d| d.label.as_str())
+            .unwrap_or("uses");
+        let tech = edge.data.as_ref().and_then(|d| d.method.as_deref());
+        let is_async = edge
+            .data
+            .as_ref()
+            .is_some_and(|d| d.async_.unwrap_or(false));
+
+        out.push_str(&edge.source);
+        out.push_str(" \"");
+        out.push_str(name_of(&edge.source, model));
+        out.push_str("\" --[");
+        out.push_str(label);
+        if let Some(t) = tech {
+            out.push('/');
+            out.push_str(t);
+        }
+        if is_async {
+            out.push_str(" async");
+        }
+        out.push_str("]--> ");
+        out.push_str(&edge.target);
+        out.push_str(" \"");
+        out.push_str(name_of(&edge.target, model));
+        out.push('"');
+        out.push('\n');
+    }
+
+    if !model.flows.is_empty() {
+        out.push_str("FLOWS:\n");
+        for flow in &model.flows {
+            serialize_flow(&mut out, flow);
+        }
+    }
+
+    out
+}
+
+/// Steps are rendered in array order; decision points nest their branches
+/// beneath the step that owns them.
+fn serialize_flow(out: &mut String, flow: &Flow) {
+    out.push_str("  flow \"");
+    out.push_str(&flow.name);
+    out.push_str("\":\n");
+    if flow.transitions.is_empty() {
+        serialize_steps(out, &flow.steps, 4);
+    } else {
+        serialize_transitions(out, flow);
+    }
+}
+
+/// Old-format flows order their flat steps through `transitions`. Walk them
+/// topologically so the LLM still sees sequence and fork points.
+fn serialize_transitions(out: &mut String, flow: &Flow) {
+    let mut in_degree: HashMap<&str, usize> =
+        flow.steps.iter().map(|s| (s.id.as_str(), 0)).collect();
+    for t in &flow.transitions {
+        if let Some(d) = in_degree.get_mut(t.target.as_str()) {
+            *d += 1;
+        }
+    }
+
+    let mut queue: VecDeque<&str> = flow
+        .steps
+        .iter()
+        .map(|s| s.id.as_str())
+        .filter(|id| in_degree[id] == 0)
+        .collect();
+    let mut order: Vec<&str> = Vec::new();
+    while let Some(id) = queue.pop_front() {
+        order.push(id);
+        for t in flow.transitions.iter().filter(|t| t.source == id) {
+            if let Some(d) = in_degree.get_mut(t.target.as_str()) {
+                *d -= 1;
+                if *d == 0 {
+                    queue.push_back(t.target.as_str());
+                }
+            }
+        }
+    }
+    // Steps caught in a cycle keep their array order at the end
+    for step in &flow.steps {
+        if !order.contains(&step.id.as_str()) {
+            order.push(step.id.as_str());
+        }
+    }
+
+    for id in order {
+        let Some(step) = flow.steps.iter().find(|s| s.id == id) else {
+            continue;
+        };
+        out.push_str("    [");
+        out.push_str(&step.id);
+        out.push_str("] ");
+        let text = step.description.as_deref().or(step.label.as_deref());
+        out.push_str(text.unwrap_or("(empty)"));
+        out.push('\n');
+
+        let outgoing: Vec<_> = flow.transitions.iter().filter(|t| t.source == id).collect();
+        let pad = if outgoing.len() > 1 {
+            out.push_str("      fork:\n");
+            "        "
+        } else {
+            "      "
+        };
+        for t in outgoing {
+            out.push_str(pad);
+            out.push_str("-> [");
+            out.push_str(&t.target);
+            out.push(']');
+            if let Some(label) = t.label.as_deref().filter(|l| !l.is_empty()) {
+                out.push_str(" \"");
+                out.push_str(label);
+                out.push('"');
+            }
+            out.push('\n');
+        }
+    }
+}
+
+fn serialize_steps(out: &mut String, steps: &[FlowStep], indent: usize) {
+    let pad: String = " ".repeat(indent);
+    for step in steps {
+        out.push_str(&pad);
+        out.push('[');
+        out.push_str(&step.id);
+        out.push_str("] ");
+        // Old files may carry only `label` until migrated to `description`
+        let text = step.description.as_deref().or(step.label.as_deref());
+        out.push_str(text.unwrap_or("(empty)"));
+        out.push('\n');
+        for branch in &step.branches {
+            out.push_str(&pad);
+            out.push_str("  branch");
+            if !branch.condition.is_empty() {
+                out.push_str(" \"");
+                out.push_str(&branch.condition);
+                out.push('"');
+            }
+            out.push_str(":\n");
+            serialize_steps(out, &branch.steps, indent + 4);
+        }
+    }
+}
+
+fn kind_str(kind: &C4Kind) -> &'static str {
+    match kind {
+        C4Kind::Person => "person",
+        C4Kind::System => "system",
+        C4Kind::Container => "container",
+        C4Kind::Component => "component",
+        C4Kind::Operation => "operation",
+        C4Kind::Process => "process",
+        C4Kind::Model => "model",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_multibyte_description_is_cut_on_a_char_boundary() {
+        let description = format!("{}é and more", "a".repeat(79));
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [{ "id": "node-1", "data": { "name": "API", "kind": "system", "description": description } }],
+            "edges": [],
+        }))
+        .unwrap();
+        let text = serialize_diagram(&model);
+        let expected = format!("\"{}é...\"", "a".repeat(79));
+        assert!(text.contains(&expected), "{text}");
+    }
+}
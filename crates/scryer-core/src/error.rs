@@ -0,0 +1,64 @@
+//! Typed errors for model storage and validation.
+//!
+//! `Display` reproduces the plain-string messages these functions returned
+//! before, and `From<ScryerError> for String` lets `?` keep working in
+//! callers that still use `Result<_, String>`.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ScryerError {
+    /// The model (or settings) file doesn't exist.
+    NotFound(String),
+    /// Any other filesystem failure.
+    Io(std::io::Error),
+    /// A file exists but isn't valid JSON for what it should hold.
+    Parse(String),
+    /// The data is well-formed but breaks a model rule (e.g. a parent cycle).
+    Validation(String),
+    /// The file changed on disk since the caller read it.
+    Conflict(String),
+}
+
+impl fmt::Display for ScryerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScryerError::NotFound(msg)
+            | ScryerError::Parse(msg)
+            | ScryerError::Validation(msg)
+            | ScryerError::Conflict(msg) => f.write_str(msg),
+            ScryerError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ScryerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScryerError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ScryerError {
+    fn from(e: std::io::Error) -> Self {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            ScryerError::NotFound(e.to_string())
+        } else {
+            ScryerError::Io(e)
+        }
+    }
+}
+
+impl From<serde_json::Error> for ScryerError {
+    fn from(e: serde_json::Error) -> Self {
+        ScryerError::Parse(e.to_string())
+    }
+}
+
+impl From<ScryerError> for String {
+    fn from(e: ScryerError) -> Self {
+        e.to_string()
+    }
+}
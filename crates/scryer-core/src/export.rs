@@ -0,0 +1,401 @@
+//! Text exports of a model for tools outside Scryer.
+//!
+//! DOT output is meant for GraphViz (`dot -Tsvg`) and anything else that
+//! reads it; layout is left entirely to the renderer. The CSV exports are a
+//! flat inventory for spreadsheets.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::render::{kind_name, level_view, status_color};
+use crate::{C4Kind, C4ModelData, C4Shape, StartingLevel, Status};
+
+/// Quote `s` as a DOT string literal.
+fn dot_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn dot_shape(kind: &C4Kind, shape: Option<&C4Shape>) -> &'static str {
+    match shape {
+        Some(C4Shape::Person) => "ellipse",
+        Some(C4Shape::Cylinder) => "cylinder",
+        Some(C4Shape::Hexagon) => "hexagon",
+        Some(C4Shape::Trapezoid) => "trapezium",
+        Some(C4Shape::Bucket) => "invtrapezium",
+        Some(C4Shape::Pipe) => "cds",
+        Some(C4Shape::Rectangle) => "box",
+        None if matches!(kind, C4Kind::Person) => "ellipse",
+        None => "box",
+    }
+}
+
+/// A cluster is either an expanded parent node or a group.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Cluster<'a> {
+    Node(&'a str),
+    Group(&'a str),
+}
+
+/// Render the model at `level` as a GraphViz `digraph`. Visible nodes are
+/// filled with their status color; parents whose children are shown become
+/// clusters, and so do groups, nested the same way they are on the canvas.
+pub fn export_dot(model: &C4ModelData, level: &StartingLevel) -> String {
+    let view = level_view(model, level);
+    let by_id: HashMap<&str, _> = model.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let groups: HashMap<&str, _> = model.groups.iter().map(|g| (g.id.as_str(), g)).collect();
+    let group_of: HashMap<&str, &str> = model
+        .groups
+        .iter()
+        .flat_map(|g| {
+            g.member_ids
+                .iter()
+                .map(move |m| (m.as_str(), g.id.as_str()))
+        })
+        .collect();
+
+    // The cluster a node sits in: its group if it has one, otherwise the
+    // cluster of its parent.
+    let node_home = |id: &str| -> Option<Cluster> {
+        if let Some(g) = group_of.get(id) {
+            return Some(Cluster::Group(*g));
+        }
+        let parent = by_id.get(id)?.parent_id.as_deref()?;
+        by_id.contains_key(parent).then_some(Cluster::Node(parent))
+    };
+    // A group nests in its parent group, or else in the parent its members
+    // share (groups spanning several parents stay at the top level).
+    let group_home = |id: &str| -> Option<Cluster> {
+        let group = groups.get(id)?;
+        if let Some(parent) = group.parent_group_id.as_deref() {
+            if groups.contains_key(parent) {
+                return Some(Cluster::Group(parent));
+            }
+        }
+        let mut parents = group
+            .member_ids
+            .iter()
+            .filter_map(|m| by_id.get(m.as_str()))
+            .map(|n| n.parent_id.as_deref());
+        let first = parents.next()??;
+        (parents.all(|p| p == Some(first)) && by_id.contains_key(first))
+            .then_some(Cluster::Node(first))
+    };
+    let home = |c: &Cluster| match c {
+        Cluster::Node(id) => node_home(*id),
+        Cluster::Group(id) => group_home(*id),
+    };
+
+    // Walk up from every visible node, registering each cluster under its
+    // own home. The hop cap keeps a corrupt group cycle from looping.
+    let mut children: HashMap<Option<Cluster>, Vec<Cluster>> = HashMap::new();
+    let mut members: HashMap<Option<Cluster>, Vec<&str>> = HashMap::new();
+    let mut seen: HashSet<Cluster> = HashSet::new();
+    for node in &view.nodes {
+        let mut current = node_home(node.id.as_str());
+        members.entry(current.clone()).or_default().push(&node.id);
+        let mut hops = 0;
+        while let Some(cluster) = current {
+            if !seen.insert(cluster.clone()) || hops > by_id.len() + groups.len() {
+                break;
+            }
+            let parent = home(&cluster);
+            children.entry(parent.clone()).or_default().push(cluster);
+            current = parent;
+            hops += 1;
+        }
+    }
+
+    let mut out = String::with_capacity(4096);
+    out.push_str("digraph model {\n");
+    out.push_str("  rankdir=TB;\n");
+    out.push_str("  node [style=\"filled\", fontname=\"Helvetica\", fontcolor=\"white\"];\n");
+    out.push_str("  edge [fontname=\"Helvetica\", fontsize=10, color=\"#52525b\"];\n");
+
+    let mut stack = vec![(None, 1usize, false)];
+    while let Some((cluster, depth, closing)) = stack.pop() {
+        let pad = "  ".repeat(depth);
+        if closing {
+            out.push_str(&format!("{}}}\n", "  ".repeat(depth - 1)));
+            continue;
+        }
+        if let Some(c) = &cluster {
+            let (key, label, color) = match c {
+                Cluster::Node(id) => {
+                    let n = by_id[id];
+                    let label = format!("{}\n[{}]", n.data.name, kind_name(&n.data.kind));
+                    (format!("node_{id}"), label, "#a1a1aa".to_string())
+                }
+                Cluster::Group(id) => {
+                    let g = groups[id];
+                    let color = g.color.clone().unwrap_or_else(|| "#a1a1aa".to_string());
+                    (format!("group_{id}"), g.name.clone(), color)
+                }
+            };
+            out.push_str(&format!(
+                "{}subgraph {} {{\n",
+                "  ".repeat(depth - 1),
+                dot_str(&format!("cluster_{key}"))
+            ));
+            out.push_str(&format!(
+                "{pad}label={}; style=\"dashed,rounded\"; color={}; fontname=\"Helvetica\";\n",
+                dot_str(&label),
+                dot_str(&color)
+            ));
+        }
+        for id in members.get(&cluster).into_iter().flatten() {
+            let d = &by_id[id].data;
+            let mut label = format!("{}\n[{}", d.name, kind_name(&d.kind));
+            if let Some(tech) = d.technology.as_deref().filter(|t| !t.is_empty()) {
+                label.push_str(&format!(": {tech}"));
+            }
+            label.push(']');
+            let mut style = "filled".to_string();
+            if d.external.unwrap_or(false) {
+                style.push_str(",dashed");
+            }
+            out.push_str(&format!(
+                "{pad}{} [label={}, shape={}, style={}, fillcolor={}];\n",
+                dot_str(id),
+                dot_str(&label),
+                dot_shape(&d.kind, d.shape.as_ref()),
+                dot_str(&style),
+                dot_str(status_color(d.status.as_ref()))
+            ));
+        }
+        if cluster.is_some() {
+            stack.push((cluster.clone(), depth, true));
+        }
+        for child in children.get(&cluster).into_iter().flatten().rev() {
+            stack.push((Some(child.clone()), depth + 1, false));
+        }
+    }
+
+    for (s, t, label) in &view.edges {
+        if label.is_empty() {
+            out.push_str(&format!("  {} -> {};\n", dot_str(s), dot_str(t)));
+        } else {
+            out.push_str(&format!(
+                "  {} -> {} [label={}];\n",
+                dot_str(s),
+                dot_str(t),
+                dot_str(label)
+            ));
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break.
+/// Fields that a spreadsheet would read as a formula (leading `=`, `+`, `-`,
+/// or `@`) get a `'` prefix so opening the file can't run anything.
+fn csv_field(s: &str) -> String {
+    let s = if s.starts_with(['=', '+', '-', '@']) {
+        format!("'{s}")
+    } else {
+        s.to_string()
+    };
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s
+    }
+}
+
+fn csv_row(fields: &[&str]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|f| csv_field(f))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// One row per node: id, name, kind, parent (ID), technology, status, owner,
+/// tags (semicolon-separated), and description.
+pub fn export_nodes_csv(model: &C4ModelData) -> String {
+    let mut out = csv_row(&[
+        "id",
+        "name",
+        "kind",
+        "parent",
+        "technology",
+        "status",
+        "owner",
+        "tags",
+        "description",
+    ]);
+    for node in &model.nodes {
+        let d = &node.data;
+        let kind = kind_name(&d.kind).to_lowercase();
+        out.push_str(&csv_row(&[
+            &node.id,
+            &d.name,
+            &kind,
+            node.parent_id.as_deref().unwrap_or(""),
+            d.technology.as_deref().unwrap_or(""),
+            d.status.as_ref().map_or("", Status::as_str),
+            d.owner.as_deref().unwrap_or(""),
+            &d.tags.join(";"),
+            &d.description,
+        ]));
+    }
+    out
+}
+
+/// One row per edge: id, source, target, label, and method.
+pub fn export_edges_csv(model: &C4ModelData) -> String {
+    let mut out = csv_row(&["id", "source", "target", "label", "method"]);
+    for edge in &model.edges {
+        let data = edge.data.as_ref();
+        out.push_str(&csv_row(&[
+            &edge.id,
+            &edge.source,
+            &edge.target,
+            data.map(|d| d.label.as_str()).unwrap_or(""),
+            data.and_then(|d| d.method.as_deref()).unwrap_or(""),
+        ]));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shop() -> C4ModelData {
+        serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "user", "data": { "name": "User", "kind": "person" } },
+                { "id": "shop", "data": { "name": "Shop", "kind": "system" } },
+                { "id": "web", "parentId": "shop",
+                  "data": { "name": "Web", "kind": "container", "status": "proposed" } },
+                { "id": "db", "parentId": "shop", "data": { "name": "DB", "kind": "container" } },
+                { "id": "bank", "data": { "name": "Bank", "kind": "system", "external": true } }
+            ],
+            "edges": [
+                { "id": "e1", "source": "user", "target": "web", "data": { "label": "Uses" } },
+                { "id": "e2", "source": "web", "target": "db", "data": { "label": "Reads \"orders\"" } },
+                { "id": "e3", "source": "web", "target": "bank" }
+            ],
+            "groups": [
+                { "id": "group-1", "name": "Fly", "memberIds": ["web"], "color": "#22c55e" }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn dot_str_escapes_quotes_and_newlines() {
+        assert_eq!(dot_str("plain"), "\"plain\"");
+        assert_eq!(dot_str("say \"hi\"\r\nnow"), "\"say \\\"hi\\\"\\nnow\"");
+        assert_eq!(dot_str("C:\\dir"), "\"C:\\\\dir\"");
+    }
+
+    #[test]
+    fn expanded_parents_become_clusters() {
+        let dot = export_dot(&shop(), &StartingLevel::Container);
+        assert!(dot.starts_with("digraph model {\n"), "{dot}");
+        assert!(dot.ends_with("}\n"), "{dot}");
+        assert!(
+            dot.contains("\n  subgraph \"cluster_node_shop\" {\n"),
+            "{dot}"
+        );
+        assert!(dot.contains("label=\"Shop\\n[System]\""), "{dot}");
+        // Top-level nodes sit outside any cluster, children inside it.
+        assert!(
+            dot.contains("\n  \"user\" [label=\"User\\n[Person]\", shape=ellipse"),
+            "{dot}"
+        );
+        assert!(
+            dot.contains("\n    \"db\" [label=\"DB\\n[Container]\""),
+            "{dot}"
+        );
+        assert!(dot.contains("style=\"filled,dashed\""), "{dot}");
+        assert!(!dot.contains("\"shop\" [label="), "{dot}");
+    }
+
+    #[test]
+    fn groups_nest_inside_their_parent_cluster() {
+        let dot = export_dot(&shop(), &StartingLevel::Container);
+        let parent = dot.find("subgraph \"cluster_node_shop\"").unwrap();
+        let group = dot
+            .find("\n    subgraph \"cluster_group_group-1\" {\n")
+            .unwrap();
+        assert!(parent < group, "{dot}");
+        assert!(
+            dot.contains("label=\"Fly\"; style=\"dashed,rounded\"; color=\"#22c55e\";"),
+            "{dot}"
+        );
+        let web = "\n      \"web\" [label=\"Web\\n[Container]\", shape=box, style=\"filled\"";
+        assert!(dot.contains(web), "{dot}");
+        assert!(dot.contains("fillcolor=\"#3b82f6\"];"), "{dot}");
+    }
+
+    #[test]
+    fn edges_carry_escaped_labels() {
+        let dot = export_dot(&shop(), &StartingLevel::Container);
+        assert!(
+            dot.contains("\n  \"user\" -> \"web\" [label=\"Uses\"];\n"),
+            "{dot}"
+        );
+        assert!(
+            dot.contains("\n  \"web\" -> \"db\" [label=\"Reads \\\"orders\\\"\"];\n"),
+            "{dot}"
+        );
+        assert!(dot.contains("\n  \"web\" -> \"bank\";\n"), "{dot}");
+    }
+
+    #[test]
+    fn level_hides_children_and_lifts_edges() {
+        let dot = export_dot(&shop(), &StartingLevel::System);
+        assert!(!dot.contains("subgraph"), "{dot}");
+        assert!(!dot.contains("\"web\""), "{dot}");
+        assert!(
+            dot.contains("\n  \"shop\" [label=\"Shop\\n[System]\""),
+            "{dot}"
+        );
+        assert!(
+            dot.contains("\n  \"user\" -> \"shop\" [label=\"Uses\"];\n"),
+            "{dot}"
+        );
+        assert!(dot.contains("\n  \"shop\" -> \"bank\";\n"), "{dot}");
+        // web -> db collapses onto shop itself and is dropped
+        assert!(!dot.contains("\"shop\" -> \"shop\""), "{dot}");
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_and_escaped() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(csv_row(&["a,b", "c"]), "\"a,b\",c\r\n");
+    }
+
+    #[test]
+    fn csv_fields_that_look_like_formulas_are_prefixed() {
+        assert_eq!(csv_field("=SUM(A1:A2)"), "'=SUM(A1:A2)");
+        assert_eq!(csv_field("+1"), "'+1");
+        assert_eq!(csv_field("-x"), "'-x");
+        assert_eq!(csv_field("@cmd"), "'@cmd");
+        assert_eq!(
+            csv_field("=HYPERLINK(\"x\", 1)"),
+            "\"'=HYPERLINK(\"\"x\"\", 1)\""
+        );
+        assert_eq!(csv_field("a=b"), "a=b");
+    }
+}
@@ -1,17 +1,30 @@
+pub mod attachments;
+pub mod backup;
+pub mod compact;
 pub mod drift;
+pub mod error;
+pub mod export;
+pub mod limits;
+pub mod render;
 pub mod rules;
 pub mod scan;
+pub mod search;
+
+pub use error::ScryerError;
+pub use search::{search_models, SearchHit};
 
 use serde::{Deserialize, Deserializer, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 /// Deserialize status leniently — unknown values become None instead of failing.
 fn deserialize_status_lenient<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Status>, D::Error> {
     let opt: Option<String> = Option::deserialize(deserializer)?;
     Ok(opt.and_then(|s| match s.as_str() {
         "proposed" => Some(Status::Proposed),
+        "in_progress" => Some(Status::InProgress),
         "implemented" => Some(Status::Implemented),
         "verified" => Some(Status::Verified),
         "vagrant" => Some(Status::Vagrant),
@@ -66,11 +79,26 @@ pub enum C4Shape {
 #[serde(rename_all = "camelCase")]
 pub enum Status {
     Proposed,
+    #[serde(rename = "in_progress")]
+    InProgress,
     Implemented,
     Verified,
     Vagrant,
 }
 
+impl Status {
+    /// The serialized name, e.g. "in_progress".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Proposed => "proposed",
+            Status::InProgress => "in_progress",
+            Status::Implemented => "implemented",
+            Status::Verified => "verified",
+            Status::Vagrant => "vagrant",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct Position {
     pub x: f64,
@@ -161,7 +189,12 @@ impl Contract {
 pub struct ContractImage {
     pub filename: String,
     pub mime_type: String,
+    #[serde(default)]
     pub data: String, // base64-encoded
+    /// On-disk reference into the attachments dir, set in place of `data`
+    /// when the file couldn't be loaded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -195,6 +228,12 @@ pub struct C4NodeData {
     /// Properties for Model-kind nodes (label/description pairs)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub properties: Vec<ModelProperty>,
+    /// Free-form labels orthogonal to kind and status, e.g. "pii", "hot-path"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Team or person responsible for this node
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
 }
 
 /// A node in the model. Matches ReactFlow's Node structure.
@@ -225,6 +264,10 @@ pub struct C4EdgeData {
     pub label: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub method: Option<String>,
+    /// True when the relationship is asynchronous (fire-and-forget via a
+    /// queue or event bus) rather than a synchronous call.
+    #[serde(rename = "async", default, skip_serializing_if = "Option::is_none")]
+    pub async_: Option<bool>,
 }
 
 /// An edge in the model. Matches ReactFlow's Edge structure.
@@ -264,6 +307,9 @@ pub struct ModelProperty {
     pub label: String,
     #[serde(default)]
     pub description: String,
+    /// Free-form field type, e.g. "String", "i64", "FK → User".
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -279,6 +325,9 @@ pub struct Group {
     pub parent_group_id: Option<String>,
     #[serde(default, skip_serializing_if = "Contract::is_empty")]
     pub contract: Contract,
+    /// Hex accent color, e.g. `#22c55e`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -336,16 +385,32 @@ pub struct C4ModelData {
     pub edges: Vec<C4Edge>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_level: Option<StartingLevel>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub source_map: HashMap<String, Vec<SourceLocation>>,
+    /// Node the editor opens focused on, drilled into the view that shows it.
+    /// Takes precedence over `starting_level` in the editor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starting_node: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub source_map: BTreeMap<String, Vec<SourceLocation>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_path: Option<String>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub ref_positions: HashMap<String, Position>,
+    /// Manual positions of reference nodes, keyed `"{parentId}/{refId}"`: the
+    /// node whose children are being viewed (`"root"` at the top level), then
+    /// the external node drawn as a reference in that view.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ref_positions: BTreeMap<String, Position>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub groups: Vec<Group>,
     #[serde(default, skip_serializing_if = "Vec::is_empty", alias = "scenarios")]
     pub flows: Vec<Flow>,
+    /// Per-model overrides for size limits; absent means the defaults apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<limits::ModelLimits>,
+    /// RFC 3339 timestamp of the model's first write. Maintained by `write_model_at`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// RFC 3339 timestamp of the model's most recent write. Maintained by `write_model_at`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
 }
 
 // --- Model Reference ---
@@ -386,6 +451,28 @@ impl ModelRef {
         }
     }
 
+    /// Path to the gzip-compressed model file, `.scry.gz`.
+    pub fn gz_path(&self) -> PathBuf {
+        let mut path = self.model_path().into_os_string();
+        path.push(".gz");
+        PathBuf::from(path)
+    }
+
+    /// The file currently holding the model: whichever of `.scry` and
+    /// `.scry.gz` exists, else (for a new model) the plain path. If a write
+    /// couldn't remove the old format, the newer file wins, and on a tie the
+    /// one this model is written as.
+    pub fn stored_path(&self) -> PathBuf {
+        let plain = self.model_path();
+        let gz = self.gz_path();
+        let modified = |p: &Path| fs::metadata(p).and_then(|m| m.modified()).ok();
+        match (modified(&plain), modified(&gz)) {
+            (None, Some(_)) => gz,
+            (Some(p), Some(g)) if g > p || (g == p && writes_gz(self)) => gz,
+            _ => plain,
+        }
+    }
+
     /// Path to the baseline snapshot file.
     pub fn baseline_path(&self) -> PathBuf {
         match self {
@@ -394,6 +481,14 @@ impl ModelRef {
         }
     }
 
+    /// Directory holding this model's externally stored attachments.
+    pub fn attachments_dir(&self) -> PathBuf {
+        match self {
+            ModelRef::Global(name) => models_dir().join(format!("{}.attachments", name)),
+            ModelRef::ProjectLocal(path) => path.join(".scryer").join("model.attachments"),
+        }
+    }
+
     /// Path to the implementing lock file.
     pub fn implementing_path(&self) -> PathBuf {
         match self {
@@ -480,7 +575,32 @@ pub fn set_implementing(model_name: &str, active: bool) -> Result<(), String> {
     }
 }
 
-/// List all model names (without .scry extension), sorted.
+/// The model name in a models-directory file name: `<name>.scry` or
+/// `<name>.scry.gz`. Baselines and anything else give `None`.
+pub fn model_file_stem(file_name: &str) -> Option<&str> {
+    let stem = file_name
+        .strip_suffix(".scry.gz")
+        .or_else(|| file_name.strip_suffix(".scry"))?;
+    (!stem.ends_with(".baseline")).then_some(stem)
+}
+
+/// Read a model file, decompressing it if it starts with the gzip magic
+/// bytes, whatever its extension.
+pub fn read_model_file(path: &Path) -> Result<String, ScryerError> {
+    decode_model_file(fs::read(path)?)
+}
+
+fn decode_model_file(bytes: Vec<u8>) -> Result<String, ScryerError> {
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        let mut raw = String::new();
+        std::io::Read::read_to_string(&mut flate2::read::GzDecoder::new(&bytes[..]), &mut raw)
+            .map_err(|e| ScryerError::Parse(format!("Corrupt gzip model file: {}", e)))?;
+        return Ok(raw);
+    }
+    String::from_utf8(bytes).map_err(|e| ScryerError::Parse(e.to_string()))
+}
+
+/// List all model names (without .scry / .scry.gz extension), sorted.
 pub fn list_models() -> Result<Vec<String>, String> {
     let dir = models_dir();
     if !dir.exists() {
@@ -491,12 +611,11 @@ pub fn list_models() -> Result<Vec<String>, String> {
         .filter_map(|entry| {
             let entry = entry.ok()?;
             let name = entry.file_name().to_string_lossy().to_string();
-            name.strip_suffix(".scry")
-                .filter(|n| !n.ends_with(".baseline"))
-                .map(|n| n.to_string())
+            model_file_stem(&name).map(|n| n.to_string())
         })
         .collect();
     names.sort();
+    names.dedup();
     Ok(names)
 }
 
@@ -521,15 +640,14 @@ pub fn resolve_model_for_project(project_path: &std::path::Path) -> Option<Strin
 }
 
 /// Read a model as raw JSON string (for Tauri frontend compatibility).
-pub fn read_model_raw(name: &str) -> Result<String, String> {
-    let path = models_dir().join(format!("{}.scry", name));
-    fs::read_to_string(&path).map_err(|e| e.to_string())
+pub fn read_model_raw(name: &str) -> Result<String, ScryerError> {
+    read_model_raw_at(&ModelRef::Global(name.to_string()))
 }
 
 /// Read a model as typed C4ModelData.
-pub fn read_model(name: &str) -> Result<C4ModelData, String> {
+pub fn read_model(name: &str) -> Result<C4ModelData, ScryerError> {
     let raw = read_model_raw(name)?;
-    serde_json::from_str(&raw).map_err(|e| e.to_string())
+    Ok(serde_json::from_str(&raw)?)
 }
 
 /// Write a model from raw JSON string (for Tauri frontend compatibility).
@@ -538,30 +656,24 @@ pub fn read_model(name: &str) -> Result<C4ModelData, String> {
 /// inotify event instead of truncate + write, which lets `SelfWrites`
 /// reliably suppress UI-initiated saves without a timestamp window that
 /// could accidentally suppress MCP writes.
-pub fn write_model_raw(name: &str, data: &str) -> Result<(), String> {
-    let dir = models_dir();
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
-    let tmp = dir.join(format!(".{}.scry.tmp", name));
-    let path = dir.join(format!("{}.scry", name));
-    fs::write(&tmp, data).map_err(|e| e.to_string())?;
-    fs::rename(&tmp, &path).map_err(|e| e.to_string())
+pub fn write_model_raw(name: &str, data: &str) -> Result<(), ScryerError> {
+    write_model_raw_at(&ModelRef::Global(name.to_string()), data)
 }
 
 /// Write a model from typed C4ModelData.
-pub fn write_model(name: &str, model: &C4ModelData) -> Result<(), String> {
-    let json = serde_json::to_string_pretty(model).map_err(|e| e.to_string())?;
-    write_model_raw(name, &json)
+pub fn write_model(name: &str, model: &C4ModelData) -> Result<(), ScryerError> {
+    write_model_at(&ModelRef::Global(name.to_string()), model)
 }
 
 // --- Baseline snapshots (for MCP diff) ---
 
 /// Save a baseline snapshot of a model (used by MCP to track what the AI last saw).
-pub fn save_baseline(name: &str, model: &C4ModelData) -> Result<(), String> {
+pub fn save_baseline(name: &str, model: &C4ModelData) -> Result<(), ScryerError> {
     let dir = models_dir();
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
-    let json = serde_json::to_string_pretty(model).map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir)?;
+    let json = serde_json::to_string_pretty(model)?;
     let path = dir.join(format!("{}.baseline.scry", name));
-    fs::write(&path, json).map_err(|e| e.to_string())
+    Ok(fs::write(&path, json)?)
 }
 
 /// Read the baseline snapshot for a model. Returns None if no baseline exists.
@@ -579,108 +691,361 @@ pub struct AiSettings {
     pub provider: String,
     pub api_key: String,
     pub model: String,
+    /// Command template for opening source files, e.g. `emacsclient +{line} {file}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor_command: Option<String>,
+    /// Store global models gzip-compressed as `.scry.gz`. Each model switches
+    /// format on its next write; reads handle both. Project-local models stay
+    /// plain JSON so they remain diffable in git.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub write_model_gz: bool,
 }
 
 fn settings_path() -> PathBuf {
     models_dir().join("settings.json")
 }
 
+/// Read settings, falling back to defaults on any failure. Use
+/// [`read_settings_result`] to tell a missing file from a broken one.
 pub fn read_settings() -> AiSettings {
+    read_settings_result().unwrap_or_default()
+}
+
+/// Read settings. A missing file is `Ok` with defaults; a file that exists
+/// but can't be read or parsed is an error.
+pub fn read_settings_result() -> Result<AiSettings, ScryerError> {
     let path = settings_path();
-    if !path.exists() {
-        return AiSettings::default();
-    }
-    fs::read_to_string(&path)
-        .ok()
-        .and_then(|s| serde_json::from_str(&s).ok())
-        .unwrap_or_default()
+    let raw = match fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(AiSettings::default()),
+        Err(e) => {
+            return Err(ScryerError::Io(std::io::Error::new(
+                e.kind(),
+                format!("Failed to read {}: {}", path.display(), e),
+            )))
+        }
+    };
+    serde_json::from_str(&raw)
+        .map_err(|e| ScryerError::Parse(format!("Invalid settings in {}: {}", path.display(), e)))
 }
 
-pub fn write_settings(settings: &AiSettings) -> Result<(), String> {
+pub fn write_settings(settings: &AiSettings) -> Result<(), ScryerError> {
     let dir = models_dir();
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
-    let json = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
-    fs::write(settings_path(), json).map_err(|e| e.to_string())
+    fs::create_dir_all(&dir)?;
+    let json = serde_json::to_string_pretty(settings)?;
+    Ok(fs::write(settings_path(), json)?)
+}
+
+/// Guess the provider from a model name, e.g. `claude-*` → anthropic.
+pub fn infer_provider(model: &str) -> Option<&'static str> {
+    let m = model.to_ascii_lowercase();
+    let m = m.rsplit('/').next().unwrap_or(&m);
+    if m.starts_with("gpt-")
+        || m.starts_with("o1")
+        || m.starts_with("o3")
+        || m.starts_with("o4")
+        || m.starts_with("chatgpt")
+    {
+        Some("openai")
+    } else if m.starts_with("claude") {
+        Some("anthropic")
+    } else if m.starts_with("gemini") {
+        Some("google")
+    } else if m.starts_with("mistral") || m.starts_with("codestral") || m.starts_with("magistral") {
+        Some("mistral")
+    } else if m.starts_with("deepseek") {
+        Some("deepseek")
+    } else if m.starts_with("llama")
+        || m.starts_with("qwen")
+        || m.starts_with("phi")
+        || m.starts_with("gemma")
+    {
+        Some("ollama")
+    } else {
+        None
+    }
+}
+
+impl AiSettings {
+    /// The explicit provider if set, otherwise one inferred from the model name.
+    pub fn effective_provider(&self) -> Option<&str> {
+        if self.provider.trim().is_empty() {
+            infer_provider(&self.model)
+        } else {
+            Some(self.provider.as_str())
+        }
+    }
 }
 
 pub fn ai_configured(settings: &AiSettings) -> bool {
-    !settings.provider.is_empty()
-        && !settings.model.is_empty()
-        && (settings.provider == "ollama" || !settings.api_key.is_empty())
+    let Some(provider) = settings.effective_provider() else {
+        return false;
+    };
+    let keyless = matches!(provider, "ollama" | "mock");
+    !settings.model.is_empty() && (keyless || !settings.api_key.is_empty())
 }
 
 /// Delete a model by name.
-pub fn delete_model(name: &str) -> Result<(), String> {
+pub fn delete_model(name: &str) -> Result<(), ScryerError> {
     let dir = models_dir();
-    let path = dir.join(format!("{}.scry", name));
-    if path.exists() {
-        fs::remove_file(&path).map_err(|e| e.to_string())?;
+    for path in [
+        dir.join(format!("{}.scry", name)),
+        dir.join(format!("{}.scry.gz", name)),
+    ] {
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
     }
     // Clean up baseline snapshot if present
     let baseline = dir.join(format!("{}.baseline.scry", name));
     if baseline.exists() {
         let _ = fs::remove_file(&baseline);
     }
+    let attachments = dir.join(format!("{}.attachments", name));
+    if attachments.exists() {
+        let _ = fs::remove_dir_all(&attachments);
+    }
     Ok(())
 }
 
+/// Copy a global model to a new name. Attachments are copied with it; the
+/// baseline is not, so the copy establishes its own on first read.
+pub fn copy_model(src: &str, dst: &str) -> Result<(), ScryerError> {
+    if ModelRef::Global(dst.to_string()).stored_path().exists() {
+        return Err(ScryerError::Validation(format!(
+            "Model '{}' already exists",
+            dst
+        )));
+    }
+    let raw = read_model_raw(src)?;
+    write_model_raw(dst, &raw)
+}
+
 // --- ModelRef-based Storage ---
 
 /// Ensure the `.scryer/.gitignore` exists for a project-local model directory.
 /// Only `model.scry` should be committed; transient files are ignored.
-fn ensure_project_gitignore(scryer_dir: &Path) -> Result<(), String> {
+fn ensure_project_gitignore(scryer_dir: &Path) -> Result<(), ScryerError> {
     let gitignore = scryer_dir.join(".gitignore");
     if !gitignore.exists() {
         fs::write(
             &gitignore,
             "*.baseline.scry\n.implementing\n.sync\n.tmp.*\n",
         )
-        .map_err(|e| format!("Failed to create .gitignore: {}", e))?;
+        .map_err(|e| {
+            ScryerError::Io(std::io::Error::new(
+                e.kind(),
+                format!("Failed to create .gitignore: {}", e),
+            ))
+        })?;
     }
     Ok(())
 }
 
+/// How long a write waits for a concurrent write of the same model to finish.
+const WRITE_LOCK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Take an exclusive advisory lock on `path`, retrying briefly while another
+/// writer holds it. The lock is released when the returned file is dropped.
+fn lock_for_write(path: &Path) -> Result<fs::File, ScryerError> {
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)?;
+    let start = Instant::now();
+    loop {
+        match file.try_lock() {
+            Ok(()) => return Ok(file),
+            Err(fs::TryLockError::WouldBlock) if start.elapsed() < WRITE_LOCK_TIMEOUT => {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            Err(fs::TryLockError::WouldBlock) => {
+                return Err(ScryerError::Io(std::io::Error::new(
+                    std::io::ErrorKind::WouldBlock,
+                    "Timed out waiting for another write to this model",
+                )))
+            }
+            Err(fs::TryLockError::Error(e)) => return Err(e.into()),
+        }
+    }
+}
+
 /// Read a model as raw JSON string from a ModelRef location.
-pub fn read_model_raw_at(r: &ModelRef) -> Result<String, String> {
-    fs::read_to_string(&r.model_path()).map_err(|e| e.to_string())
+/// Externally stored attachments are loaded back inline. Reading never writes:
+/// files that still embed image data move it to external storage on their
+/// next write.
+pub fn read_model_raw_at(r: &ModelRef) -> Result<String, ScryerError> {
+    Ok(read_model_raw_tagged_at(r)?.0)
+}
+
+/// [`read_model_raw_at`] together with the etag (see [`model_etag_at`]) of
+/// the exact bytes that were read, so the pair can't straddle another write.
+pub fn read_model_raw_tagged_at(r: &ModelRef) -> Result<(String, String), ScryerError> {
+    let bytes = fs::read(r.stored_path())?;
+    let etag = etag_of(&bytes);
+    let raw = decode_model_file(bytes)?;
+    if !raw.contains("\"image\"") {
+        return Ok((raw, etag));
+    }
+    let mut val: serde_json::Value = serde_json::from_str(&raw)?;
+    attachments::inline(&mut val, &r.attachments_dir());
+    Ok((serde_json::to_string_pretty(&val)?, etag))
 }
 
 /// Read a model as typed C4ModelData from a ModelRef location.
-pub fn read_model_at(r: &ModelRef) -> Result<C4ModelData, String> {
+pub fn read_model_at(r: &ModelRef) -> Result<C4ModelData, ScryerError> {
     let raw = read_model_raw_at(r)?;
-    serde_json::from_str(&raw).map_err(|e| e.to_string())
+    Ok(serde_json::from_str(&raw)?)
 }
 
 /// Write a model from raw JSON string to a ModelRef location.
 /// Uses atomic write (temp file + rename). Auto-creates `.gitignore` for project-local models.
-pub fn write_model_raw_at(r: &ModelRef, data: &str) -> Result<(), String> {
+/// With the `writeModelGz` setting on, the model is stored as `.scry.gz` and
+/// the plain file is removed (and the other way round when it's off).
+/// Concurrent writers of the same model (UI and MCP) are serialized by an
+/// advisory lock so they can't interleave on the shared temp file.
+pub fn write_model_raw_at(r: &ModelRef, data: &str) -> Result<(), ScryerError> {
+    write_model_raw_if_match_at(r, data, None).map(|_| ())
+}
+
+fn etag_of(bytes: &[u8]) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Fingerprint of the model file as stored on disk, for spotting changes
+/// made by someone else since it was read. `None` when there is no file.
+pub fn model_etag_at(r: &ModelRef) -> Option<String> {
+    fs::read(r.stored_path()).ok().map(|bytes| etag_of(&bytes))
+}
+
+/// [`model_etag_at`] for a global model by name.
+pub fn model_etag(name: &str) -> Option<String> {
+    model_etag_at(&ModelRef::Global(name.to_string()))
+}
+
+/// [`write_model_raw_at`], but refused with [`ScryerError::Conflict`] when
+/// `expected_etag` is given and no longer matches the file on disk. The
+/// check runs under the write lock, so a concurrent writer can't slip in
+/// between it and the write. Returns the etag of the file as written.
+pub fn write_model_raw_if_match_at(
+    r: &ModelRef,
+    data: &str,
+    expected_etag: Option<&str>,
+) -> Result<String, ScryerError> {
     let dir = r.dir();
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir)?;
     if r.is_project_local() {
         ensure_project_gitignore(&dir)?;
     }
-    let model_path = r.model_path();
+    let lock_name = match r {
+        ModelRef::Global(name) => format!(".{}.scry.lock", name),
+        // Matches the `.tmp.*` pattern in the project .gitignore
+        ModelRef::ProjectLocal(_) => ".tmp.model.lock".to_string(),
+    };
+    let _lock = lock_for_write(&dir.join(lock_name))?;
+    if let Some(expected) = expected_etag {
+        if model_etag_at(r).as_deref() != Some(expected) {
+            return Err(ScryerError::Conflict(
+                "Conflict: the model changed on disk since it was loaded".to_string(),
+            ));
+        }
+    }
+    let externalized;
+    let mut data = data;
+    if data.contains("\"image\"") {
+        let mut val: serde_json::Value = serde_json::from_str(data)?;
+        if attachments::externalize(&mut val, &r.attachments_dir())
+            .map_err(ScryerError::Validation)?
+        {
+            externalized = serde_json::to_string_pretty(&val)?;
+            data = &externalized;
+        }
+    }
+    let gz = writes_gz(r);
+    let (model_path, other) = if gz {
+        (r.gz_path(), r.model_path())
+    } else {
+        (r.model_path(), r.gz_path())
+    };
     let tmp_name = match r {
         ModelRef::Global(name) => format!(".{}.scry.tmp", name),
         ModelRef::ProjectLocal(_) => ".tmp.model.scry".to_string(),
     };
     let tmp = dir.join(&tmp_name);
-    fs::write(&tmp, data).map_err(|e| e.to_string())?;
-    fs::rename(&tmp, &model_path).map_err(|e| e.to_string())
+    let bytes = if gz {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        std::io::Write::write_all(&mut encoder, data.as_bytes())?;
+        encoder.finish()?
+    } else {
+        data.as_bytes().to_vec()
+    };
+    let mut file = fs::File::create(&tmp)?;
+    std::io::Write::write_all(&mut file, &bytes)?;
+    file.sync_all()?;
+    fs::rename(&tmp, &model_path)?;
+    // The write has landed; a leftover old-format file is older, so
+    // `stored_path` skips it, and the next write tries again.
+    if other.exists() {
+        let _ = fs::remove_file(&other);
+    }
+    Ok(etag_of(&bytes))
+}
+
+/// Whether `r` is written as `.scry.gz`: global models with the
+/// `writeModelGz` setting on.
+fn writes_gz(r: &ModelRef) -> bool {
+    matches!(r, ModelRef::Global(_)) && read_settings().write_model_gz
+}
+
+/// Delete attachment files the model on disk no longer refers to.
+/// Returns how many were removed.
+pub fn prune_attachments_at(r: &ModelRef) -> Result<usize, ScryerError> {
+    let raw = read_model_file(&r.stored_path())?;
+    let val: serde_json::Value = serde_json::from_str(&raw)?;
+    attachments::prune(&val, &r.attachments_dir())
+        .map_err(|e| ScryerError::Io(std::io::Error::other(e)))
 }
 
 /// Write a model from typed C4ModelData to a ModelRef location.
-pub fn write_model_at(r: &ModelRef, model: &C4ModelData) -> Result<(), String> {
-    let json = serde_json::to_string_pretty(model).map_err(|e| e.to_string())?;
+/// Stamps `updated_at` with the current time and keeps `created_at` — taken
+/// from the model itself, else from the file on disk, else now. Groups are
+/// normalized first (see [`normalize_groups`]).
+pub fn write_model_at(r: &ModelRef, model: &C4ModelData) -> Result<(), ScryerError> {
+    validate_no_parent_cycles(&model.nodes)?;
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let mut model = model.clone();
+    normalize_groups(&mut model);
+    model.created_at = model
+        .created_at
+        .take()
+        .or_else(|| read_created_at(r))
+        .or_else(|| Some(now.clone()));
+    model.updated_at = Some(now);
+    let json = serde_json::to_string_pretty(&model)?;
     write_model_raw_at(r, &json)
 }
 
+/// Read just the `createdAt` stamp of the model currently on disk, if any.
+fn read_created_at(r: &ModelRef) -> Option<String> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Stamp {
+        created_at: Option<String>,
+    }
+    let raw = read_model_file(&r.stored_path()).ok()?;
+    serde_json::from_str::<Stamp>(&raw).ok()?.created_at
+}
+
 /// Save a baseline snapshot at a ModelRef location.
-pub fn save_baseline_at(r: &ModelRef, model: &C4ModelData) -> Result<(), String> {
+pub fn save_baseline_at(r: &ModelRef, model: &C4ModelData) -> Result<(), ScryerError> {
     let dir = r.dir();
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
-    let json = serde_json::to_string_pretty(model).map_err(|e| e.to_string())?;
-    fs::write(&r.baseline_path(), json).map_err(|e| e.to_string())
+    fs::create_dir_all(&dir)?;
+    let json = serde_json::to_string_pretty(model)?;
+    Ok(fs::write(&r.baseline_path(), json)?)
 }
 
 /// Read the baseline snapshot at a ModelRef location.
@@ -709,10 +1074,11 @@ pub fn set_implementing_at(r: &ModelRef, active: bool) -> Result<(), String> {
 }
 
 /// Delete a model at a ModelRef location (model file + baseline).
-pub fn delete_model_at(r: &ModelRef) -> Result<(), String> {
-    let model_path = r.model_path();
-    if model_path.exists() {
-        fs::remove_file(&model_path).map_err(|e| e.to_string())?;
+pub fn delete_model_at(r: &ModelRef) -> Result<(), ScryerError> {
+    for model_path in [r.model_path(), r.gz_path()] {
+        if model_path.exists() {
+            fs::remove_file(&model_path)?;
+        }
     }
     let baseline = r.baseline_path();
     if baseline.exists() {
@@ -723,6 +1089,10 @@ pub fn delete_model_at(r: &ModelRef) -> Result<(), String> {
     if imp.exists() {
         let _ = fs::remove_file(&imp);
     }
+    let attachments = r.attachments_dir();
+    if attachments.exists() {
+        let _ = fs::remove_dir_all(&attachments);
+    }
     Ok(())
 }
 
@@ -757,7 +1127,7 @@ pub fn registered_projects() -> Vec<PathBuf> {
     let all: Vec<PathBuf> = serde_json::from_str(&raw).unwrap_or_default();
     let valid: Vec<PathBuf> = all
         .into_iter()
-        .filter(|p| p.join(".scryer").join("model.scry").exists())
+        .filter(|p| ModelRef::ProjectLocal(p.clone()).stored_path().exists())
         .collect();
     // Lazily prune invalid entries
     if let Ok(json) = serde_json::to_string_pretty(&valid) {
@@ -766,6 +1136,53 @@ pub fn registered_projects() -> Vec<PathBuf> {
     valid
 }
 
+// --- Recent Models ---
+
+const MAX_RECENT_MODELS: usize = 10;
+
+/// A model the desktop app opened, most recent first in [`recent_models`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentModel {
+    /// Model ref string, as passed to [`ModelRef::parse`].
+    pub name: String,
+    /// RFC 3339 timestamp of the last open.
+    pub opened_at: String,
+}
+
+fn recent_models_path() -> PathBuf {
+    models_dir().join("recent.json")
+}
+
+/// Recently opened models, most recent first, pruning any that no longer exist.
+pub fn recent_models() -> Vec<RecentModel> {
+    let raw = match fs::read_to_string(recent_models_path()) {
+        Ok(s) => s,
+        Err(_) => return vec![],
+    };
+    let all: Vec<RecentModel> = serde_json::from_str(&raw).unwrap_or_default();
+    all.into_iter()
+        .filter(|r| ModelRef::parse(&r.name).stored_path().exists())
+        .collect()
+}
+
+/// Move `name` to the front of the recent list, stamped with the current time.
+pub fn touch_recent_model(name: &str) -> Result<(), String> {
+    let mut recent = recent_models();
+    recent.retain(|r| r.name != name);
+    recent.insert(
+        0,
+        RecentModel {
+            name: name.to_string(),
+            opened_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        },
+    );
+    recent.truncate(MAX_RECENT_MODELS);
+    let json = serde_json::to_string_pretty(&recent).map_err(|e| e.to_string())?;
+    fs::create_dir_all(models_dir()).map_err(|e| e.to_string())?;
+    fs::write(recent_models_path(), json).map_err(|e| e.to_string())
+}
+
 /// List all models: global models from `~/.scryer/` + project-local models from registry.
 pub fn list_all_models() -> Result<Vec<ModelListEntry>, String> {
     let mut entries = Vec::new();
@@ -817,9 +1234,9 @@ pub fn list_all_models() -> Result<Vec<ModelListEntry>, String> {
 /// Returns a ModelRef if found.
 pub fn resolve_model_for_project_ref(project_path: &Path) -> Option<ModelRef> {
     // Check project-local first
-    let local_model = project_path.join(".scryer").join("model.scry");
-    if local_model.exists() {
-        return Some(ModelRef::ProjectLocal(project_path.to_path_buf()));
+    let local_model = ModelRef::ProjectLocal(project_path.to_path_buf());
+    if local_model.stored_path().exists() {
+        return Some(local_model);
     }
 
     // Fall back to scanning global models for project_path match
@@ -878,16 +1295,44 @@ pub fn make_edge_id(source: &str, target: &str) -> String {
     format!("edge-{}-{}", source, target)
 }
 
-/// Generate the next flow ID by scanning existing flows.
-/// Preserves "scenario-N" prefix for backward compatibility with existing .scry files.
-pub fn next_flow_id(model: &C4ModelData) -> String {
-    let max = model
-        .flows
+/// Like [`make_edge_id`], but appends `-2`, `-3`, … when the model already has
+/// an edge with that ID (a second relationship between the same pair).
+pub fn next_edge_id(model: &C4ModelData, source: &str, target: &str) -> String {
+    let base = make_edge_id(source, target);
+    let taken = |id: &str| model.edges.iter().any(|e| e.id == id);
+    if !taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|id| !taken(id))
+        .unwrap()
+}
+
+/// Next `{prefix}-N` ID that isn't in `existing`. N starts past the largest
+/// numeric suffix (after the last `-`) of any existing ID, whatever its prefix,
+/// so imported or hand-edited IDs are accounted for.
+fn next_prefixed_id<'a>(prefix: &str, existing: impl Iterator<Item = &'a str>) -> String {
+    let existing: HashSet<&str> = existing.collect();
+    let max = existing
         .iter()
-        .filter_map(|s| s.id.strip_prefix("scenario-").and_then(|n| n.parse::<u64>().ok()))
+        .filter_map(|id| id.rsplit_once('-').and_then(|(_, n)| n.parse::<u64>().ok()))
         .max()
         .unwrap_or(0);
-    format!("scenario-{}", max + 1)
+    let mut n = max + 1;
+    loop {
+        let id = format!("{}-{}", prefix, n);
+        if !existing.contains(id.as_str()) {
+            return id;
+        }
+        n += 1;
+    }
+}
+
+/// Generate the next flow ID by scanning existing flows.
+/// Preserves "scenario-N" prefix for backward compatibility with existing .scry files.
+pub fn next_flow_id(model: &C4ModelData) -> String {
+    next_prefixed_id("scenario", model.flows.iter().map(|f| f.id.as_str()))
 }
 
 /// Collect all step IDs recursively (including branch sub-steps).
@@ -904,13 +1349,702 @@ pub fn collect_step_ids(steps: &[FlowStep]) -> Vec<&str> {
 
 /// Generate the next step ID by scanning all steps across all flows.
 pub fn next_step_id(model: &C4ModelData) -> String {
-    let max = model
-        .flows
+    next_prefixed_id(
+        "step",
+        model.flows.iter().flat_map(|f| collect_step_ids(&f.steps)),
+    )
+}
+
+/// Check that following `parent_id` links never leads back to a node already
+/// on the chain. Dangling parent IDs end the chain and are not an error here.
+pub fn validate_no_parent_cycles(nodes: &[C4Node]) -> Result<(), ScryerError> {
+    let parents: HashMap<&str, &str> = nodes
         .iter()
-        .flat_map(|f| collect_step_ids(&f.steps))
-        .filter_map(|id| id.strip_prefix("step-").and_then(|n| n.parse::<u64>().ok()))
-        .max()
-        .unwrap_or(0);
-    format!("step-{}", max + 1)
+        .filter_map(|n| n.parent_id.as_deref().map(|p| (n.id.as_str(), p)))
+        .collect();
+    let mut acyclic: HashSet<&str> = HashSet::new();
+    for node in nodes {
+        let mut chain: Vec<&str> = vec![node.id.as_str()];
+        let mut current = node.id.as_str();
+        while let Some(&parent) = parents.get(current) {
+            if acyclic.contains(parent) {
+                break;
+            }
+            if let Some(start) = chain.iter().position(|id| *id == parent) {
+                let mut cycle = chain[start..].to_vec();
+                cycle.push(parent);
+                return Err(ScryerError::Validation(format!(
+                    "Parent cycle detected: {}",
+                    cycle.join(" → ")
+                )));
+            }
+            chain.push(parent);
+            current = parent;
+        }
+        acyclic.extend(chain);
+    }
+    Ok(())
+}
+
+/// Drop `ref_positions` entries whose view or referenced node was removed.
+pub fn prune_ref_positions(model: &mut C4ModelData, removed: &HashSet<String>) {
+    model
+        .ref_positions
+        .retain(|key, _| match key.split_once('/') {
+            Some((parent, r)) => !removed.contains(parent) && !removed.contains(r),
+            None => true,
+        });
+}
+
+/// Make group membership self-consistent: drop member IDs that aren't nodes
+/// in the model and duplicates within a group, keep each node in at most one
+/// group (the last group listing it wins, as in `set_groups`), and drop
+/// groups left without members unless they parent another group.
+pub fn normalize_groups(model: &mut C4ModelData) {
+    let node_ids: HashSet<&str> = model.nodes.iter().map(|n| n.id.as_str()).collect();
+    normalize_group_list(&mut model.groups, &node_ids);
+}
+
+/// [`normalize_groups`] on a model's raw JSON, for writers (the editor) that
+/// don't go through the typed model. Everything but `groups` passes through
+/// untouched, and the input is returned as-is when nothing changes.
+pub fn normalize_groups_raw(data: &str) -> Result<String, ScryerError> {
+    let mut val: serde_json::Value = serde_json::from_str(data)?;
+    let Some(groups) = val.get("groups") else {
+        return Ok(data.to_string());
+    };
+    let original: Vec<Group> = serde_json::from_value(groups.clone())?;
+    let mut normalized = original.clone();
+    let node_ids: HashSet<&str> = val
+        .get("nodes")
+        .and_then(|n| n.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|n| n.get("id")?.as_str())
+        .collect();
+    normalize_group_list(&mut normalized, &node_ids);
+    if normalized == original {
+        return Ok(data.to_string());
+    }
+    val["groups"] = serde_json::to_value(&normalized)?;
+    Ok(serde_json::to_string(&val)?)
+}
+
+fn normalize_group_list(groups: &mut Vec<Group>, node_ids: &HashSet<&str>) {
+    let mut claimed: HashSet<String> = HashSet::new();
+    for group in groups.iter_mut().rev() {
+        let mut seen: HashSet<String> = HashSet::new();
+        group.member_ids.retain(|id| {
+            node_ids.contains(id.as_str()) && seen.insert(id.clone()) && !claimed.contains(id)
+        });
+        claimed.extend(seen);
+    }
+
+    // Removing an empty group can empty its parent, so repeat until stable
+    loop {
+        let parents: HashSet<String> = groups
+            .iter()
+            .filter_map(|g| g.parent_group_id.clone())
+            .collect();
+        let before = groups.len();
+        groups.retain(|g| !g.member_ids.is_empty() || parents.contains(&g.id));
+        if groups.len() == before {
+            break;
+        }
+    }
+}
+
+/// Rewrite `@[old]` mentions to `@[new]` in node descriptions and flows
+/// (descriptions, step text, and branch conditions), so renaming a node
+/// doesn't leave them dangling. Returns how many mentions were rewritten.
+pub fn rename_mentions(model: &mut C4ModelData, old: &str, new: &str) -> usize {
+    let from = format!("@[{}]", old);
+    let to = format!("@[{}]", new);
+    let mut count = 0;
+    let mut fix = |text: &mut String| {
+        let n = text.matches(&from).count();
+        if n > 0 {
+            *text = text.replace(&from, &to);
+            count += n;
+        }
+    };
+    fn walk(steps: &mut [FlowStep], fix: &mut impl FnMut(&mut String)) {
+        for step in steps {
+            if let Some(label) = step.label.as_mut() {
+                fix(label);
+            }
+            if let Some(description) = step.description.as_mut() {
+                fix(description);
+            }
+            for branch in &mut step.branches {
+                fix(&mut branch.condition);
+                walk(&mut branch.steps, fix);
+            }
+        }
+    }
+    for node in &mut model.nodes {
+        fix(&mut node.data.description);
+    }
+    for flow in &mut model.flows {
+        if let Some(description) = flow.description.as_mut() {
+            fix(description);
+        }
+        walk(&mut flow.steps, &mut fix);
+    }
+    count
+}
+
+/// Strip references to things that no longer exist: nodes whose parent is
+/// gone (along with their children), edges with a missing endpoint, source
+/// map entries for unknown nodes/flows, reference positions for missing
+/// nodes, a starting node that's gone, group members and parent groups that
+/// are gone, and groups left with nothing in them. Returns one line per fix.
+pub fn repair_model(model: &mut C4ModelData) -> Vec<String> {
+    let mut fixes = Vec::new();
+
+    // An orphan can't be re-homed without guessing, so drop it; its own
+    // children become orphans on the next pass
+    loop {
+        let ids: HashSet<String> = model.nodes.iter().map(|n| n.id.clone()).collect();
+        let before = model.nodes.len();
+        model.nodes.retain(|n| match &n.parent_id {
+            Some(parent) if !ids.contains(parent) => {
+                fixes.push(format!(
+                    "Removed node '{}' ({}): parent '{}' missing",
+                    n.id, n.data.name, parent
+                ));
+                false
+            }
+            _ => true,
+        });
+        if model.nodes.len() == before {
+            break;
+        }
+    }
+
+    let node_ids: HashSet<String> = model.nodes.iter().map(|n| n.id.clone()).collect();
+
+    model.edges.retain(|e| {
+        let keep = node_ids.contains(&e.source) && node_ids.contains(&e.target);
+        if !keep {
+            fixes.push(format!(
+                "Removed edge '{}' ({} -> {}): endpoint missing",
+                e.id, e.source, e.target
+            ));
+        }
+        keep
+    });
+
+    let flow_ids: HashSet<&str> = model.flows.iter().map(|f| f.id.as_str()).collect();
+    let mut stale_sources: Vec<String> = model
+        .source_map
+        .keys()
+        .filter(|k| !node_ids.contains(*k) && !flow_ids.contains(k.as_str()))
+        .cloned()
+        .collect();
+    stale_sources.sort();
+    for key in stale_sources {
+        model.source_map.remove(&key);
+        fixes.push(format!("Removed source map entry for missing '{}'", key));
+    }
+
+    let before = model.ref_positions.len();
+    model
+        .ref_positions
+        .retain(|key, _| match key.split_once('/') {
+            Some((parent, r)) => {
+                (parent == "root" || node_ids.contains(parent)) && node_ids.contains(r)
+            }
+            None => true,
+        });
+    if model.ref_positions.len() < before {
+        fixes.push(format!(
+            "Removed {} reference position(s) for missing nodes",
+            before - model.ref_positions.len()
+        ));
+    }
+
+    if let Some(id) = &model.starting_node {
+        if !node_ids.contains(id) {
+            fixes.push(format!("Cleared missing starting node '{}'", id));
+            model.starting_node = None;
+        }
+    }
+
+    for group in &mut model.groups {
+        let missing: Vec<String> = group
+            .member_ids
+            .iter()
+            .filter(|id| !node_ids.contains(*id))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            group.member_ids.retain(|id| node_ids.contains(id));
+            fixes.push(format!(
+                "Removed missing member(s) {} from group '{}'",
+                missing.join(", "),
+                group.id
+            ));
+        }
+    }
+
+    // Removing an empty group can empty its parent, so repeat until stable
+    loop {
+        let group_ids: HashSet<String> = model.groups.iter().map(|g| g.id.clone()).collect();
+        for group in &mut model.groups {
+            if let Some(parent) = &group.parent_group_id {
+                if !group_ids.contains(parent) {
+                    fixes.push(format!(
+                        "Cleared missing parent group '{}' on group '{}'",
+                        parent, group.id
+                    ));
+                    group.parent_group_id = None;
+                }
+            }
+        }
+        let parents: HashSet<String> = model
+            .groups
+            .iter()
+            .filter_map(|g| g.parent_group_id.clone())
+            .collect();
+        let before = model.groups.len();
+        model.groups.retain(|g| {
+            let keep = !g.member_ids.is_empty() || parents.contains(&g.id);
+            if !keep {
+                fixes.push(format!("Removed empty group '{}' ({})", g.id, g.name));
+            }
+            keep
+        });
+        if model.groups.len() == before {
+            break;
+        }
+    }
+
+    fixes
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parent_cycle_is_rejected() {
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "node-1", "parentId": "node-2", "data": { "name": "A", "kind": "container" } },
+                { "id": "node-2", "parentId": "node-1", "data": { "name": "B", "kind": "container" } },
+            ],
+            "edges": [],
+        }))
+        .unwrap();
+
+        let err = validate_no_parent_cycles(&model.nodes)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("node-1 → node-2 → node-1"), "{err}");
+    }
+
+    #[test]
+    fn next_ids_account_for_foreign_prefixes() {
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [],
+            "edges": [],
+            "flows": [
+                { "id": "scenario-2", "name": "A", "steps": [
+                    { "id": "step-1" },
+                    { "id": "imported-step-7" },
+                ] },
+                { "id": "flow-5", "name": "B", "steps": [
+                    { "id": "s-3", "branches": [
+                        { "steps": [{ "id": "step-8" }] },
+                    ] },
+                ] },
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(next_flow_id(&model), "scenario-6");
+        assert_eq!(next_step_id(&model), "step-9");
+    }
+
+    #[test]
+    fn second_edge_between_same_pair_gets_suffix() {
+        let mut model: C4ModelData =
+            serde_json::from_value(serde_json::json!({ "nodes": [], "edges": [] })).unwrap();
+        for label in ["reads", "writes", "deletes"] {
+            let id = next_edge_id(&model, "node-1", "node-2");
+            model.edges.push(C4Edge {
+                id,
+                source: "node-1".into(),
+                target: "node-2".into(),
+                data: Some(C4EdgeData {
+                    label: label.into(),
+                    method: None,
+                    async_: None,
+                }),
+            });
+        }
+
+        let ids: Vec<&str> = model.edges.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "edge-node-1-node-2",
+                "edge-node-1-node-2-2",
+                "edge-node-1-node-2-3"
+            ]
+        );
+    }
+
+    #[test]
+    fn prune_ref_positions_drops_removed_nodes() {
+        let mut model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [],
+            "edges": [],
+            "refPositions": {
+                "root/node-1": { "x": 0.0, "y": 0.0 },
+                "node-1/node-2": { "x": 10.0, "y": 0.0 },
+                "node-3/node-2": { "x": 20.0, "y": 0.0 },
+            }
+        }))
+        .unwrap();
+
+        prune_ref_positions(&mut model, &HashSet::from(["node-1".to_string()]));
+
+        assert_eq!(model.ref_positions.len(), 1);
+        assert!(model.ref_positions.contains_key("node-3/node-2"));
+    }
+
+    #[test]
+    fn concurrent_writes_never_blend() {
+        let project = std::env::temp_dir().join(format!("scryer-lock-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&project);
+        fs::create_dir_all(&project).unwrap();
+        let model_ref = ModelRef::ProjectLocal(project.clone());
+
+        let payload = |fill: &str| {
+            format!(
+                r#"{{"nodes":[],"edges":[],"pad":"{}"}}"#,
+                fill.repeat(1 << 18)
+            )
+        };
+        let a = payload("a");
+        let b = payload("b");
+
+        let handles: Vec<_> = [a.clone(), b.clone()]
+            .into_iter()
+            .map(|data| {
+                let model_ref = model_ref.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..20 {
+                        write_model_raw_at(&model_ref, &data).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+
+        let written = fs::read_to_string(model_ref.model_path()).unwrap();
+        assert!(
+            written == a || written == b,
+            "final file is a blend of both writes"
+        );
+        let _ = fs::remove_dir_all(&project);
+    }
+
+    #[test]
+    fn serialization_is_byte_stable() {
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [],
+            "edges": [],
+            "sourceMap": {
+                "node-9": [{ "pattern": "src/z.rs" }],
+                "node-1": [{ "pattern": "src/a.rs" }],
+                "node-5": [{ "pattern": "src/m.rs" }],
+            },
+            "refPositions": {
+                "root/node-9": { "x": 1.0, "y": 2.0 },
+                "node-1/node-5": { "x": 3.0, "y": 4.0 },
+                "root/node-1": { "x": 5.0, "y": 6.0 },
+            },
+        }))
+        .unwrap();
+
+        let first = serde_json::to_string_pretty(&model).unwrap();
+        let reparsed: C4ModelData = serde_json::from_str(&first).unwrap();
+        let second = serde_json::to_string_pretty(&reparsed).unwrap();
+        assert_eq!(first, second);
+
+        let a = first.find("\"node-1\"").unwrap();
+        let b = first.find("\"node-5\"").unwrap();
+        let c = first.find("\"node-9\"").unwrap();
+        assert!(a < b && b < c, "source map keys are not sorted");
+    }
+
+    #[test]
+    fn gzip_model_file_reads_like_plain() {
+        let path =
+            std::env::temp_dir().join(format!("scryer-gz-test-{}.scry.gz", std::process::id()));
+        let raw = r#"{"nodes":[],"edges":[]}"#;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        std::io::Write::write_all(&mut encoder, raw.as_bytes()).unwrap();
+        fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        assert_eq!(read_model_file(&path).unwrap(), raw);
+        assert_eq!(model_file_stem("shop.scry.gz"), Some("shop"));
+        assert_eq!(model_file_stem("shop.baseline.scry"), None);
+        let _ = fs::remove_file(&path);
+    }
+
+    /// A model with `value` as its groups and a node for every member ID.
+    fn groups(value: serde_json::Value) -> C4ModelData {
+        let ids: std::collections::BTreeSet<&str> = value
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|g| g["memberIds"].as_array().unwrap())
+            .filter_map(|id| id.as_str())
+            .collect();
+        let nodes: Vec<serde_json::Value> = ids
+            .iter()
+            .map(|id| serde_json::json!({ "id": id, "data": { "name": id, "kind": "system" } }))
+            .collect();
+        serde_json::from_value(serde_json::json!({ "nodes": nodes, "edges": [], "groups": value }))
+            .unwrap()
+    }
+
+    fn members(model: &C4ModelData) -> Vec<(&str, Vec<&str>)> {
+        model
+            .groups
+            .iter()
+            .map(|g| {
+                (
+                    g.id.as_str(),
+                    g.member_ids.iter().map(|m| m.as_str()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn normalize_groups_drops_duplicate_members() {
+        let mut model = groups(serde_json::json!([
+            { "id": "group-1", "name": "A", "memberIds": ["node-1", "node-2", "node-1"] },
+        ]));
+        normalize_groups(&mut model);
+        assert_eq!(members(&model), vec![("group-1", vec!["node-1", "node-2"])]);
+    }
+
+    #[test]
+    fn normalize_groups_last_group_wins() {
+        let mut model = groups(serde_json::json!([
+            { "id": "group-1", "name": "A", "memberIds": ["node-1", "node-2"] },
+            { "id": "group-2", "name": "B", "memberIds": ["node-2", "node-3"] },
+        ]));
+        normalize_groups(&mut model);
+        assert_eq!(
+            members(&model),
+            vec![
+                ("group-1", vec!["node-1"]),
+                ("group-2", vec!["node-2", "node-3"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize_groups_cascades_to_emptied_parents() {
+        // group-2 loses its only member to group-3, which leaves group-1
+        // (its parent, with no members of its own) empty as well.
+        let mut model = groups(serde_json::json!([
+            { "id": "group-1", "name": "Outer", "memberIds": [] },
+            { "id": "group-2", "name": "Inner", "memberIds": ["node-1"], "parentGroupId": "group-1" },
+            { "id": "group-3", "name": "Other", "memberIds": ["node-1"] },
+            { "id": "group-4", "name": "Kept", "memberIds": [] },
+            { "id": "group-5", "name": "Child", "memberIds": ["node-2"], "parentGroupId": "group-4" },
+        ]));
+        normalize_groups(&mut model);
+        let ids: Vec<&str> = model.groups.iter().map(|g| g.id.as_str()).collect();
+        assert_eq!(ids, vec!["group-3", "group-4", "group-5"]);
+    }
+
+    #[test]
+    fn normalize_groups_drops_deleted_members() {
+        // Every member of group-2 is gone, which empties it and then its
+        // parent group-1; group-3 just loses node-3.
+        let mut model = groups(serde_json::json!([
+            { "id": "group-1", "name": "Outer", "memberIds": [] },
+            { "id": "group-2", "name": "Inner", "memberIds": ["node-1", "node-2"], "parentGroupId": "group-1" },
+            { "id": "group-3", "name": "Other", "memberIds": ["node-3", "node-4"] },
+        ]));
+        model
+            .nodes
+            .retain(|n| !matches!(n.id.as_str(), "node-1" | "node-2" | "node-3"));
+        normalize_groups(&mut model);
+        assert_eq!(members(&model), vec![("group-3", vec!["node-4"])]);
+
+        let data = r#"{"nodes":[{"id":"node-2"}],"groups":[{"id":"group-1","name":"A","memberIds":["node-1"]},{"id":"group-2","name":"B","memberIds":["node-1","node-2"]}]}"#;
+        let out: serde_json::Value =
+            serde_json::from_str(&normalize_groups_raw(data).unwrap()).unwrap();
+        assert_eq!(
+            out["groups"],
+            serde_json::json!([{ "id": "group-2", "name": "B", "memberIds": ["node-2"] }])
+        );
+    }
+
+    #[test]
+    fn normalize_groups_raw_keeps_other_fields() {
+        let data = r#"{"nodes":[{"id":"node-1","position":{"x":1,"y":2}}],"groups":[{"id":"group-1","name":"A","memberIds":["node-1","node-1"]}]}"#;
+        let out: serde_json::Value =
+            serde_json::from_str(&normalize_groups_raw(data).unwrap()).unwrap();
+        assert_eq!(out["nodes"][0]["position"]["x"], 1);
+        assert_eq!(out["groups"][0]["memberIds"], serde_json::json!(["node-1"]));
+
+        let clean = r#"{"nodes":[{"id":"node-1"}],"groups":[{"id":"group-1","name":"A","memberIds":["node-1"]}]}"#;
+        assert_eq!(normalize_groups_raw(clean).unwrap(), clean);
+    }
+
+    fn broken(value: serde_json::Value) -> C4ModelData {
+        let mut model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "node-1", "data": { "name": "Shop", "kind": "system" } },
+                { "id": "node-2", "parentId": "node-1", "data": { "name": "Web", "kind": "container" } },
+            ],
+            "edges": [
+                { "id": "edge-1", "source": "node-1", "target": "node-2" },
+            ],
+        }))
+        .unwrap();
+        let extra: C4ModelData = serde_json::from_value(value).unwrap();
+        model.nodes.extend(extra.nodes);
+        model.edges.extend(extra.edges);
+        model.groups = extra.groups;
+        model.starting_node = extra.starting_node;
+        model
+    }
+
+    fn node_ids(model: &C4ModelData) -> Vec<&str> {
+        model.nodes.iter().map(|n| n.id.as_str()).collect()
+    }
+
+    #[test]
+    fn repair_drops_nodes_with_a_missing_parent() {
+        let mut model = broken(serde_json::json!({
+            "nodes": [
+                { "id": "node-3", "parentId": "node-9", "data": { "name": "Api", "kind": "container" } },
+                { "id": "node-4", "parentId": "node-3", "data": { "name": "Auth", "kind": "component" } },
+            ],
+            "edges": [
+                { "id": "edge-2", "source": "node-2", "target": "node-4" },
+            ],
+        }));
+
+        let fixes = repair_model(&mut model);
+        assert_eq!(node_ids(&model), vec!["node-1", "node-2"]);
+        assert_eq!(model.edges.len(), 1);
+        assert_eq!(fixes.len(), 3, "{fixes:?}");
+        assert!(fixes[0].contains("'node-3'") && fixes[0].contains("'node-9' missing"));
+        assert!(fixes[1].contains("'node-4'"));
+        assert!(fixes[2].contains("'edge-2'"));
+    }
+
+    #[test]
+    fn repair_drops_edges_with_a_missing_endpoint() {
+        let mut model = broken(serde_json::json!({
+            "nodes": [],
+            "edges": [
+                { "id": "edge-2", "source": "node-9", "target": "node-2" },
+                { "id": "edge-3", "source": "node-2", "target": "node-8" },
+            ],
+        }));
+
+        let fixes = repair_model(&mut model);
+        let edges: Vec<&str> = model.edges.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(edges, vec!["edge-1"]);
+        assert_eq!(
+            fixes,
+            vec![
+                "Removed edge 'edge-2' (node-9 -> node-2): endpoint missing",
+                "Removed edge 'edge-3' (node-2 -> node-8): endpoint missing",
+            ]
+        );
+    }
+
+    #[test]
+    fn repair_drops_missing_group_members_and_empty_groups() {
+        let mut model = broken(serde_json::json!({
+            "nodes": [],
+            "edges": [],
+            "groups": [
+                { "id": "group-1", "name": "Cloud", "memberIds": ["node-2", "node-9"] },
+                { "id": "group-2", "name": "Gone", "memberIds": ["node-8"] },
+            ],
+        }));
+
+        let fixes = repair_model(&mut model);
+        assert_eq!(members(&model), vec![("group-1", vec!["node-2"])]);
+        assert_eq!(
+            fixes,
+            vec![
+                "Removed missing member(s) node-9 from group 'group-1'",
+                "Removed missing member(s) node-8 from group 'group-2'",
+                "Removed empty group 'group-2' (Gone)",
+            ]
+        );
+    }
+
+    #[test]
+    fn repair_clears_a_missing_starting_node() {
+        let mut model = broken(serde_json::json!({
+            "nodes": [],
+            "edges": [],
+            "startingNode": "node-9",
+        }));
+
+        let fixes = repair_model(&mut model);
+        assert_eq!(model.starting_node, None);
+        assert_eq!(fixes, vec!["Cleared missing starting node 'node-9'"]);
+        assert!(repair_model(&mut model).is_empty());
+    }
+
+    #[test]
+    fn newer_format_wins_when_both_files_exist() {
+        let root = std::env::temp_dir().join(format!("scryer-both-test-{}", std::process::id()));
+        let r = ModelRef::ProjectLocal(root.clone());
+        fs::create_dir_all(r.dir()).unwrap();
+        fs::write(r.model_path(), "{}").unwrap();
+        fs::write(r.gz_path(), "{}").unwrap();
+        let age = |p: PathBuf, secs: u64| {
+            let time = std::time::SystemTime::now() - Duration::from_secs(secs);
+            let file = fs::File::options().write(true).open(p).unwrap();
+            file.set_modified(time).unwrap();
+        };
+
+        age(r.model_path(), 60);
+        assert_eq!(r.stored_path(), r.gz_path());
+        age(r.gz_path(), 120);
+        assert_eq!(r.stored_path(), r.model_path());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn stale_etag_write_is_rejected() {
+        let root = std::env::temp_dir().join(format!("scryer-etag-test-{}", std::process::id()));
+        let r = ModelRef::ProjectLocal(root.clone());
+        assert_eq!(model_etag_at(&r), None);
+        let written = write_model_raw_if_match_at(&r, r#"{"nodes":[],"edges":[]}"#, None).unwrap();
+        let (_, loaded) = read_model_raw_tagged_at(&r).unwrap();
+        assert_eq!(loaded, written);
+        assert_eq!(model_etag_at(&r).as_deref(), Some(written.as_str()));
+
+        fs::write(r.stored_path(), r#"{"nodes":[],"edges":[],"flows":[]}"#).unwrap();
+        let err = write_model_raw_if_match_at(&r, "{}", Some(&loaded)).unwrap_err();
+        assert!(matches!(err, ScryerError::Conflict(_)));
+
+        let current = model_etag_at(&r).unwrap();
+        write_model_raw_if_match_at(&r, r#"{"nodes":[],"edges":[]}"#, Some(&current)).unwrap();
+        assert_ne!(model_etag_at(&r).unwrap(), current);
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,177 @@
+//! Size limits enforced on model text and attachments.
+//!
+//! The defaults keep the canvas legible. Teams that write longer text can
+//! override them with environment variables, or per model with a `limits`
+//! block in the model file; `0` removes a limit entirely.
+
+use serde::{Deserialize, Serialize};
+
+use crate::C4Kind;
+
+/// Per-model limit policy, stored as `limits` in the model file. Set fields
+/// take precedence over the environment and the defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelLimits {
+    /// Description limit for architectural nodes (persons, systems,
+    /// containers, components). `0` removes the limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_description: Option<usize>,
+    /// Description limit for code-level nodes (operations, processes,
+    /// models), which are otherwise unlimited. `0` keeps them unlimited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_code_description: Option<usize>,
+}
+
+/// Default description limit for architectural (non-code) nodes.
+pub const DEFAULT_DESCRIPTION_LEN: usize = 200;
+
+/// Read a limit override from `var`: `Some(Some(n))` sets it, `Some(None)`
+/// (a value of `0`) disables it, `None` means unset or unparseable.
+fn env_override(var: &str) -> Option<Option<usize>> {
+    let value = std::env::var(var).ok()?;
+    let n = value.trim().parse::<usize>().ok()?;
+    Some((n > 0).then_some(n))
+}
+
+/// Maximum description length for a node of `kind`, or `None` if unlimited.
+/// Operations, processes, and models are code-level and unlimited unless the
+/// model's `limits` caps them. Otherwise the model's `maxDescription` wins,
+/// then `SCRYER_MAX_DESCRIPTION_LEN`, then the default.
+pub fn max_description_len(kind: &C4Kind, limits: Option<&ModelLimits>) -> Option<usize> {
+    if matches!(kind, C4Kind::Operation | C4Kind::Process | C4Kind::Model) {
+        let max = limits.and_then(|l| l.max_code_description)?;
+        return (max > 0).then_some(max);
+    }
+    if let Some(max) = limits.and_then(|l| l.max_description) {
+        return (max > 0).then_some(max);
+    }
+    env_override("SCRYER_MAX_DESCRIPTION_LEN").unwrap_or(Some(DEFAULT_DESCRIPTION_LEN))
+}
+
+/// Check `description` against the limit for `kind` under the model's
+/// `limits`. `label` names the node in the error message.
+pub fn check_description_len(
+    kind: &C4Kind,
+    description: &str,
+    label: &str,
+    limits: Option<&ModelLimits>,
+) -> Result<(), String> {
+    match max_description_len(kind, limits) {
+        Some(max) if description.len() > max => Err(format!(
+            "Description for '{}' must be {} characters or less",
+            label, max
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Default edge label limit — one short verb phrase.
+pub const DEFAULT_EDGE_LABEL_LEN: usize = 30;
+
+/// Maximum edge label length, or `None` if unlimited.
+/// Override the default with `SCRYER_MAX_EDGE_LABEL_LEN`.
+pub fn max_edge_label_len() -> Option<usize> {
+    env_override("SCRYER_MAX_EDGE_LABEL_LEN").unwrap_or(Some(DEFAULT_EDGE_LABEL_LEN))
+}
+
+/// Check an edge label against [`max_edge_label_len`].
+pub fn check_edge_label_len(label: &str) -> Result<(), String> {
+    match max_edge_label_len() {
+        Some(max) if label.len() > max => Err(format!(
+            "Edge label '{}' exceeds {} character limit",
+            label, max
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Default maximum decoded size of a single attachment (10 MB).
+pub const DEFAULT_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// Maximum decoded attachment size in bytes, or `None` if unlimited.
+/// Override the default with `SCRYER_MAX_ATTACHMENT_BYTES`.
+pub fn max_attachment_bytes() -> Option<usize> {
+    env_override("SCRYER_MAX_ATTACHMENT_BYTES").unwrap_or(Some(DEFAULT_ATTACHMENT_BYTES))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Tests that touch `SCRYER_MAX_DESCRIPTION_LEN` hold this so they don't
+    /// see each other's value.
+    static ENV: Mutex<()> = Mutex::new(());
+
+    fn with_env<T>(value: Option<&str>, f: impl FnOnce() -> T) -> T {
+        let _guard = ENV.lock().unwrap_or_else(|e| e.into_inner());
+        match value {
+            Some(v) => std::env::set_var("SCRYER_MAX_DESCRIPTION_LEN", v),
+            None => std::env::remove_var("SCRYER_MAX_DESCRIPTION_LEN"),
+        }
+        let out = f();
+        std::env::remove_var("SCRYER_MAX_DESCRIPTION_LEN");
+        out
+    }
+
+    fn limits(max: usize) -> ModelLimits {
+        ModelLimits {
+            max_description: Some(max),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn model_limit_wins_over_env() {
+        let max = with_env(Some("500"), || {
+            max_description_len(&C4Kind::Container, Some(&limits(80)))
+        });
+        assert_eq!(max, Some(80));
+    }
+
+    #[test]
+    fn env_wins_over_default() {
+        let max = with_env(Some("500"), || {
+            max_description_len(&C4Kind::Container, None)
+        });
+        assert_eq!(max, Some(500));
+    }
+
+    #[test]
+    fn default_applies_when_unset_or_unparseable() {
+        let unset = with_env(None, || max_description_len(&C4Kind::System, None));
+        assert_eq!(unset, Some(DEFAULT_DESCRIPTION_LEN));
+        let garbage = with_env(Some("lots"), || max_description_len(&C4Kind::System, None));
+        assert_eq!(garbage, Some(DEFAULT_DESCRIPTION_LEN));
+    }
+
+    #[test]
+    fn zero_removes_the_limit() {
+        let from_model = with_env(Some("500"), || {
+            max_description_len(&C4Kind::Component, Some(&limits(0)))
+        });
+        assert_eq!(from_model, None);
+        let from_env = with_env(Some("0"), || max_description_len(&C4Kind::Component, None));
+        assert_eq!(from_env, None);
+    }
+
+    #[test]
+    fn code_nodes_are_unlimited_unless_the_model_caps_them() {
+        let capped = ModelLimits {
+            max_code_description: Some(1000),
+            ..limits(80)
+        };
+        with_env(Some("500"), || {
+            assert_eq!(max_description_len(&C4Kind::Operation, None), None);
+            assert_eq!(
+                max_description_len(&C4Kind::Process, Some(&limits(80))),
+                None
+            );
+            assert_eq!(
+                max_description_len(&C4Kind::Model, Some(&capped)),
+                Some(1000)
+            );
+        });
+    }
+}
@@ -0,0 +1,267 @@
+//! Static SVG rendering of a model at one abstraction level.
+//!
+//! Not a replica of the React canvas — a layered top-to-bottom layout with
+//! legible labels, good enough to paste into documentation.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{C4Kind, C4ModelData, C4Node, StartingLevel, Status};
+
+const NODE_W: f64 = 200.0;
+const NODE_H: f64 = 90.0;
+const GAP_X: f64 = 60.0;
+const GAP_Y: f64 = 90.0;
+const MARGIN: f64 = 40.0;
+
+/// Nodes and lifted edges visible at a given level.
+pub struct LevelView<'a> {
+    pub nodes: Vec<&'a C4Node>,
+    /// (source, target, label), deduplicated, self-loops dropped.
+    pub edges: Vec<(&'a str, &'a str, &'a str)>,
+}
+
+fn kind_depth(kind: &C4Kind) -> u8 {
+    match kind {
+        C4Kind::Person | C4Kind::System => 0,
+        C4Kind::Container => 1,
+        C4Kind::Component => 2,
+        C4Kind::Operation | C4Kind::Process | C4Kind::Model => 3,
+    }
+}
+
+fn level_depth(level: &StartingLevel) -> u8 {
+    match level {
+        StartingLevel::System => 0,
+        StartingLevel::Container => 1,
+        StartingLevel::Component => 2,
+    }
+}
+
+/// Select the nodes shown at `level`: everything at or above that depth,
+/// except parents whose children are shown in their place. Edges between
+/// hidden nodes are lifted to their nearest visible ancestor.
+pub fn level_view<'a>(model: &'a C4ModelData, level: &StartingLevel) -> LevelView<'a> {
+    let max_depth = level_depth(level);
+    let by_id: HashMap<&str, &C4Node> = model.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+    let candidates: HashSet<&str> = model
+        .nodes
+        .iter()
+        .filter(|n| kind_depth(&n.data.kind) <= max_depth)
+        .map(|n| n.id.as_str())
+        .collect();
+    let expanded: HashSet<&str> = model
+        .nodes
+        .iter()
+        .filter(|n| candidates.contains(n.id.as_str()))
+        .filter_map(|n| n.parent_id.as_deref())
+        .collect();
+    let nodes: Vec<&C4Node> = model
+        .nodes
+        .iter()
+        .filter(|n| candidates.contains(n.id.as_str()) && !expanded.contains(n.id.as_str()))
+        .collect();
+    let visible: HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+
+    let lift = |id: &'a str| -> Option<&'a str> {
+        let mut current = id;
+        let mut hops = 0;
+        while !visible.contains(current) {
+            current = by_id.get(current)?.parent_id.as_deref()?;
+            hops += 1;
+            if hops > by_id.len() {
+                return None;
+            }
+        }
+        Some(current)
+    };
+
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+    for edge in &model.edges {
+        let (Some(s), Some(t)) = (lift(&edge.source), lift(&edge.target)) else {
+            continue;
+        };
+        if s == t || !seen.insert((s, t)) {
+            continue;
+        }
+        let label = edge.data.as_ref().map(|d| d.label.as_str()).unwrap_or("");
+        edges.push((s, t, label));
+    }
+
+    LevelView { nodes, edges }
+}
+
+pub(crate) fn kind_name(kind: &C4Kind) -> &'static str {
+    match kind {
+        C4Kind::Person => "Person",
+        C4Kind::System => "System",
+        C4Kind::Container => "Container",
+        C4Kind::Component => "Component",
+        C4Kind::Operation => "Operation",
+        C4Kind::Process => "Process",
+        C4Kind::Model => "Model",
+    }
+}
+
+pub(crate) fn status_color(status: Option<&Status>) -> &'static str {
+    match status {
+        Some(Status::Proposed) => "#3b82f6",
+        Some(Status::InProgress) => "#8b5cf6",
+        Some(Status::Implemented) => "#f59e0b",
+        Some(Status::Verified) => "#10b981",
+        Some(Status::Vagrant) => "#f43f5e",
+        None => "#71717a",
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        let cut: String = s.chars().take(max.saturating_sub(1)).collect();
+        format!("{cut}…")
+    }
+}
+
+/// Assign each visible node a layer: longest path from a node with no
+/// incoming edges. Layers are capped at the node count so cycles terminate.
+fn layers(view: &LevelView) -> HashMap<String, usize> {
+    let mut layer: HashMap<String, usize> = view.nodes.iter().map(|n| (n.id.clone(), 0)).collect();
+    // Bellman-Ford style relaxation, capped so cycles can't loop forever
+    for _ in 0..view.nodes.len() {
+        let mut changed = false;
+        for (s, t, _) in &view.edges {
+            let next = layer[*s] + 1;
+            if next < view.nodes.len() && next > layer[*t] {
+                layer.insert(t.to_string(), next);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    layer
+}
+
+/// Render the model at `level` as a standalone SVG document.
+pub fn render_svg(model: &C4ModelData, level: &StartingLevel) -> String {
+    let view = level_view(model, level);
+    let layer = layers(&view);
+
+    let mut rows: Vec<Vec<&C4Node>> = Vec::new();
+    for node in &view.nodes {
+        let l = layer[&node.id];
+        if rows.len() <= l {
+            rows.resize_with(l + 1, Vec::new);
+        }
+        rows[l].push(node);
+    }
+    rows.retain(|r| !r.is_empty());
+
+    let widest = rows.iter().map(|r| r.len()).max().unwrap_or(0) as f64;
+    let width = (MARGIN * 2.0 + widest * NODE_W + (widest - 1.0).max(0.0) * GAP_X).max(NODE_W);
+    let height =
+        MARGIN * 2.0 + rows.len() as f64 * NODE_H + (rows.len() as f64 - 1.0).max(0.0) * GAP_Y;
+
+    let mut pos: HashMap<&str, (f64, f64)> = HashMap::new();
+    for (r, row) in rows.iter().enumerate() {
+        let row_w = row.len() as f64 * NODE_W + (row.len() as f64 - 1.0) * GAP_X;
+        let x0 = (width - row_w) / 2.0;
+        for (i, node) in row.iter().enumerate() {
+            let x = x0 + i as f64 * (NODE_W + GAP_X);
+            let y = MARGIN + r as f64 * (NODE_H + GAP_Y);
+            pos.insert(node.id.as_str(), (x, y));
+        }
+    }
+
+    let mut out = String::with_capacity(4096);
+    out.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" \
+         font-family=\"-apple-system, Helvetica, Arial, sans-serif\">\n",
+        w = width,
+        h = height
+    ));
+    out.push_str(
+        "  <defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" \
+         markerWidth=\"8\" markerHeight=\"8\" orient=\"auto-start-reverse\">\
+         <path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"#52525b\"/></marker></defs>\n",
+    );
+    out.push_str(&format!(
+        "  <rect width=\"{width}\" height=\"{height}\" fill=\"#ffffff\"/>\n"
+    ));
+
+    for (s, t, label) in &view.edges {
+        let (sx, sy) = pos[s];
+        let (tx, ty) = pos[t];
+        let (x1, y1, x2, y2) = if ty > sy {
+            (sx + NODE_W / 2.0, sy + NODE_H, tx + NODE_W / 2.0, ty)
+        } else if ty < sy {
+            (sx + NODE_W / 2.0, sy, tx + NODE_W / 2.0, ty + NODE_H)
+        } else if tx > sx {
+            (sx + NODE_W, sy + NODE_H / 2.0, tx, ty + NODE_H / 2.0)
+        } else {
+            (sx, sy + NODE_H / 2.0, tx + NODE_W, ty + NODE_H / 2.0)
+        };
+        out.push_str(&format!(
+            "  <line x1=\"{x1}\" y1=\"{y1}\" x2=\"{x2}\" y2=\"{y2}\" stroke=\"#52525b\" \
+             stroke-width=\"1.5\" marker-end=\"url(#arrow)\"/>\n"
+        ));
+        if !label.is_empty() {
+            out.push_str(&format!(
+                "  <text x=\"{}\" y=\"{}\" font-size=\"11\" fill=\"#3f3f46\" text-anchor=\"middle\">{}</text>\n",
+                (x1 + x2) / 2.0,
+                (y1 + y2) / 2.0 - 4.0,
+                escape(label)
+            ));
+        }
+    }
+
+    for node in &view.nodes {
+        let (x, y) = pos[node.id.as_str()];
+        let d = &node.data;
+        let stroke = status_color(d.status.as_ref());
+        let fill = if d.external.unwrap_or(false) {
+            "#f4f4f5"
+        } else {
+            "#ffffff"
+        };
+        let rx = if matches!(d.kind, C4Kind::Person) {
+            45
+        } else {
+            8
+        };
+        out.push_str(&format!(
+            "  <rect x=\"{x}\" y=\"{y}\" width=\"{NODE_W}\" height=\"{NODE_H}\" rx=\"{rx}\" \
+             fill=\"{fill}\" stroke=\"{stroke}\" stroke-width=\"2\"/>\n"
+        ));
+        let cx = x + NODE_W / 2.0;
+        out.push_str(&format!(
+            "  <text x=\"{cx}\" y=\"{}\" font-size=\"14\" font-weight=\"600\" fill=\"#18181b\" \
+             text-anchor=\"middle\">{}</text>\n",
+            y + 34.0,
+            escape(&truncate(&d.name, 26))
+        ));
+        let mut subtitle = kind_name(&d.kind).to_string();
+        if let Some(tech) = d.technology.as_deref().filter(|t| !t.is_empty()) {
+            subtitle.push_str(&format!(" · {tech}"));
+        }
+        out.push_str(&format!(
+            "  <text x=\"{cx}\" y=\"{}\" font-size=\"11\" fill=\"#71717a\" \
+             text-anchor=\"middle\">[{}]</text>\n",
+            y + 56.0,
+            escape(&truncate(&subtitle, 32))
+        ));
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
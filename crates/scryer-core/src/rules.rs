@@ -52,7 +52,8 @@ label. The queue is infrastructure that can fail, scale, and be monitored indepe
 the specific communication paths are lost — it looks like every producer talks to every consumer. \
 Resolve this by decomposing the broker into its topics or queues as components (broker = container, \
 each topic = component), then route edges through the specific topics. At the system level a fan \
-through the broker is fine — that is the right abstraction. The detail lives one level down.\n\
+through the broker is fine — that is the right abstraction. The detail lives one level down. \
+Mark the edges into and out of the queue with `async: true` so the messaging semantics are explicit.\n\
 13. Node names describe roles, not technology stacks. A node name should say what it IS \
 (\"Website\", \"CMS\", \"API Gateway\"), not list its technologies with \"+\" or \"&\". \
 Technology details belong in the technology field. If a container uses multiple frameworks \
@@ -195,3 +196,36 @@ between containers, any change that alters boundaries at a higher level than whe
 Does not require approval: adding/modifying components and operations within existing boundaries, adding \
 edges between existing nodes, updating descriptions/technology/status/source map, detailing a node's \
 internals when the user explicitly asked you to.";
+
+/// Names accepted by [`rules_section`], besides a rule number.
+pub const SECTIONS: &[&str] = &["rules", "workflow", "authority"];
+
+/// One part of [`RULES`], so callers that only need e.g. the workflow don't
+/// pay for the whole text. `key` is `"rules"` for the numbered modeling
+/// rules, a rule number like `"12"`, or a word from a section heading
+/// (`"workflow"`, `"hierarchy"`). Case-insensitive.
+pub fn rules_section(key: &str) -> Option<String> {
+    let key = key.trim().to_lowercase();
+    let mut parts = RULES.split("\n\n## ");
+    let numbered = parts.next()?;
+    if key == "rules" {
+        return Some(numbered.to_string());
+    }
+    if let Ok(n) = key.parse::<u32>() {
+        let prefix = format!("{}. ", n);
+        return numbered
+            .lines()
+            .find(|line| line.starts_with(&prefix))
+            .map(str::to_string);
+    }
+    if key.is_empty() {
+        return None;
+    }
+    parts
+        .find(|part| {
+            part.lines()
+                .next()
+                .is_some_and(|heading| heading.to_lowercase().contains(&key))
+        })
+        .map(|part| format!("## {}", part))
+}
@@ -0,0 +1,217 @@
+//! Case-insensitive text search across every model on disk.
+
+use crate::{list_all_models, read_model_at, C4ModelData, FlowStep, ModelRef};
+
+/// Characters of context kept on each side of a match in [`SearchHit::snippet`].
+const SNIPPET_CONTEXT: usize = 40;
+
+/// One match of the query inside a model.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    /// Model ref string, as passed to [`ModelRef::parse`].
+    pub model: String,
+    /// The matching node, when the match is on a node field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    /// Which field matched: `name`, `description`, `edgeLabel`, `flowName`,
+    /// or `flowStep`.
+    pub field: &'static str,
+    /// The matched text with some surrounding context.
+    pub snippet: String,
+}
+
+/// Search node names and descriptions, edge labels, flow names, and flow
+/// step labels and descriptions in every model. Models that can't be read
+/// or parsed are skipped.
+pub fn search_models(query: &str) -> Vec<SearchHit> {
+    if query.trim().is_empty() {
+        return vec![];
+    }
+    let Ok(entries) = list_all_models() else {
+        return vec![];
+    };
+    let models = entries.into_iter().filter_map(|entry| {
+        let model = read_model_at(&ModelRef::parse(&entry.ref_str)).ok()?;
+        Some((entry.ref_str, model))
+    });
+    search_in(models, query)
+}
+
+/// [`search_models`] over already-loaded `(ref string, model)` pairs.
+///
+/// Hits are ranked: node names equal to the query first, then other name
+/// matches, flow names, edge labels, flow steps, and descriptions last.
+/// Within a rank, hits keep model order.
+fn search_in(
+    models: impl IntoIterator<Item = (String, C4ModelData)>,
+    query: &str,
+) -> Vec<SearchHit> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return vec![];
+    }
+
+    fn walk(steps: &[FlowStep], push: &mut impl FnMut(Option<&str>, &'static str, &str)) {
+        for step in steps {
+            for text in [&step.label, &step.description].into_iter().flatten() {
+                push(None, "flowStep", text);
+            }
+            for branch in &step.branches {
+                walk(&branch.steps, push);
+            }
+        }
+    }
+
+    let mut hits = Vec::new();
+    for (model_ref, model) in models {
+        let mut push = |node_id: Option<&str>, field: &'static str, text: &str| {
+            if let Some(snippet) = snippet(text, &query) {
+                let rank = match field {
+                    "name" if text.to_lowercase() == query => 0,
+                    "name" => 1,
+                    "flowName" => 2,
+                    "edgeLabel" => 3,
+                    "flowStep" => 4,
+                    _ => 5,
+                };
+                let hit = SearchHit {
+                    model: model_ref.clone(),
+                    node_id: node_id.map(str::to_string),
+                    field,
+                    snippet,
+                };
+                hits.push((rank, hit));
+            }
+        };
+        for node in &model.nodes {
+            push(Some(&node.id), "name", &node.data.name);
+            push(Some(&node.id), "description", &node.data.description);
+        }
+        for edge in &model.edges {
+            if let Some(data) = &edge.data {
+                push(None, "edgeLabel", &data.label);
+            }
+        }
+        for flow in &model.flows {
+            push(None, "flowName", &flow.name);
+            walk(&flow.steps, &mut push);
+        }
+    }
+    hits.sort_by_key(|(rank, _)| *rank);
+    hits.into_iter().map(|(_, hit)| hit).collect()
+}
+
+/// The part of `text` around the first occurrence of `query` (already
+/// lowercased), or `None` if it doesn't occur.
+fn snippet(text: &str, query: &str) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars.iter().flat_map(|c| c.to_lowercase()).collect();
+    // Lowercasing can change the length of some characters; fall back to
+    // the whole text rather than slicing at the wrong place.
+    let needle: Vec<char> = query.chars().collect();
+    let pos = lower.windows(needle.len()).position(|w| w == needle)?;
+    if lower.len() != chars.len() {
+        return Some(text.to_string());
+    }
+    let start = pos.saturating_sub(SNIPPET_CONTEXT);
+    let end = (pos + needle.len() + SNIPPET_CONTEXT).min(chars.len());
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    out.extend(&chars[start..end]);
+    if end < chars.len() {
+        out.push('…');
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shop() -> (String, C4ModelData) {
+        let model = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "node-1", "data": { "name": "Checkout", "kind": "system",
+                    "description": "Takes the payment and hands off to the Payment Gateway" } },
+                { "id": "node-2", "data": { "name": "Payment Gateway", "kind": "system", "external": true } },
+                { "id": "node-3", "data": { "name": "Payments API", "kind": "container" } },
+            ],
+            "edges": [
+                { "id": "edge-1", "source": "node-1", "target": "node-2", "data": { "label": "Charges via payment API" } },
+            ],
+            "flows": [
+                { "id": "flow-1", "name": "Payment", "steps": [
+                    { "id": "step-1", "label": "Customer pays" },
+                    { "id": "step-2", "label": "Check card", "branches": [
+                        { "condition": "declined", "steps": [
+                            { "id": "step-3", "description": "Retry the PAYMENT later" },
+                        ] },
+                    ] },
+                ] },
+            ],
+        }))
+        .unwrap();
+        ("shop".to_string(), model)
+    }
+
+    fn fields(hits: &[SearchHit]) -> Vec<(&str, Option<&str>)> {
+        hits.iter()
+            .map(|h| (h.field, h.node_id.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn matches_every_field_and_ranks_exact_names_first() {
+        let hits = search_in([shop()], "payment gateway");
+        assert_eq!(
+            fields(&hits),
+            vec![("name", Some("node-2")), ("description", Some("node-1"))]
+        );
+        assert_eq!(hits[0].snippet, "Payment Gateway");
+        assert!(hits.iter().all(|h| h.model == "shop"));
+
+        let hits = search_in([shop()], "payment");
+        assert_eq!(
+            fields(&hits),
+            vec![
+                ("name", Some("node-2")),
+                ("name", Some("node-3")),
+                ("flowName", None),
+                ("edgeLabel", None),
+                ("flowStep", None),
+                ("description", Some("node-1")),
+            ]
+        );
+        assert_eq!(hits[4].snippet, "Retry the PAYMENT later");
+    }
+
+    #[test]
+    fn matching_ignores_case_and_surrounding_space() {
+        let lower = fields(&search_in([shop()], "checkout"));
+        assert_eq!(lower, vec![("name", Some("node-1"))]);
+        assert_eq!(fields(&search_in([shop()], "  CHECKOUT ")), lower);
+        assert_eq!(
+            fields(&search_in([shop()], "CUSTOMER")),
+            vec![("flowStep", None)]
+        );
+    }
+
+    #[test]
+    fn empty_or_unmatched_query_finds_nothing() {
+        assert!(search_in([shop()], "   ").is_empty());
+        assert!(search_in([shop()], "invoice").is_empty());
+    }
+
+    #[test]
+    fn snippet_trims_long_text_around_the_match() {
+        let text = format!("{}Gateway{}", "a".repeat(60), "b".repeat(60));
+        let snippet = snippet(&text, "gateway").unwrap();
+        assert_eq!(
+            snippet,
+            format!("…{}Gateway{}…", "a".repeat(40), "b".repeat(40))
+        );
+    }
+}
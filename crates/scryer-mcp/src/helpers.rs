@@ -1,6 +1,9 @@
+use crate::messages::TaskMessages;
 use rmcp::ErrorData as McpError;
-use scryer_core::{C4Edge, C4Kind, C4ModelData, C4Node, C4Shape, Flow, Status};
-use std::collections::HashMap;
+use scryer_core::{
+    C4Edge, C4Kind, C4ModelData, C4Node, C4Shape, Contract, ContractItem, Flow, Group, Status,
+};
+use std::collections::{HashMap, HashSet};
 
 /// Recursively collect all steps (flattened) from a step tree.
 pub(crate) fn collect_all_steps(steps: &[scryer_core::FlowStep]) -> Vec<&scryer_core::FlowStep> {
@@ -14,6 +17,70 @@ pub(crate) fn collect_all_steps(steps: &[scryer_core::FlowStep]) -> Vec<&scryer_
     result
 }
 
+/// Names referenced by `@[Name]` mentions in `text`, in order.
+pub(crate) fn mentions(text: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut search_from = 0;
+    while let Some(start) = text[search_from..].find("@[") {
+        let abs_start = search_from + start + 2;
+        let Some(end) = text[abs_start..].find(']') else {
+            break;
+        };
+        found.push(&text[abs_start..abs_start + end]);
+        search_from = abs_start + end + 1;
+    }
+    found
+}
+
+/// Lowercased words of `text`, splitting camelCase and snake_case names so
+/// `submitOrder` and "submit the order" share words. Short words are dropped.
+fn words(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut cur = String::new();
+    let mut prev_lower = false;
+    for c in text.chars() {
+        if !c.is_alphanumeric() || (c.is_uppercase() && prev_lower) {
+            if cur.len() >= 3 {
+                out.push(std::mem::take(&mut cur));
+            }
+            cur.clear();
+        }
+        if c.is_alphanumeric() {
+            cur.extend(c.to_lowercase());
+        }
+        prev_lower = c.is_lowercase();
+    }
+    if cur.len() >= 3 {
+        out.push(cur);
+    }
+    out
+}
+
+/// Process nodes whose names best match a flow step's text, best first (at
+/// most three). Words match when equal or when one is a prefix of the other
+/// (4+ letters), so "submits" finds `submitOrder`.
+pub(crate) fn suggest_step_links<'a>(model: &'a C4ModelData, text: &str) -> Vec<&'a C4Node> {
+    let step_words = words(text);
+    let matches = |a: &str, b: &str| {
+        a == b || (a.len().min(b.len()) >= 4 && (a.starts_with(b) || b.starts_with(a)))
+    };
+    let mut scored: Vec<(usize, &C4Node)> = model
+        .nodes
+        .iter()
+        .filter(|n| n.data.kind == C4Kind::Process)
+        .map(|n| {
+            let score = words(&n.data.name)
+                .iter()
+                .filter(|w| step_words.iter().any(|s| matches(s, w)))
+                .count();
+            (score, n)
+        })
+        .filter(|(score, _)| *score > 0)
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().take(3).map(|(_, n)| n).collect()
+}
+
 /// Recursively migrate label → description on steps that have label but no description.
 pub(crate) fn migrate_flow_labels(steps: &mut [scryer_core::FlowStep]) {
     for step in steps.iter_mut() {
@@ -28,7 +95,7 @@ pub(crate) fn migrate_flow_labels(steps: &mut [scryer_core::FlowStep]) {
     }
 }
 
-/// Recursively strip UI-only fields (position, type, refPositions) from a JSON value.
+/// Recursively strip UI-only fields (position, node type, refPositions) from a JSON value.
 pub(crate) fn strip_ui_fields(val: &mut serde_json::Value) {
     strip_fields(val, false);
 }
@@ -40,9 +107,13 @@ pub(crate) fn strip_fields_compact(val: &mut serde_json::Value) {
 fn strip_fields(val: &mut serde_json::Value, compact: bool) {
     match val {
         serde_json::Value::Object(map) => {
-            // Always strip UI-only fields
+            // Always strip UI-only fields. Only node objects (the ones with
+            // `data`) carry the renderer `type`; a model property's `type` is
+            // content and stays.
             map.remove("position");
-            map.remove("type");
+            if map.contains_key("data") {
+                map.remove("type");
+            }
             map.remove("refPositions");
 
             if compact {
@@ -82,14 +153,7 @@ pub(crate) fn externalize_attachments(val: &mut serde_json::Value, model_name: &
                 if let Some(serde_json::Value::Object(img)) = map.get_mut("image") {
                     let mime = img.get("mimeType").and_then(|v| v.as_str()).unwrap_or("image/png").to_string();
                     let filename = img.get("filename").and_then(|v| v.as_str()).unwrap_or("image").to_string();
-                    let ext = match mime.as_str() {
-                        "image/png" => "png",
-                        "image/jpeg" | "image/jpg" => "jpg",
-                        "image/gif" => "gif",
-                        "image/webp" => "webp",
-                        "image/svg+xml" => "svg",
-                        _ => "bin",
-                    };
+                    let ext = scryer_core::attachments::extension_for_mime(&mime);
                     if let Some(serde_json::Value::String(b64)) = img.get("data") {
                         if let Ok(bytes) = base64_decode(b64) {
                             let tmp_dir = std::env::temp_dir().join("scryer-attachments").join(model_name);
@@ -125,7 +189,7 @@ fn base64_decode(input: &str) -> Result<Vec<u8>, String> {
 }
 
 pub(crate) fn parse_kind(s: &str) -> Result<C4Kind, McpError> {
-    match s {
+    match s.to_ascii_lowercase().as_str() {
         "person" => Ok(C4Kind::Person),
         "system" => Ok(C4Kind::System),
         "container" => Ok(C4Kind::Container),
@@ -144,8 +208,9 @@ pub(crate) fn parse_kind(s: &str) -> Result<C4Kind, McpError> {
 }
 
 pub(crate) fn parse_status(s: &str) -> Option<Status> {
-    match s {
+    match s.to_ascii_lowercase().as_str() {
         "proposed" => Some(Status::Proposed),
+        "in_progress" => Some(Status::InProgress),
         "implemented" => Some(Status::Implemented),
         "verified" => Some(Status::Verified),
         "vagrant" => Some(Status::Vagrant),
@@ -154,7 +219,7 @@ pub(crate) fn parse_status(s: &str) -> Option<Status> {
 }
 
 pub(crate) fn parse_shape(s: &str) -> Option<C4Shape> {
-    match s {
+    match s.to_ascii_lowercase().as_str() {
         "rectangle" => Some(C4Shape::Rectangle),
         "person" => Some(C4Shape::Person),
         "cylinder" => Some(C4Shape::Cylinder),
@@ -179,13 +244,7 @@ pub(crate) fn kind_str(k: &C4Kind) -> &'static str {
 }
 
 pub(crate) fn status_str(s: &Option<Status>) -> &'static str {
-    match s {
-        Some(Status::Proposed) => "proposed",
-        Some(Status::Implemented) => "implemented",
-        Some(Status::Verified) => "verified",
-        Some(Status::Vagrant) => "vagrant",
-        None => "none",
-    }
+    s.as_ref().map_or("none", Status::as_str)
 }
 
 pub(crate) fn shape_str(s: &Option<C4Shape>) -> &'static str {
@@ -205,35 +264,278 @@ pub(crate) fn opt_str(s: &Option<String>) -> &str {
     s.as_deref().unwrap_or("none")
 }
 
+/// Resolve a node by ID, or by display name (case-insensitive) when no ID is
+/// given. Ambiguous names list the candidate IDs.
+pub(crate) fn resolve_node_id(
+    model: &C4ModelData,
+    node_id: Option<&str>,
+    node_name: Option<&str>,
+) -> Result<String, String> {
+    if let Some(id) = node_id {
+        return if model.nodes.iter().any(|n| n.id == id) {
+            Ok(id.to_string())
+        } else {
+            Err(format!("Node '{}' not found", id))
+        };
+    }
+    let Some(name) = node_name else {
+        return Err("Provide either node_id or node_name".to_string());
+    };
+    let matches: Vec<&C4Node> = model
+        .nodes
+        .iter()
+        .filter(|n| n.data.name.eq_ignore_ascii_case(name))
+        .collect();
+    match matches.as_slice() {
+        [] => Err(format!("No node named '{}'", name)),
+        [n] => Ok(n.id.clone()),
+        many => Err(format!(
+            "Name '{}' is ambiguous — matches {}. Pass node_id instead.",
+            name,
+            many.iter()
+                .map(|n| n.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// IDs of `node_id` and everything nested under it.
+pub(crate) fn subtree_ids(model: &C4ModelData, node_id: &str) -> HashSet<String> {
+    let mut ids: HashSet<String> = HashSet::new();
+    ids.insert(node_id.to_string());
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for n in &model.nodes {
+            if let Some(pid) = &n.parent_id {
+                if ids.contains(pid) && !ids.contains(&n.id) {
+                    ids.insert(n.id.clone());
+                    changed = true;
+                }
+            }
+        }
+    }
+    ids
+}
+
+/// Remove `roots` and everything nested under them, along with their edges,
+/// source map entries, ref positions, and group memberships. Groups this
+/// empties are pruned when the model is written. Returns the removed IDs.
+pub(crate) fn remove_subtree(model: &mut C4ModelData, roots: &[String]) -> HashSet<String> {
+    let mut ids: HashSet<String> = roots
+        .iter()
+        .filter(|id| model.nodes.iter().any(|n| &n.id == *id))
+        .cloned()
+        .collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for n in &model.nodes {
+            if let Some(pid) = &n.parent_id {
+                if ids.contains(pid) && !ids.contains(&n.id) {
+                    ids.insert(n.id.clone());
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    model.nodes.retain(|n| !ids.contains(&n.id));
+    model
+        .edges
+        .retain(|e| !ids.contains(&e.source) && !ids.contains(&e.target));
+    model.source_map.retain(|id, _| !ids.contains(id));
+    scryer_core::prune_ref_positions(model, &ids);
+    for group in &mut model.groups {
+        group.member_ids.retain(|id| !ids.contains(id));
+    }
+    ids
+}
+
+/// Check if `node_id` sits anywhere below `ancestor_id` in the parent hierarchy.
+pub(crate) fn is_descendant_of(model: &C4ModelData, node_id: &str, ancestor_id: &str) -> bool {
+    let mut cur = node_id;
+    loop {
+        let parent = model
+            .nodes
+            .iter()
+            .find(|n| n.id == cur)
+            .and_then(|n| n.parent_id.as_deref());
+        match parent {
+            Some(pid) if pid == ancestor_id => return true,
+            Some(pid) => cur = pid,
+            None => return false,
+        }
+    }
+}
+
+/// Check if a node's direct parent is an external system.
+pub(crate) fn has_external_parent(model: &C4ModelData, node: &C4Node) -> bool {
+    node.parent_id.as_deref().is_some_and(|pid| {
+        model
+            .nodes
+            .iter()
+            .any(|p| p.id == pid && p.data.external == Some(true))
+    })
+}
+
+/// ID, parent→children, and source→edges lookups over a model, built once so
+/// tools that walk the hierarchy or dependencies per node (e.g. `get_task`)
+/// don't rescan the node or edge list on every step.
+pub(crate) struct NodeIndex<'a> {
+    by_id: HashMap<&'a str, &'a C4Node>,
+    children: HashMap<&'a str, Vec<&'a C4Node>>,
+    outgoing: HashMap<&'a str, Vec<&'a C4Edge>>,
+    /// Lookups served, so tests can check the work stays linear.
+    #[cfg(test)]
+    lookups: std::cell::Cell<usize>,
+}
+
+impl<'a> NodeIndex<'a> {
+    pub(crate) fn new(model: &'a C4ModelData) -> Self {
+        let mut by_id = HashMap::with_capacity(model.nodes.len());
+        let mut children: HashMap<&str, Vec<&C4Node>> = HashMap::new();
+        for node in &model.nodes {
+            by_id.insert(node.id.as_str(), node);
+            if let Some(pid) = node.parent_id.as_deref() {
+                children.entry(pid).or_default().push(node);
+            }
+        }
+        let mut outgoing: HashMap<&str, Vec<&C4Edge>> = HashMap::new();
+        for edge in &model.edges {
+            outgoing.entry(edge.source.as_str()).or_default().push(edge);
+        }
+        Self {
+            by_id,
+            children,
+            outgoing,
+            #[cfg(test)]
+            lookups: Default::default(),
+        }
+    }
+
+    pub(crate) fn get(&self, id: &str) -> Option<&'a C4Node> {
+        #[cfg(test)]
+        self.lookups.set(self.lookups.get() + 1);
+        self.by_id.get(id).copied()
+    }
+
+    /// Direct children of `id`, in model order.
+    pub(crate) fn children(&self, id: &str) -> &[&'a C4Node] {
+        #[cfg(test)]
+        self.lookups.set(self.lookups.get() + 1);
+        self.children.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Edges leaving `id`, in model order.
+    pub(crate) fn outgoing(&self, id: &str) -> &[&'a C4Edge] {
+        #[cfg(test)]
+        self.lookups.set(self.lookups.get() + 1);
+        self.outgoing.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Ancestors of `id` from the root down, excluding the node itself. Stops
+    /// at a missing parent, or after visiting every node if the parents cycle.
+    pub(crate) fn ancestors(&self, id: &str) -> Vec<&'a C4Node> {
+        let mut chain: Vec<&C4Node> = Vec::new();
+        let mut cur = self.get(id).and_then(|n| n.parent_id.as_deref());
+        while let Some(pid) = cur {
+            let Some(parent) = self.get(pid) else { break };
+            if chain.len() == self.by_id.len() {
+                break;
+            }
+            chain.push(parent);
+            cur = parent.parent_id.as_deref();
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Indexed [`is_descendant_of`].
+    pub(crate) fn is_descendant_of(&self, node_id: &str, ancestor_id: &str) -> bool {
+        self.ancestors(node_id).iter().any(|a| a.id == ancestor_id)
+    }
+
+    /// Indexed [`has_external_parent`].
+    pub(crate) fn has_external_parent(&self, node: &C4Node) -> bool {
+        node.parent_id
+            .as_deref()
+            .and_then(|pid| self.get(pid))
+            .is_some_and(|p| p.data.external == Some(true))
+    }
+}
+
+/// Marks a contract item as an override: `"~Never use unwrap"` drops the
+/// inherited item with that text from the same section instead of adding an
+/// item. Matching ignores case and surrounding whitespace.
+pub(crate) const CONTRACT_OVERRIDE_PREFIX: char = '~';
+
+/// The inherited item text an override item removes, lowercased for matching,
+/// or `None` if `item` is an ordinary item.
+pub(crate) fn contract_override_target(item: &ContractItem) -> Option<String> {
+    item.text()
+        .trim()
+        .strip_prefix(CONTRACT_OVERRIDE_PREFIX)
+        .map(|t| t.trim().to_lowercase())
+}
+
+/// Merge a node's contract onto its ancestors' (`chain` is root first). Items
+/// are additive, except overrides, which remove a matching item inherited
+/// from further up — so the closest node wins.
+pub(crate) fn merge_contract(chain: &[&C4Node], node: &C4Node) -> Contract {
+    fn merge_section(merged: &mut Vec<ContractItem>, items: &[ContractItem]) {
+        for item in items {
+            match contract_override_target(item) {
+                Some(target) => merged.retain(|m| m.text().trim().to_lowercase() != target),
+                None => merged.push(item.clone()),
+            }
+        }
+    }
+
+    let mut merged = Contract::default();
+    for contract in chain
+        .iter()
+        .map(|n| &n.data.contract)
+        .chain(std::iter::once(&node.data.contract))
+    {
+        merge_section(&mut merged.expect, &contract.expect);
+        merge_section(&mut merged.ask, &contract.ask);
+        merge_section(&mut merged.never, &contract.never);
+    }
+    merged
+}
+
 pub(crate) fn format_contract_and_notes(
     name: &str,
     contract: &scryer_core::Contract,
     notes: &[String],
+    m: &TaskMessages,
 ) -> String {
     let mut out = String::new();
     if !contract.is_empty() {
-        out.push_str(&format!("\n{} — Contract (MUST follow):\n", name));
+        out.push_str(&format!("\n{} — {}\n", name, m.contract_for));
         if !contract.expect.is_empty() {
-            out.push_str("  MUST:\n");
+            out.push_str(&format!("  {}\n", m.must));
             for item in &contract.expect {
                 out.push_str(&format!("    - {}\n", item));
             }
         }
         if !contract.ask.is_empty() {
-            out.push_str("  ASK USER FIRST:\n");
+            out.push_str(&format!("  {}\n", m.ask_user));
             for item in &contract.ask {
                 out.push_str(&format!("    - {}\n", item));
             }
         }
         if !contract.never.is_empty() {
-            out.push_str("  NEVER:\n");
+            out.push_str(&format!("  {}\n", m.never));
             for item in &contract.never {
                 out.push_str(&format!("    - {}\n", item));
             }
         }
     }
     if !notes.is_empty() {
-        out.push_str(&format!("\n{} — Notes:\n", name));
+        out.push_str(&format!("\n{} — {}\n", name, m.notes));
         for d in notes {
             out.push_str(&format!("  - {}\n", d));
         }
@@ -241,6 +543,28 @@ pub(crate) fn format_contract_and_notes(
     out
 }
 
+pub(crate) fn is_async_edge(edge: &C4Edge) -> bool {
+    edge.data
+        .as_ref()
+        .is_some_and(|d| d.async_.unwrap_or(false))
+}
+
+/// Suffix describing how an edge couples its endpoints, e.g. ` [REST, async]`.
+pub(crate) fn edge_coupling(edge: &C4Edge) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    if let Some(method) = edge.data.as_ref().and_then(|d| d.method.as_deref()) {
+        parts.push(method);
+    }
+    if is_async_edge(edge) {
+        parts.push("async");
+    }
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", parts.join(", "))
+    }
+}
+
 pub(crate) fn find_next_name<'a>(
     blocked: &[&'a scryer_core::C4Node],
     ready: &[&'a scryer_core::C4Node],
@@ -258,8 +582,12 @@ pub(crate) fn find_next_name<'a>(
     blocked.first().map(|n| n.data.name.as_str())
 }
 
-pub(crate) fn format_done_message(model: &C4ModelData) -> String {
-    let mut output = String::from("All tasks complete.");
+pub(crate) fn format_done_message(
+    model: &C4ModelData,
+    model_ref: &str,
+    m: &TaskMessages,
+) -> String {
+    let mut output = String::from(m.all_complete);
 
     // Check for member nodes (operations/processes/models) that are still proposed
     let mut pending_members: Vec<(&C4Node, &str)> = Vec::new();
@@ -270,20 +598,21 @@ pub(crate) fn format_done_message(model: &C4ModelData) -> String {
         for member in model.nodes.iter().filter(|n| {
             n.parent_id.as_deref() == Some(&node.id)
                 && matches!(n.data.kind, C4Kind::Operation | C4Kind::Process | C4Kind::Model)
-                && matches!(n.data.status, Some(Status::Proposed))
+                && matches!(n.data.status, Some(Status::Proposed) | Some(Status::InProgress))
         }) {
             pending_members.push((member, &node.data.name));
         }
     }
     if !pending_members.is_empty() {
-        output.push_str("\n\nThese member nodes are still proposed — mark as `implemented` with a reason explaining what was built:\n");
+        output.push_str(&format!("\n\n{}\n", m.members_pending));
         for (member, parent_name) in &pending_members {
             output.push_str(&format!(
-                "  - {} [{}] ({}, {}) in {}\n",
+                "  - {} [{}] ({}, {}) {} {}\n",
                 member.data.name,
                 member.id,
                 kind_str(&member.data.kind),
                 status_str(&member.data.status),
+                m.in_parent,
                 parent_name
             ));
         }
@@ -291,22 +620,110 @@ pub(crate) fn format_done_message(model: &C4ModelData) -> String {
 
     if model.flows.is_empty() {
         if pending_members.is_empty() {
-            output.push_str(" Nothing to build.");
+            output.push_str(&format!(" {}", m.nothing_to_build));
         }
         return output;
     }
 
-    output.push_str("\n\nPlease validate that the model's flows still accurately describe the system behavior. Flows are integration test specs — update step descriptions as needed using `set_flows`. Use @[Name] mentions in step descriptions to reference architecture nodes. When a test exists for a flow, use `update_source_map` to link the flow to the test file.\n");
+    output.push_str(&format!("\n\n{}\n", m.validate_flows));
 
     for flow in &model.flows {
         let all_steps = collect_all_steps(&flow.steps);
-        output.push_str(&format!("\n**{}** — {} steps\n", flow.name, all_steps.len()));
+        output.push_str(&format!(
+            "\n**{}** — {} {}\n",
+            flow.name,
+            all_steps.len(),
+            m.steps
+        ));
+    }
+
+    // Steps that don't point at the architecture yet, with likely processes
+    // to mention, so the fix is one update_steps call per flow.
+    let names: HashSet<&str> = model.nodes.iter().map(|n| n.data.name.as_str()).collect();
+    let mut to_link: Vec<String> = Vec::new();
+    let mut example: Option<(&str, &str, String)> = None; // (flow_id, step_id, text)
+    for flow in &model.flows {
+        for step in collect_all_steps(&flow.steps) {
+            let text = step
+                .description
+                .as_deref()
+                .or(step.label.as_deref())
+                .unwrap_or("");
+            let found = mentions(text);
+            let broken: Vec<&str> = found
+                .iter()
+                .copied()
+                .filter(|m| !names.contains(m))
+                .collect();
+            let issue = if found.is_empty() {
+                m.no_mention.to_string()
+            } else if !broken.is_empty() {
+                format!("{} @[{}]", m.mentions_missing, broken.join("], @["))
+            } else {
+                continue;
+            };
+            let candidates = suggest_step_links(model, text);
+            let mut line = format!(
+                "  - {} {} {} {}: \"{}\" — {}",
+                m.flow, flow.id, m.step, step.id, text, issue
+            );
+            if candidates.is_empty() {
+                line.push_str(&format!(". {}", m.no_candidates));
+            } else {
+                let list: Vec<String> = candidates
+                    .iter()
+                    .map(|n| format!("@[{}] [{}]", n.data.name, n.id))
+                    .collect();
+                line.push_str(&format!(". {}: {}", m.candidates, list.join(", ")));
+                if example.is_none() {
+                    example = Some((
+                        &flow.id,
+                        &step.id,
+                        format!("{} (@[{}])", text, candidates[0].data.name),
+                    ));
+                }
+            }
+            to_link.push(line);
+        }
+    }
+    if !to_link.is_empty() {
+        output.push_str(&format!(
+            "\n{} ({}) — {}\n{}\n",
+            m.steps_to_link,
+            to_link.len(),
+            m.steps_to_link_hint,
+            to_link.join("\n")
+        ));
+        if let Some((flow_id, step_id, text)) = example {
+            output.push_str(&format!(
+                "{}\n```\nupdate_steps(model: \"{}\", flow_id: \"{}\", steps: [{{step_id: \"{}\", description: {:?}}}])\n```\n",
+                m.for_example, model_ref, flow_id, step_id, text
+            ));
+        }
     }
 
     output
 }
 
-pub(crate) fn compute_diff(baseline: &C4ModelData, current: &C4ModelData) -> String {
+/// Copy of `model` cut down to the nodes in `ids`, the edges between them,
+/// and the groups with a member among them. Flows are dropped, so a diff of
+/// two scoped copies only reports changes inside the scope.
+pub(crate) fn scope_to_nodes(model: &C4ModelData, ids: &HashSet<String>) -> C4ModelData {
+    let mut scoped = model.clone();
+    scoped.nodes.retain(|n| ids.contains(&n.id));
+    scoped
+        .edges
+        .retain(|e| ids.contains(&e.source) && ids.contains(&e.target));
+    scoped
+        .groups
+        .retain(|g| g.member_ids.iter().any(|m| ids.contains(m)));
+    scoped.source_map.retain(|k, _| ids.contains(k));
+    scoped.flows.clear();
+    scoped
+}
+
+/// Human-readable diff from `baseline` to `current`, or `None` if nothing differs.
+pub(crate) fn compute_diff(baseline: &C4ModelData, current: &C4ModelData) -> Option<String> {
     let base_nodes: HashMap<&str, &C4Node> =
         baseline.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
     let curr_nodes: HashMap<&str, &C4Node> =
@@ -345,6 +762,12 @@ pub(crate) fn compute_diff(baseline: &C4ModelData, current: &C4ModelData) -> Str
             if n.data.status.is_some() {
                 detail.push_str(&format!(", status={}", status_str(&n.data.status)));
             }
+            if !n.data.tags.is_empty() {
+                detail.push_str(&format!(", tags=[{}]", n.data.tags.join(", ")));
+            }
+            if let Some(owner) = &n.data.owner {
+                detail.push_str(&format!(", owner={}", owner));
+            }
             if !n.data.description.is_empty() {
                 let desc = if n.data.description.len() > 80 {
                     format!("{}...", &n.data.description[..77])
@@ -459,6 +882,34 @@ pub(crate) fn compute_diff(baseline: &C4ModelData, current: &C4ModelData) -> Str
                     base.data.properties.len(),
                     curr.data.properties.len()
                 ));
+                for prop in &curr.data.properties {
+                    let Some(old) = base.data.properties.iter().find(|p| p.label == prop.label)
+                    else {
+                        continue;
+                    };
+                    if old.type_ != prop.type_ {
+                        changes.push(format!(
+                            ".{} type {} -> {}",
+                            prop.label,
+                            opt_str(&old.type_),
+                            opt_str(&prop.type_)
+                        ));
+                    }
+                }
+            }
+            if base.data.owner != curr.data.owner {
+                changes.push(format!(
+                    "owner {} -> {}",
+                    opt_str(&base.data.owner),
+                    opt_str(&curr.data.owner)
+                ));
+            }
+            if base.data.tags != curr.data.tags {
+                changes.push(format!(
+                    "tags [{}] -> [{}]",
+                    base.data.tags.join(", "),
+                    curr.data.tags.join(", ")
+                ));
             }
             if !changes.is_empty() {
                 mod_lines.push(format!(
@@ -488,9 +939,14 @@ pub(crate) fn compute_diff(baseline: &C4ModelData, current: &C4ModelData) -> Str
         let mut lines = vec![format!("Edges added ({}):", edges_added.len())];
         for e in &edges_added {
             let label = e.data.as_ref().map(|d| d.label.as_str()).unwrap_or("");
+            let is_async = is_async_edge(e);
             lines.push(format!(
-                "  - {}: {} -> {} \"{}\"",
-                e.id, e.source, e.target, label
+                "  - {}: {} -> {} \"{}\"{}",
+                e.id,
+                e.source,
+                e.target,
+                label,
+                if is_async { " (async)" } else { "" }
             ));
         }
         sections.push(lines.join("\n"));
@@ -535,6 +991,15 @@ pub(crate) fn compute_diff(baseline: &C4ModelData, current: &C4ModelData) -> Str
                     curr_method.unwrap_or("none")
                 ));
             }
+            let base_async = base_data.is_some_and(|d| d.async_.unwrap_or(false));
+            let curr_async = curr_data.is_some_and(|d| d.async_.unwrap_or(false));
+            if base_async != curr_async {
+                changes.push(format!(
+                    "{} -> {}",
+                    if base_async { "async" } else { "sync" },
+                    if curr_async { "async" } else { "sync" }
+                ));
+            }
             if !changes.is_empty() {
                 edge_mod_lines.push(format!("  - {}: {}", id, changes.join(", ")));
             }
@@ -625,9 +1090,233 @@ pub(crate) fn compute_diff(baseline: &C4ModelData, current: &C4ModelData) -> Str
         ));
     }
 
+    // --- Groups ---
+    let base_groups: HashMap<&str, &Group> =
+        baseline.groups.iter().map(|g| (g.id.as_str(), g)).collect();
+    let mut group_lines: Vec<String> = Vec::new();
+    for curr in &current.groups {
+        let Some(base) = base_groups.get(curr.id.as_str()) else {
+            group_lines.push(format!("  + {} \"{}\"", curr.id, curr.name));
+            continue;
+        };
+        let mut changes: Vec<String> = Vec::new();
+        if base.name != curr.name {
+            changes.push(format!("name \"{}\" -> \"{}\"", base.name, curr.name));
+        }
+        if base.member_ids != curr.member_ids {
+            changes.push(format!(
+                "members {} -> {}",
+                base.member_ids.len(),
+                curr.member_ids.len()
+            ));
+        }
+        if base.color != curr.color {
+            changes.push(format!(
+                "color {} -> {}",
+                base.color.as_deref().unwrap_or("none"),
+                curr.color.as_deref().unwrap_or("none")
+            ));
+        }
+        if !changes.is_empty() {
+            group_lines.push(format!("  ~ {}: {}", curr.id, changes.join(", ")));
+        }
+    }
+    for base in &baseline.groups {
+        if !current.groups.iter().any(|g| g.id == base.id) {
+            group_lines.push(format!("  - {} \"{}\"", base.id, base.name));
+        }
+    }
+    if !group_lines.is_empty() {
+        sections.push(format!(
+            "Groups changed ({}):\n{}",
+            group_lines.len(),
+            group_lines.join("\n")
+        ));
+    }
+
     if sections.is_empty() {
-        "No changes since last seen.".to_string()
+        None
     } else {
-        sections.join("\n\n")
+        Some(sections.join("\n\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enum_parsing_ignores_case() {
+        assert!(matches!(parse_kind("System"), Ok(C4Kind::System)));
+        assert_eq!(parse_shape("Cylinder"), Some(C4Shape::Cylinder));
+        assert_eq!(parse_status("Implemented"), Some(Status::Implemented));
+    }
+
+    fn contract_node(id: &str, parent: Option<&str>, contract: serde_json::Value) -> C4Node {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "parentId": parent,
+            "data": { "name": id, "kind": "container", "contract": contract },
+        }))
+        .unwrap()
+    }
+
+    fn texts(items: &[ContractItem]) -> Vec<&str> {
+        items.iter().map(|i| i.text()).collect()
+    }
+
+    #[test]
+    fn merge_contract_adds_inherited_items() {
+        let system = contract_node(
+            "sys",
+            None,
+            serde_json::json!({ "expect": ["Return JSON"] }),
+        );
+        let node = contract_node("api", Some("sys"), serde_json::json!({}));
+        let merged = merge_contract(&[&system], &node);
+        assert_eq!(texts(&merged.expect), ["Return JSON"]);
+        assert!(merged.never.is_empty());
+    }
+
+    #[test]
+    fn merge_contract_extends_inherited_items() {
+        let system = contract_node("sys", None, serde_json::json!({ "never": ["Use unwrap"] }));
+        let node = contract_node(
+            "api",
+            Some("sys"),
+            serde_json::json!({ "never": ["Log secrets"], "ask": ["Which cache?"] }),
+        );
+        let merged = merge_contract(&[&system], &node);
+        assert_eq!(texts(&merged.never), ["Use unwrap", "Log secrets"]);
+        assert_eq!(texts(&merged.ask), ["Which cache?"]);
+    }
+
+    #[test]
+    fn merge_contract_override_removes_inherited_item() {
+        let system = contract_node(
+            "sys",
+            None,
+            serde_json::json!({ "never": ["Use unwrap", "Log secrets"], "expect": ["Use unwrap"] }),
+        );
+        let container = contract_node("app", Some("sys"), serde_json::json!({}));
+        let tests = contract_node(
+            "tests",
+            Some("app"),
+            serde_json::json!({ "never": [{ "text": " ~ use UNWRAP " }] }),
+        );
+        let merged = merge_contract(&[&system, &container], &tests);
+        // Only the matching item in the same section goes; the override itself isn't shown
+        assert_eq!(texts(&merged.never), ["Log secrets"]);
+        assert_eq!(texts(&merged.expect), ["Use unwrap"]);
+
+        // Closest node wins: a re-added item below an override survives
+        let relaxed = contract_node(
+            "app",
+            Some("sys"),
+            serde_json::json!({ "never": ["~Use unwrap"] }),
+        );
+        let strict = contract_node(
+            "core",
+            Some("app"),
+            serde_json::json!({ "never": ["Use unwrap"] }),
+        );
+        let merged = merge_contract(&[&system, &relaxed], &strict);
+        assert_eq!(texts(&merged.never), ["Log secrets", "Use unwrap"]);
+    }
+
+    #[test]
+    fn node_index_scales_to_large_models() {
+        // 20 systems x 50 containers x 20 components — ~21k nodes and ~20k
+        // edges, looked up through the index a bounded number of times per
+        // node.
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for s in 0..20 {
+            let sys = format!("sys-{}", s);
+            nodes.push(serde_json::json!({ "id": sys, "data": { "name": sys, "kind": "system", "external": s == 0 } }));
+            for c in 0..50 {
+                let con = format!("{}-con-{}", sys, c);
+                nodes.push(serde_json::json!({ "id": con, "parentId": sys, "data": { "name": con, "kind": "container" } }));
+                for k in 0..20 {
+                    let comp = format!("{}-comp-{}", con, k);
+                    nodes.push(serde_json::json!({ "id": comp, "parentId": con, "data": { "name": comp, "kind": "component" } }));
+                    if k > 0 {
+                        let prev = format!("{}-comp-{}", con, k - 1);
+                        edges.push(serde_json::json!({ "id": format!("{}-e", comp), "source": comp, "target": prev }));
+                    }
+                }
+            }
+        }
+        let model: C4ModelData =
+            serde_json::from_value(serde_json::json!({ "nodes": nodes, "edges": edges })).unwrap();
+
+        let index = NodeIndex::new(&model);
+        let (mut external, mut children, mut outgoing) = (0, 0, 0);
+        for node in &model.nodes {
+            let chain = index.ancestors(&node.id);
+            if let Some(pid) = node.parent_id.as_deref() {
+                assert_eq!(chain.last().map(|n| n.id.as_str()), Some(pid));
+                assert!(index.is_descendant_of(&node.id, &chain[0].id));
+            }
+            external += index.has_external_parent(node) as usize;
+            children += index.children(&node.id).len();
+            outgoing += index.outgoing(&node.id).len();
+        }
+        // Two ancestor walks (at most three lookups each, the hierarchy being
+        // three deep), the parent check, and the children and outgoing
+        // lookups per node.
+        assert!(index.lookups.get() <= model.nodes.len() * 9);
+        assert_eq!(outgoing, model.edges.len());
+
+        assert_eq!(external, 50);
+        assert_eq!(children, model.nodes.len() - 20);
+        assert_eq!(index.children("sys-3").len(), 50);
+        assert_eq!(
+            index
+                .ancestors("sys-3-con-7-comp-1")
+                .iter()
+                .map(|n| n.id.as_str())
+                .collect::<Vec<_>>(),
+            ["sys-3", "sys-3-con-7"]
+        );
+    }
+
+    #[test]
+    fn suggest_step_links_ranks_processes_by_shared_words() {
+        fn node(id: &str, name: &str, kind: &str) -> serde_json::Value {
+            serde_json::json!({ "id": id, "data": { "name": name, "kind": kind } })
+        }
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                node("node-1", "submitOrder", "process"),
+                node("node-2", "Validate Order Payment", "process"),
+                node("node-3", "Submit Order Payment", "process"),
+                node("node-4", "Order Lookup", "process"),
+                node("node-5", "Send Invoice", "process"),
+                node("node-6", "Submit Order Payment Form", "component"),
+            ],
+            "edges": [],
+        }))
+        .unwrap();
+        let suggest = |text: &str| {
+            suggest_step_links(&model, text)
+                .into_iter()
+                .map(|n| n.id.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        // node-3 shares three words; node-1 ("submits" ~ "submit") and node-2
+        // share two each and keep model order; node-4's single word misses
+        // the cap, and the component is never suggested.
+        assert_eq!(
+            suggest("Customer submits the order and payment"),
+            vec!["node-3", "node-1", "node-2"]
+        );
+        // "look" prefixes "lookup", so node-4 leads here
+        assert_eq!(
+            suggest("Look up the order"),
+            vec!["node-4", "node-1", "node-2"]
+        );
+        assert!(suggest("Refund is issued").is_empty());
     }
 }
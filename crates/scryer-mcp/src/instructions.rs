@@ -7,7 +7,7 @@ pub(crate) const INSTRUCTIONS: &str = r#"scryer is a C4 architecture diagramming
 - **Component**: A logical component inside a container. Parent must be a container node.
 - **Operation**: A single function, method, or handler inside a component — code you can point to in one file. Use operation for anything that maps to one function/method. Parent must be a component node. **Name must be a valid identifier** (camelCase or snake_case — match the target language's convention).
 - **Process**: A multi-step behavioral flow that orchestrates multiple operations — like a saga, pipeline, or workflow. Processes describe *sequences*, not individual functions. If it maps to a single function, it's an operation, not a process. Parent must be a component node. Use `type: "process"` in node data.
-- **Model**: A data model inside a component. Parent must be a component node. Has optional `properties` (array of `{label, description}`). Use `type: "model"` in node data. **Name must be a valid type name** (PascalCase or camelCase). **Property labels must be valid identifiers.**
+- **Model**: A data model inside a component. Parent must be a component node. Has optional `properties` (array of `{label, description, type?}`, where `type` is a free-form field type like `"String"` or `"FK → User"`). Use `type: "model"` in node data. **Name must be a valid type name** (PascalCase or camelCase). **Property labels must be valid identifiers.**
 
 ## Node Types
 All nodes use type `"c4"`, except: operation uses `"operation"`, process uses `"process"`, model uses `"model"`.
@@ -26,6 +26,7 @@ The model has an optional `sourceMap` field: a mapping from node or flow ID to a
 Set status on nodes that represent work. Omit status for framework defaults that require no implementation effort. Nodes without status are context — visible but not actionable by `get_task`. Edges do not have status — edge color is inferred from endpoint nodes in the UI.
 
 - **"proposed"** (blue): Planned — doesn't exist yet.
+- **"in_progress"** (violet): Started but not finished. Set this when you begin a task that spans sessions — `get_task` keeps it in the work set and hands it out before untouched proposals.
 - **"implemented"** (amber): Code exists but may be incomplete (stubs, partial implementation, scaffolding).
 - **"verified"** (green): Production-ready. **Gated**: can only be set when ALL inherited `expect` contract items have `passed: true`.
 - **"vagrant"** (rose): Discovered during codebase sync — exists in code but was not part of the architecture plan. Needs review: keep it or remove it.
@@ -35,7 +36,7 @@ A `reason` is required on every status change via `update_nodes`. State what's s
 **Container/system status propagates upward**: when all component children of a container are implemented/verified, `get_task` will prompt you to mark the container as implemented. Same for systems when all containers are done.
 
 ## IDs
-Node IDs: "node-N" (auto-generated). Edge IDs: "edge-{source}-{target}". Use `get_model` to discover existing IDs.
+Node IDs: "node-N" (auto-generated). Edge IDs: "edge-{source}-{target}", with a "-2", "-3", … suffix for further edges between the same pair. Use `get_model` to discover existing IDs.
 
 ## Modeling workflow
 Call `get_rules` before creating or editing a model — it contains the full modeling workflow and C4 rules.
@@ -47,6 +48,7 @@ When building code from a model, use `get_task` in a loop. Each call returns one
 3. Mark the node(s) as `implemented` via `update_nodes` with a `reason` explaining what was built. Only mark nodes listed in the task.
 4. **Call `get_task` again immediately.** Do not stop after one task — there are always more until it returns "All tasks complete."
 The task system tracks what's done and what's next. Do not read the full model via `get_model` to derive your own implementation order.
+Contracts are additive down the hierarchy. To relax an inherited rule for one subtree, add an item to the same section whose text is `~` plus the inherited item's text (e.g. never: `"~Never use unwrap"`) — it removes the match instead of adding an item, and the closest node wins.
 
 ### Verification (implemented → verified)
 "Verified" is separate from implementation — do not set it during the implementation loop. A node is verified when:
@@ -101,3 +103,42 @@ remove it. The model defines what should exist. Anything not in the model is dri
 If you rename, move, delete, or restructure code that is source-mapped in the model, \
 update the model in the same response using update_nodes. \
 Delete removed nodes with delete_nodes. The model must stay in sync with the code.";
+
+/// Spanish rendering of [`TASK_INSTRUCTIONS`]. Tool names, field names, and
+/// status values stay in English — they're part of the API.
+pub(crate) const TASK_INSTRUCTIONS_ES: &str = "\
+La especificación anterior es tu fuente de verdad: te dice QUÉ construir. \
+Confía en tu conocimiento para frameworks y herramientas conocidos. \
+No investigues la configuración estándar de un framework: ya sabes cómo hacerlo.
+
+Si hay una sección de Contrato, son requisitos vinculantes del usuario. \
+Los puntos DEBE no son negociables: cada uno tiene un indicador passed/failed que condiciona el estado `verified`. \
+Los puntos PREGUNTAR AL USUARIO PRIMERO requieren confirmación antes de decidir. \
+Los puntos NUNCA son restricciones estrictas. Si un punto del contrato incluye una URL, léela para tener contexto.
+
+## Significado de los estados
+- **proposed**: Planificado, todavía sin código. \
+- **implemented**: El código existe pero puede estar incompleto: stubs, implementación parcial, andamiaje. \
+- **verified**: Listo para producción. SOLO se puede establecer cuando todos los puntos `expect` del contrato (incluidos los heredados) tienen `passed: true`. \
+Cada cambio de estado requiere un `reason`: indica qué falta o qué se acaba de completar. Para implemented: \"Falta el middleware de autenticación y el límite de peticiones\". Para verified: \"Todos los puntos del contrato pasan\".
+
+Si algo no está claro o la especificación no cubre una decisión que necesitas tomar, \
+pregunta al usuario: no te pierdas en búsquedas web.
+
+## Después de construir
+1. Marca SOLO el/los nodo(s) indicados arriba como `implemented` con update_nodes. Incluye un `reason` que explique qué se construyó. \
+Incluye `source` en cada nodo: un patrón glob (y line/endLine para operaciones). \
+Contenedores y componentes: `[{\"pattern\": \"src/auth/**/*.ts\"}]`. \
+Operaciones: `[{\"pattern\": \"src/auth/handler.ts\", \"line\": 15, \"endLine\": 42}]`.
+2. Llama a get_task inmediatamente para obtener la siguiente tarea. NO te detengas: hay más tareas.
+3. Repite hasta que get_task devuelva \"All tasks complete.\"
+
+## El modelo es la especificación
+El modelo de arquitectura es tu fuente de verdad. Construye exactamente lo que describe: ni más, ni menos. \
+Si una plantilla o generador añade código que no está en el modelo (colecciones, páginas, bloques, rutas adicionales, etc.), \
+elimínalo. El modelo define lo que debe existir. Todo lo que no esté en el modelo es deriva y debe limpiarse.
+
+## Al modificar código existente
+Si renombras, mueves, eliminas o reestructuras código que está mapeado en el modelo, \
+actualiza el modelo en la misma respuesta con update_nodes. \
+Elimina los nodos borrados con delete_nodes. El modelo debe mantenerse sincronizado con el código.";
@@ -1,5 +1,6 @@
 mod helpers;
 mod instructions;
+mod messages;
 mod server;
 mod tools;
 mod types;
@@ -17,17 +18,127 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         _ => {}
     }
 
+    init_logging();
+
+    let args: Vec<String> = std::env::args().collect();
+    let flag_value = |name: &str| {
+        args.iter()
+            .position(|a| a == name)
+            .and_then(|i| args.get(i + 1))
+            .map(|s| s.as_str())
+    };
+    let http = args.iter().any(|a| a == "--http")
+        || matches!(flag_value("--transport"), Some("http" | "sse"));
+    if http {
+        let port = match flag_value("--port") {
+            Some(p) => p
+                .parse::<u16>()
+                .map_err(|_| format!("Invalid port '{}'", p))?,
+            None => DEFAULT_HTTP_PORT,
+        };
+        return serve_http(port).await;
+    }
+
     let service = ScryerServer::new()
         .serve(rmcp::transport::io::stdio())
         .await
-        .inspect_err(|e| eprintln!("MCP server error: {}", e))?;
+        .inspect_err(|e| tracing::error!("MCP server error: {}", e))?;
     service.waiting().await?;
     Ok(())
 }
 
-/// Write project-scoped MCP config files in the current directory so that
-/// Claude Code and/or Codex discover scryer-mcp when working in this project.
-/// Only writes config for tools that are actually installed.
+/// Log to stderr — stdout carries the MCP protocol and must never see
+/// anything else. Filtered by `SCRYER_LOG` (falling back to `RUST_LOG`),
+/// warnings only by default.
+fn init_logging() {
+    use tracing_subscriber::EnvFilter;
+    let filter = EnvFilter::try_from_env("SCRYER_LOG")
+        .or_else(|_| EnvFilter::try_from_default_env())
+        .unwrap_or_else(|_| EnvFilter::new("warn"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+/// Port for `--transport http` when `--port` isn't given.
+const DEFAULT_HTTP_PORT: u16 = 8765;
+
+/// Serve MCP over streamable HTTP (with SSE responses) at
+/// `http://127.0.0.1:{port}/mcp`, for agents that can't spawn a stdio
+/// process. Binds to localhost only — the models directory is not meant to
+/// be exposed to the network — and refuses requests a browser sends on
+/// behalf of another site (see [`local_only`]).
+async fn serve_http(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+    use rmcp::transport::streamable_http_server::{
+        session::local::LocalSessionManager, StreamableHttpService,
+    };
+
+    let service = StreamableHttpService::new(
+        || Ok(ScryerServer::new()),
+        LocalSessionManager::default().into(),
+        Default::default(),
+    );
+    let router = local_only(axum::Router::new().nest_service("/mcp", service));
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await?;
+    tracing::info!("listening on http://127.0.0.1:{}/mcp", port);
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
+
+/// Reject requests whose `Host` or `Origin` isn't localhost. Binding to
+/// 127.0.0.1 alone doesn't stop DNS rebinding: a page on another domain that
+/// resolves to 127.0.0.1 can still reach the port from the user's browser,
+/// but it can't make the browser send a localhost `Host` or `Origin`.
+fn local_only(router: axum::Router) -> axum::Router {
+    use axum::http::{header, StatusCode};
+    use axum::response::IntoResponse;
+
+    router.layer(axum::middleware::from_fn(
+        |req: axum::extract::Request, next: axum::middleware::Next| async move {
+            let header_str = |name| req.headers().get(name).and_then(|v| v.to_str().ok());
+            let host = header_str(header::HOST).or(req.uri().authority().map(|a| a.as_str()));
+            let origin = header_str(header::ORIGIN);
+            if !host.is_some_and(is_local_host) || !origin.is_none_or(is_local_origin) {
+                return (
+                    StatusCode::FORBIDDEN,
+                    "Only localhost clients may use this server",
+                )
+                    .into_response();
+            }
+            next.run(req).await
+        },
+    ))
+}
+
+/// Whether a `Host` value (with or without a port) names this machine.
+fn is_local_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or(""),
+        None => host.split(':').next().unwrap_or(""),
+    };
+    matches!(
+        name.to_ascii_lowercase().as_str(),
+        "localhost" | "127.0.0.1" | "::1"
+    )
+}
+
+/// Whether an `Origin` (`scheme://host[:port]`) is a localhost page.
+fn is_local_origin(origin: &str) -> bool {
+    origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+        .is_some_and(|rest| !rest.contains('/') && is_local_host(rest))
+}
+
+/// Write MCP config so that Claude Code, Codex, Cursor, Windsurf, and/or the
+/// Gemini CLI discover scryer-mcp when working in this project. Config is project-scoped
+/// except for Windsurf, which only reads a global file. Only writes config for
+/// tools that are actually installed.
 fn init_project() -> Result<(), Box<dyn std::error::Error>> {
     let binary_path = std::env::current_exe()?
         .canonicalize()?
@@ -38,31 +149,48 @@ fn init_project() -> Result<(), Box<dyn std::error::Error>> {
 
     let has_claude = which("claude");
     let has_codex = which("codex");
+    let has_cursor = which("cursor");
+    let has_windsurf = which("windsurf");
+    let has_gemini = which("gemini");
 
-    if !has_claude && !has_codex {
-        eprintln!("Neither `claude` nor `codex` found in PATH.");
-        eprintln!("Install Claude Code or OpenAI Codex first, then re-run `scryer-mcp init`.");
+    if !has_claude && !has_codex && !has_cursor && !has_windsurf && !has_gemini {
+        eprintln!("None of `claude`, `codex`, `cursor`, `windsurf`, or `gemini` found in PATH.");
+        eprintln!("Install one of them first, then re-run `scryer-mcp init`.");
         std::process::exit(1);
     }
 
-    let mut wrote_any = false;
+    let mut tools: Vec<&str> = Vec::new();
 
     if has_claude {
         init_claude_code(&cwd, &binary_path)?;
-        wrote_any = true;
+        tools.push("Claude Code");
     }
 
     if has_codex {
         init_codex(&cwd, &binary_path)?;
-        wrote_any = true;
+        tools.push("Codex");
+    }
+
+    if has_cursor {
+        init_cursor(&cwd, &binary_path)?;
+        tools.push("Cursor");
     }
 
-    if wrote_any {
-        let tools: Vec<&str> = [
-            if has_claude { Some("Claude Code") } else { None },
-            if has_codex { Some("Codex") } else { None },
-        ].into_iter().flatten().collect();
-        eprintln!("\nDone. {} will use scryer in this project.", tools.join(" and "));
+    if has_windsurf {
+        init_windsurf(&binary_path)?;
+        tools.push("Windsurf");
+    }
+
+    if has_gemini {
+        init_gemini(&cwd, &binary_path)?;
+        tools.push("Gemini CLI");
+    }
+
+    if !tools.is_empty() {
+        eprintln!(
+            "\nDone. {} will use scryer in this project.",
+            join_names(&tools)
+        );
         if has_claude {
             eprintln!("\nTo auto-approve scryer read tools in Claude Code, add to .claude/settings.local.json:");
             eprintln!("  \"permissions\": {{ \"allow\": [\"mcp__scryer__list_models\", \"mcp__scryer__get_model\", \"mcp__scryer__get_node\", \"mcp__scryer__get_rules\", \"mcp__scryer__get_changes\", \"mcp__scryer__get_structure\"] }}");
@@ -72,6 +200,16 @@ fn init_project() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// "A", "A and B", "A, B, and C".
+fn join_names(names: &[&str]) -> String {
+    match names {
+        [] => String::new(),
+        [one] => one.to_string(),
+        [a, b] => format!("{a} and {b}"),
+        [rest @ .., last] => format!("{}, and {last}", rest.join(", ")),
+    }
+}
+
 fn which(name: &str) -> bool {
     // Check PATH for the given binary
     std::env::var_os("PATH")
@@ -84,15 +222,31 @@ fn which(name: &str) -> bool {
         .unwrap_or(false)
 }
 
-/// Write .mcp.json for Claude Code, merging with any existing config.
-fn init_claude_code(
-    cwd: &std::path::Path,
-    binary_path: &str,
+/// Set `mcpServers.scryer` in the JSON config at `path`, preserving every
+/// other key. Creates the file (and its directory) if needed. A file that
+/// isn't a plain JSON object (e.g. JSONC with comments) is left untouched and
+/// reported as an error rather than overwritten.
+fn write_mcp_servers_json(
+    path: &std::path::Path,
+    entry: serde_json::Value,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mcp_json_path = cwd.join(".mcp.json");
-    let mut root: serde_json::Value = if mcp_json_path.exists() {
-        let contents = std::fs::read_to_string(&mcp_json_path)?;
-        serde_json::from_str(&contents).unwrap_or_else(|_| serde_json::json!({}))
+    let mut root: serde_json::Value = if path.exists() {
+        let contents = std::fs::read_to_string(path)?;
+        let root: serde_json::Value = serde_json::from_str(&contents).map_err(|e| {
+            format!(
+                "Can't parse {} ({}). Fix it or add the scryer server by hand; the file was not changed.",
+                path.display(),
+                e
+            )
+        })?;
+        if !root.is_object() {
+            return Err(format!(
+                "{} isn't a JSON object; the file was not changed.",
+                path.display()
+            )
+            .into());
+        }
+        root
     } else {
         serde_json::json!({})
     };
@@ -100,17 +254,73 @@ fn init_claude_code(
     if !root.get("mcpServers").is_some_and(|v| v.is_object()) {
         root["mcpServers"] = serde_json::json!({});
     }
-    root["mcpServers"]["scryer"] = serde_json::json!({
-        "type": "stdio",
-        "command": binary_path,
-        "args": [],
-    });
+    root["mcpServers"]["scryer"] = entry;
 
-    std::fs::write(&mcp_json_path, serde_json::to_string_pretty(&root)?)?;
-    eprintln!("Wrote {}", mcp_json_path.display());
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(&root)?)?;
+    eprintln!("Wrote {}", path.display());
     Ok(())
 }
 
+/// Write .mcp.json for Claude Code, merging with any existing config.
+fn init_claude_code(
+    cwd: &std::path::Path,
+    binary_path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    write_mcp_servers_json(
+        &cwd.join(".mcp.json"),
+        serde_json::json!({
+            "type": "stdio",
+            "command": binary_path,
+            "args": [],
+        }),
+    )
+}
+
+/// Write .cursor/mcp.json for Cursor, merging with any existing config.
+fn init_cursor(cwd: &std::path::Path, binary_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    write_mcp_servers_json(
+        &cwd.join(".cursor").join("mcp.json"),
+        serde_json::json!({
+            "command": binary_path,
+            "args": [],
+        }),
+    )
+}
+
+/// Write .gemini/settings.json for the Gemini CLI, merging with any existing
+/// config.
+fn init_gemini(cwd: &std::path::Path, binary_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    write_mcp_servers_json(
+        &cwd.join(".gemini").join("settings.json"),
+        serde_json::json!({
+            "command": binary_path,
+            "args": [],
+        }),
+    )
+}
+
+/// Write ~/.codeium/windsurf/mcp_config.json for Windsurf, merging with any
+/// existing config. Windsurf has no project-level MCP config, so this applies
+/// to every project; the server resolves the model from the working directory.
+fn init_windsurf(binary_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let home = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .ok_or("Could not determine home directory")?;
+    write_mcp_servers_json(
+        &std::path::Path::new(&home)
+            .join(".codeium")
+            .join("windsurf")
+            .join("mcp_config.json"),
+        serde_json::json!({
+            "command": binary_path,
+            "args": [],
+        }),
+    )
+}
+
 /// Write .codex/config.toml for OpenAI Codex, merging with any existing config.
 fn init_codex(
     cwd: &std::path::Path,
@@ -141,3 +351,69 @@ fn init_codex(
     eprintln!("Wrote {}", config_toml_path.display());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn local_hosts_and_origins() {
+        for host in [
+            "localhost",
+            "localhost:8765",
+            "127.0.0.1:8765",
+            "[::1]:8765",
+            "LOCALHOST",
+        ] {
+            assert!(is_local_host(host), "{host}");
+        }
+        for host in [
+            "evil.example",
+            "evil.example:8765",
+            "localhost.evil.example",
+            "127.0.0.2",
+        ] {
+            assert!(!is_local_host(host), "{host}");
+        }
+        assert!(is_local_origin("http://localhost:3000"));
+        assert!(is_local_origin("https://127.0.0.1"));
+        assert!(!is_local_origin("http://evil.example"));
+        assert!(!is_local_origin("null"));
+        assert!(!is_local_origin("http://localhost.evil.example"));
+    }
+
+    /// Send a raw POST with `headers` and return the response status line.
+    async fn status(addr: std::net::SocketAddr, headers: &str) -> String {
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let request = format!(
+            "POST /mcp HTTP/1.1\r\n{}Content-Length: 0\r\nConnection: close\r\n\r\n",
+            headers
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response.lines().next().unwrap_or("").to_string()
+    }
+
+    #[tokio::test]
+    async fn rejects_rebound_requests() {
+        let router =
+            local_only(axum::Router::new().route("/mcp", axum::routing::post(|| async { "ok" })));
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+            .await
+            .unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        let ok = format!("Host: 127.0.0.1:{}\r\n", addr.port());
+        assert!(status(addr, &ok).await.contains("200"));
+        let local_page = format!("{}Origin: http://localhost:5173\r\n", ok);
+        assert!(status(addr, &local_page).await.contains("200"));
+
+        let rebound = format!("Host: evil.example:{}\r\n", addr.port());
+        assert!(status(addr, &rebound).await.contains("403"));
+        let cross_site = format!("{}Origin: http://evil.example\r\n", ok);
+        assert!(status(addr, &cross_site).await.contains("403"));
+    }
+}
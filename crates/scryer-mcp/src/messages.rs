@@ -0,0 +1,339 @@
+//! Fixed text that `get_task` wraps around node content, per output language.
+//!
+//! Node names, descriptions, and contract items are the user's own words and
+//! pass through untouched. Tool names, field names, and status values also
+//! stay in English since the agent has to send them back verbatim.
+
+use crate::instructions::{TASK_INSTRUCTIONS, TASK_INSTRUCTIONS_ES};
+
+/// Language codes accepted by `get_task`'s `language` field.
+pub(crate) const LANGUAGES: [&str; 2] = ["en", "es"];
+
+pub(crate) struct TaskMessages {
+    pub instructions: &'static str,
+    pub task: &'static str,
+    pub of: &'static str,
+    pub setup: &'static str,
+    pub build: &'static str,
+    pub scaffold: &'static str,
+    pub choose_next: &'static str,
+    pub choose_intro: &'static str,
+    pub choose_call: &'static str,
+    pub group: &'static str,
+    pub note: &'static str,
+    pub build_only: &'static str,
+    pub scaffold_intro: &'static str,
+    pub technology: &'static str,
+    pub status: &'static str,
+    pub contract: &'static str,
+    pub contract_for: &'static str,
+    pub group_contract: &'static str,
+    pub must: &'static str,
+    pub ask_user: &'static str,
+    pub never: &'static str,
+    pub notes: &'static str,
+    pub processes: &'static str,
+    pub models: &'static str,
+    pub operations: &'static str,
+    pub sources: &'static str,
+    pub dependencies: &'static str,
+    pub after_build: &'static str,
+    pub after_scaffold: &'static str,
+    pub also_mark_members: &'static str,
+    pub progress: &'static str,
+    pub tasks_complete: &'static str,
+    pub next_up: &'static str,
+    pub remaining: &'static str,
+    pub ready: &'static str,
+    pub blocked: &'static str,
+    pub points: &'static str,
+    pub all: &'static str,
+    /// The stop signal agents wait for (RULES rule 8), so it stays in
+    /// English in every language.
+    pub all_complete: &'static str,
+    pub nothing_to_build: &'static str,
+    pub mark_parents: &'static str,
+    pub members_pending: &'static str,
+    pub in_parent: &'static str,
+    pub validate_flows_next: &'static str,
+    pub validate_flows: &'static str,
+    pub steps: &'static str,
+    pub flow: &'static str,
+    pub step: &'static str,
+    pub no_mention: &'static str,
+    pub mentions_missing: &'static str,
+    pub no_candidates: &'static str,
+    pub candidates: &'static str,
+    pub steps_to_link: &'static str,
+    pub steps_to_link_hint: &'static str,
+    pub for_example: &'static str,
+    pub skipped_intro: &'static str,
+    pub skipped_retry: &'static str,
+    pub no_work: &'static str,
+    pub work_ready: &'static str,
+    pub other_statuses_remain: &'static str,
+    pub cycle_intro: &'static str,
+    pub cycle_fix: &'static str,
+}
+
+impl TaskMessages {
+    /// The footer line, e.g. `Progress: 3/10 tasks complete | Next up: Auth`.
+    pub fn progress_line(&self, completed: usize, total: usize, next: Option<&str>) -> String {
+        let mut line = format!(
+            "{}: {}/{} {}",
+            self.progress, completed, total, self.tasks_complete
+        );
+        if let Some(name) = next {
+            line.push_str(&format!(" | {}: {}", self.next_up, name));
+        }
+        line
+    }
+
+    /// Footer suffix with the remaining work, e.g.
+    /// ` | Remaining: 4 ready, 2 blocked (~15 points)`.
+    pub fn remaining_suffix(&self, ready: usize, blocked: usize, points: usize) -> String {
+        format!(
+            " | {}: {} {}, {} {} (~{} {})",
+            self.remaining, ready, self.ready, blocked, self.blocked, points, self.points
+        )
+    }
+
+    /// e.g. `All 12 tasks complete.`
+    pub fn all_complete_count(&self, completed: usize) -> String {
+        format!("{} {} {}.", self.all, completed, self.tasks_complete)
+    }
+
+    /// Shown when a `status` filter leaves nothing ready, e.g. `No proposed
+    /// work is ready. 3 task(s) with other statuses remain — …`.
+    pub fn status_filter_empty(&self, status: &str, others: usize) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.no_work, status, self.work_ready, others, self.other_statuses_remain
+        )
+    }
+}
+
+static EN: TaskMessages = TaskMessages {
+    instructions: TASK_INSTRUCTIONS,
+    task: "Task",
+    of: "of",
+    setup: "Setup",
+    build: "Build",
+    scaffold: "Scaffold",
+    choose_next: "Choose next task",
+    choose_intro:
+        "These containers are ready to build. Pick the one that makes the most sense to start with.",
+    choose_call: "Call `get_task` again with `node_id` set to the chosen container's ID.",
+    group: "Group",
+    note: "Note",
+    build_only:
+        "Build ONLY what this task describes. Do not scaffold or set up other parts of the project.",
+    scaffold_intro: "Set up the project structure for these containers:",
+    technology: "Technology",
+    status: "Status",
+    contract: "Contract (you MUST follow these requirements):",
+    contract_for: "Contract (MUST follow):",
+    group_contract: "Group Contract (MUST follow):",
+    must: "MUST:",
+    ask_user: "ASK USER FIRST:",
+    never: "NEVER:",
+    notes: "Notes:",
+    processes: "Processes:",
+    models: "Models:",
+    operations: "Operations:",
+    sources: "Sources:",
+    dependencies: "Dependencies:",
+    after_build: "After building, mark as implemented with a reason and set source locations:",
+    after_scaffold:
+        "After scaffolding, mark these as implemented with a reason explaining what was scaffolded:",
+    also_mark_members:
+        "Also mark these member nodes as `implemented` with a reason explaining what was built:",
+    progress: "Progress",
+    tasks_complete: "tasks complete",
+    next_up: "Next up",
+    remaining: "Remaining",
+    ready: "ready",
+    blocked: "blocked",
+    points: "points",
+    all: "All",
+    all_complete: "All tasks complete.",
+    nothing_to_build: "Nothing to build.",
+    mark_parents: "Mark these parent nodes as implemented:",
+    members_pending: "These member nodes are still proposed — mark as `implemented` with a reason explaining what was built:",
+    in_parent: "in",
+    validate_flows_next: "Then call `get_task` again to validate flows.",
+    validate_flows: "Please validate that the model's flows still accurately describe the system behavior. Flows are integration test specs — update step descriptions as needed using `set_flows`. Use @[Name] mentions in step descriptions to reference architecture nodes. When a test exists for a flow, use `update_source_map` to link the flow to the test file.",
+    steps: "steps",
+    flow: "flow",
+    step: "step",
+    no_mention: "no @[Name] mention",
+    mentions_missing: "mentions missing",
+    no_candidates: "No matching process; mention the node that handles it.",
+    candidates: "Candidates",
+    steps_to_link: "Steps to link",
+    steps_to_link_hint: "rewrite each with `update_steps` so it mentions the node it exercises:",
+    for_example: "For example:",
+    skipped_intro: "No task is available — the remaining work is skipped or depends on skipped nodes:",
+    skipped_retry: "Call `get_task` without `skip_ids` once these are unblocked.",
+    no_work: "No",
+    work_ready: "work is ready.",
+    other_statuses_remain: "task(s) with other statuses remain — call `get_task` without `status` to continue.",
+    cycle_intro: "Dependency cycle detected. The following nodes all block each other:",
+    cycle_fix: "Fix the model by removing or redirecting edges to break the cycle.",
+};
+
+static ES: TaskMessages = TaskMessages {
+    instructions: TASK_INSTRUCTIONS_ES,
+    task: "Tarea",
+    of: "de",
+    setup: "Preparación",
+    build: "Construir",
+    scaffold: "Andamiaje",
+    choose_next: "Elige la siguiente tarea",
+    choose_intro: "Estos contenedores están listos para construirse. Elige el que tenga más sentido para empezar.",
+    choose_call: "Vuelve a llamar a `get_task` con `node_id` igual al ID del contenedor elegido.",
+    group: "Grupo",
+    note: "Nota",
+    build_only: "Construye SOLO lo que describe esta tarea. No prepares ni configures otras partes del proyecto.",
+    scaffold_intro: "Prepara la estructura del proyecto para estos contenedores:",
+    technology: "Tecnología",
+    status: "Estado",
+    contract: "Contrato (DEBES cumplir estos requisitos):",
+    contract_for: "Contrato (de obligado cumplimiento):",
+    group_contract: "Contrato del grupo (de obligado cumplimiento):",
+    must: "DEBE:",
+    ask_user: "PREGUNTAR AL USUARIO PRIMERO:",
+    never: "NUNCA:",
+    notes: "Notas:",
+    processes: "Procesos:",
+    models: "Modelos:",
+    operations: "Operaciones:",
+    sources: "Fuentes:",
+    dependencies: "Dependencias:",
+    after_build: "Después de construir, marca como implementado con un motivo e indica las ubicaciones del código:",
+    after_scaffold: "Después de preparar el andamiaje, marca estos nodos como implementados con un motivo que explique qué se preparó:",
+    also_mark_members: "Marca también estos nodos miembro como `implemented` con un motivo que explique qué se construyó:",
+    progress: "Progreso",
+    tasks_complete: "tareas completadas",
+    next_up: "Siguiente",
+    remaining: "Pendiente",
+    ready: "listas",
+    blocked: "bloqueadas",
+    points: "puntos",
+    all: "Las",
+    all_complete: "All tasks complete.",
+    nothing_to_build: "No hay nada que construir.",
+    mark_parents: "Marca estos nodos padre como implementados:",
+    members_pending: "Estos nodos miembro siguen en proposed: márcalos como `implemented` con un motivo que explique qué se construyó:",
+    in_parent: "en",
+    validate_flows_next: "Después vuelve a llamar a `get_task` para validar los flujos.",
+    validate_flows: "Comprueba que los flujos del modelo siguen describiendo con precisión el comportamiento del sistema. Los flujos son especificaciones de pruebas de integración: actualiza las descripciones de los pasos con `set_flows` si hace falta. Usa menciones @[Nombre] en las descripciones de los pasos para referenciar nodos de la arquitectura. Cuando exista una prueba para un flujo, usa `update_source_map` para enlazar el flujo con el archivo de la prueba.",
+    steps: "pasos",
+    flow: "flujo",
+    step: "paso",
+    no_mention: "sin mención @[Nombre]",
+    mentions_missing: "menciones inexistentes",
+    no_candidates: "Ningún proceso coincide; menciona el nodo que lo gestiona.",
+    candidates: "Candidatos",
+    steps_to_link: "Pasos por enlazar",
+    steps_to_link_hint: "reescribe cada uno con `update_steps` para que mencione el nodo que ejercita:",
+    for_example: "Por ejemplo:",
+    skipped_intro: "No hay ninguna tarea disponible: el trabajo restante está omitido o depende de nodos omitidos:",
+    skipped_retry: "Llama a `get_task` sin `skip_ids` cuando se hayan desbloqueado.",
+    no_work: "No hay trabajo",
+    work_ready: "listo.",
+    other_statuses_remain: "tarea(s) con otros estados siguen pendientes: llama a `get_task` sin `status` para continuar.",
+    cycle_intro: "Se ha detectado un ciclo de dependencias. Todos estos nodos se bloquean entre sí:",
+    cycle_fix: "Corrige el modelo eliminando o redirigiendo aristas para romper el ciclo.",
+};
+
+/// Messages for `language` (e.g. "es" or "es-MX" — only the primary subtag
+/// is used), or `None` if it isn't one of [`LANGUAGES`].
+pub(crate) fn task_messages(language: &str) -> Option<&'static TaskMessages> {
+    let primary = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase();
+    match primary.as_str() {
+        "en" => Some(&EN),
+        "es" => Some(&ES),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The parts of the task instructions that must survive translation
+    /// verbatim: code spans, bold status names, headings, numbered steps,
+    /// and tool names.
+    fn skeleton(text: &str) -> Vec<String> {
+        let mut parts: Vec<String> = text
+            .split('`')
+            .skip(1)
+            .step_by(2)
+            .map(|s| format!("`{s}`"))
+            .collect();
+        parts.extend(
+            text.split("**")
+                .skip(1)
+                .step_by(2)
+                .map(|s| format!("**{s}**")),
+        );
+        for line in text.lines() {
+            if line.starts_with("## ") {
+                parts.push("##".to_string());
+            } else if let Some((n, _)) = line.split_once(". ") {
+                if n.parse::<u32>().is_ok() {
+                    parts.push(format!("{n}."));
+                }
+            }
+        }
+        for tool in ["get_task", "update_nodes", "delete_nodes"] {
+            parts.push(format!("{tool} x{}", text.matches(tool).count()));
+        }
+        parts
+    }
+
+    #[test]
+    fn translations_keep_the_instruction_structure() {
+        let en = skeleton(EN.instructions);
+        for language in LANGUAGES {
+            let m = task_messages(language).unwrap();
+            assert_eq!(
+                skeleton(m.instructions),
+                en,
+                "{language} instructions drifted"
+            );
+        }
+    }
+
+    #[test]
+    fn instructions_quote_the_done_message() {
+        for language in LANGUAGES {
+            let m = task_messages(language).unwrap();
+            assert!(
+                m.instructions.contains(&format!("\"{}\"", m.all_complete)),
+                "{language} instructions don't quote its done message"
+            );
+        }
+    }
+
+    #[test]
+    fn done_message_is_the_same_in_every_language() {
+        for language in LANGUAGES {
+            let m = task_messages(language).unwrap();
+            assert_eq!(m.all_complete, EN.all_complete, "{language}");
+        }
+    }
+
+    #[test]
+    fn region_subtags_fall_back_to_the_primary_language() {
+        assert!(std::ptr::eq(task_messages("es-MX").unwrap(), &ES));
+        assert!(std::ptr::eq(task_messages("EN_gb").unwrap(), &EN));
+        assert!(task_messages("fr").is_none());
+    }
+}
@@ -12,6 +12,10 @@ pub struct ScryerServer {
     /// Session-level active model. Set by `get_model`/explicit name, used as
     /// default when tools omit the model parameter.
     pub(crate) active_model: std::sync::Arc<std::sync::Mutex<Option<scryer_core::ModelRef>>>,
+    /// Full `get_model` reads per model since it last changed: the model's
+    /// `updatedAt` at the latest read, and how many reads saw that version.
+    pub(crate) full_reads:
+        std::sync::Arc<std::sync::Mutex<std::collections::HashMap<String, (Option<String>, u32)>>>,
 }
 
 impl ScryerServer {
@@ -20,9 +24,12 @@ impl ScryerServer {
             tool_router: Self::tool_router_read()
                 + Self::tool_router_nodes()
                 + Self::tool_router_edges()
+                + Self::tool_router_flows()
                 + Self::tool_router_task()
-                + Self::tool_router_misc(),
+                + Self::tool_router_misc()
+                + Self::tool_router_attachments(),
             active_model: std::sync::Arc::new(std::sync::Mutex::new(None)),
+            full_reads: std::sync::Arc::new(std::sync::Mutex::new(Default::default())),
         }
     }
 }
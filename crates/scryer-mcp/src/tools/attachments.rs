@@ -0,0 +1,299 @@
+use crate::server::ScryerServer;
+use crate::types::*;
+use base64::Engine;
+use rmcp::{
+    handler::server::wrapper::Parameters,
+    model::{CallToolResult, Content},
+    tool, tool_router, ErrorData as McpError,
+};
+use scryer_core::{Contract, ContractImage, ContractItem};
+
+/// The contract section named `name`, e.g. "expect".
+fn section_mut<'a>(contract: &'a mut Contract, name: &str) -> Option<&'a mut Vec<ContractItem>> {
+    match name {
+        "expect" => Some(&mut contract.expect),
+        "ask" => Some(&mut contract.ask),
+        "never" => Some(&mut contract.never),
+        _ => None,
+    }
+}
+
+/// Decoded size of base64 `data` without decoding it.
+fn decoded_len(data: &str) -> usize {
+    let padding = data.bytes().rev().take_while(|b| *b == b'=').count();
+    (data.len() / 4 * 3).saturating_sub(padding)
+}
+
+#[tool_router(router = tool_router_attachments, vis = "pub(crate)")]
+impl ScryerServer {
+    #[tool(
+        description = "Attach an image file from disk (png, jpeg, gif, webp, svg) to a node's contract — e.g. a screenshot or an existing diagram. Attach to an existing contract item with section + item_index, or create a new item with text. The attachment ID is \"{section}-{index}\"."
+    )]
+    fn attach_file(
+        &self,
+        Parameters(req): Parameters<AttachFileRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let path = std::path::Path::new(&req.path);
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let Some(mime) = scryer_core::attachments::mime_for_extension(ext) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Unsupported file type '{}' (expected png, jpeg, gif, webp, or svg)",
+                req.path
+            ))]));
+        };
+        let size = match std::fs::metadata(path) {
+            Ok(m) => m.len(),
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Cannot read '{}': {}",
+                    req.path, e
+                ))]));
+            }
+        };
+        if let Some(max) = scryer_core::limits::max_attachment_bytes() {
+            if size > max as u64 {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "'{}' is {} bytes, exceeding the {} byte limit",
+                    req.path, size, max
+                ))]));
+            }
+        }
+        let section_name = req.section.as_deref().unwrap_or("expect");
+        if req.item_index.is_none() && req.text.as_deref().is_none_or(|t| t.trim().is_empty()) {
+            return Ok(CallToolResult::error(vec![Content::text(
+                "Pass item_index to attach to an existing contract item, or text for a new one.",
+            )]));
+        }
+
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        let Some(node) = model.nodes.iter_mut().find(|n| n.id == req.node_id) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Node '{}' not found",
+                req.node_id
+            ))]));
+        };
+        let Some(items) = section_mut(&mut node.data.contract, section_name) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Invalid section '{}'. Must be: expect, ask, never",
+                section_name
+            ))]));
+        };
+
+        let bytes = match std::fs::read(path) {
+            Ok(b) => b,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Cannot read '{}': {}",
+                    req.path, e
+                ))]));
+            }
+        };
+        let image = ContractImage {
+            filename: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| "image".to_string()),
+            mime_type: mime.to_string(),
+            data: base64::engine::general_purpose::STANDARD.encode(&bytes),
+            path: None,
+        };
+
+        let index = match req.item_index {
+            Some(i) => {
+                let Some(item) = items.get_mut(i) else {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Node '{}' has no {} item at index {} ({} item(s))",
+                        req.node_id,
+                        section_name,
+                        i,
+                        items.len()
+                    ))]));
+                };
+                *item = match std::mem::replace(item, ContractItem::Plain(String::new())) {
+                    ContractItem::Full {
+                        text, passed, url, ..
+                    } => ContractItem::Full {
+                        text,
+                        passed,
+                        url,
+                        image: Some(image),
+                    },
+                    ContractItem::Plain(text) => ContractItem::Full {
+                        text,
+                        passed: None,
+                        url: None,
+                        image: Some(image),
+                    },
+                };
+                i
+            }
+            None => {
+                items.push(ContractItem::Full {
+                    text: req.text.unwrap_or_default().trim().to_string(),
+                    passed: None,
+                    url: None,
+                    image: Some(image),
+                });
+                items.len() - 1
+            }
+        };
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Attached '{}' to '{}' as {}-{} ({} bytes)",
+                    req.path,
+                    req.node_id,
+                    section_name,
+                    index,
+                    bytes.len()
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
+    #[tool(
+        description = "List images attached to node contracts. Returns JSON entries of {node_id, attachment_id, filename, mime_type, size}. Pass node_id to list one node's attachments."
+    )]
+    fn list_attachments(
+        &self,
+        Parameters(req): Parameters<ListAttachmentsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        if let Some(id) = &req.node_id {
+            if !model.nodes.iter().any(|n| n.id == *id) {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Node '{}' not found",
+                    id
+                ))]));
+            }
+        }
+
+        let mut entries = Vec::new();
+        for node in &model.nodes {
+            if req.node_id.as_ref().is_some_and(|id| *id != node.id) {
+                continue;
+            }
+            let contract = &node.data.contract;
+            for (section, items) in [
+                ("expect", &contract.expect),
+                ("ask", &contract.ask),
+                ("never", &contract.never),
+            ] {
+                for (i, item) in items.iter().enumerate() {
+                    if let ContractItem::Full {
+                        image: Some(img), ..
+                    } = item
+                    {
+                        entries.push(serde_json::json!({
+                            "node_id": node.id,
+                            "attachment_id": format!("{}-{}", section, i),
+                            "filename": img.filename,
+                            "mime_type": img.mime_type,
+                            "size": decoded_len(&img.data),
+                        }));
+                    }
+                }
+            }
+        }
+
+        if entries.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No attachments.",
+            )]));
+        }
+        let json = serde_json::to_string_pretty(&entries)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Remove an image from a node's contract item. The contract item itself is kept. attachment_id comes from list_attachments, e.g. \"expect-0\"."
+    )]
+    fn remove_attachment(
+        &self,
+        Parameters(req): Parameters<RemoveAttachmentRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let parsed = req
+            .attachment_id
+            .rsplit_once('-')
+            .and_then(|(section, i)| Some((section, i.parse::<usize>().ok()?)));
+        let Some((section_name, index)) = parsed else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Invalid attachment ID '{}'. Expected \"{{section}}-{{index}}\", e.g. \"expect-0\"",
+                req.attachment_id
+            ))]));
+        };
+
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        let Some(node) = model.nodes.iter_mut().find(|n| n.id == req.node_id) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Node '{}' not found",
+                req.node_id
+            ))]));
+        };
+        let removed = section_mut(&mut node.data.contract, section_name)
+            .and_then(|items| items.get_mut(index))
+            .and_then(|item| match item {
+                ContractItem::Full { image, .. } => image.take(),
+                ContractItem::Plain(_) => None,
+            });
+        let Some(image) = removed else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Node '{}' has no attachment '{}'",
+                req.node_id, req.attachment_id
+            ))]));
+        };
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                // Stored files are shared by content, so only drop ones nothing refers to now
+                let _ = scryer_core::prune_attachments_at(&model_ref);
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Removed '{}' ({}) from '{}'",
+                    req.attachment_id, image.filename, req.node_id
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+}
@@ -11,7 +11,9 @@ use std::collections::HashSet;
 
 #[tool_router(router = tool_router_edges, vis = "pub(crate)")]
 impl ScryerServer {
-    #[tool(description = "Add one or more relationship edges between nodes")]
+    #[tool(
+        description = "Add one or more relationship edges between nodes. Two nodes can have several edges when they relate in genuinely different ways (e.g. \"reads\" and \"writes\"), but not two with the same label."
+    )]
     fn add_edges(
         &self,
         Parameters(req): Parameters<AddEdgeRequest>,
@@ -44,21 +46,36 @@ impl ScryerServer {
                     item.target
                 ))]));
             }
+            if let Err(e) = validate_no_self_loop(&item.source, &item.target) {
+                return Ok(CallToolResult::error(vec![Content::text(e)]));
+            }
 
-            if item.label.len() > 30 {
-                return Ok(CallToolResult::error(vec![Content::text(format!(
-                    "Edge label '{}' exceeds 30 character limit",
-                    item.label
-                ))]));
+            if let Err(e) = scryer_core::limits::check_edge_label_len(&item.label) {
+                return Ok(CallToolResult::error(vec![Content::text(e)]));
             }
 
-            let id = scryer_core::make_edge_id(&item.source, &item.target);
-            if model.edges.iter().any(|e| e.id == id) {
+            if model.edges.iter().any(|e| {
+                e.source == item.source
+                    && e.target == item.target
+                    && e.data.as_ref().is_some_and(|d| d.label == item.label)
+            }) {
                 return Ok(CallToolResult::error(vec![Content::text(format!(
-                    "Edge from '{}' to '{}' already exists",
-                    item.source, item.target
+                    "Edge from '{}' to '{}' labeled \"{}\" already exists",
+                    item.source, item.target, item.label
                 ))]));
             }
+            let id = match item.id {
+                Some(id) => {
+                    if model.edges.iter().any(|e| e.id == id) {
+                        return Ok(CallToolResult::error(vec![Content::text(format!(
+                            "Edge '{}' already exists",
+                            id
+                        ))]));
+                    }
+                    id
+                }
+                None => scryer_core::next_edge_id(&model, &item.source, &item.target),
+            };
 
             model.edges.push(C4Edge {
                 id: id.clone(),
@@ -67,6 +84,7 @@ impl ScryerServer {
                 data: Some(C4EdgeData {
                     label: item.label,
                     method: item.method,
+                    async_: item.async_.filter(|a| *a),
                 }),
             });
             added.push(id);
@@ -147,23 +165,27 @@ impl ScryerServer {
                     ))]));
                 }
             };
+            if let Err(e) = validate_no_self_loop(&edge.source, &edge.target) {
+                return Ok(CallToolResult::error(vec![Content::text(e)]));
+            }
 
             let data = edge.data.get_or_insert(C4EdgeData {
                 label: String::new(),
                 method: None,
+                async_: None,
             });
             if let Some(label) = item.label {
-                if label.len() > 30 {
-                    return Ok(CallToolResult::error(vec![Content::text(format!(
-                        "Edge label '{}' exceeds 30 character limit",
-                        label
-                    ))]));
+                if let Err(e) = scryer_core::limits::check_edge_label_len(&label) {
+                    return Ok(CallToolResult::error(vec![Content::text(e)]));
                 }
                 data.label = label;
             }
             if let Some(tech) = item.method {
                 data.method = Some(tech);
             }
+            if let Some(is_async) = item.async_ {
+                data.async_ = is_async.then_some(true);
+            }
             updated += 1;
         }
 
@@ -0,0 +1,352 @@
+use crate::server::ScryerServer;
+use crate::types::*;
+use rmcp::{
+    handler::server::wrapper::Parameters,
+    model::{CallToolResult, Content},
+    tool, tool_router, ErrorData as McpError,
+};
+use scryer_core::FlowStep;
+use std::collections::HashSet;
+
+/// Find the list containing `id` (top-level or inside any branch) and its index.
+fn find_step_slot<'a>(
+    steps: &'a mut Vec<FlowStep>,
+    id: &str,
+) -> Option<(&'a mut Vec<FlowStep>, usize)> {
+    if let Some(i) = steps.iter().position(|s| s.id == id) {
+        return Some((steps, i));
+    }
+    for step in steps.iter_mut() {
+        for branch in &mut step.branches {
+            if let Some(slot) = find_step_slot(&mut branch.steps, id) {
+                return Some(slot);
+            }
+        }
+    }
+    None
+}
+
+/// Remove every step whose ID is in `ids`, at any depth. Returns how many were removed.
+fn remove_steps(steps: &mut Vec<FlowStep>, ids: &HashSet<&str>) -> usize {
+    let before = steps.len();
+    steps.retain(|s| !ids.contains(s.id.as_str()));
+    let mut removed = before - steps.len();
+    for step in steps.iter_mut() {
+        for branch in &mut step.branches {
+            removed += remove_steps(&mut branch.steps, ids);
+        }
+    }
+    removed
+}
+
+/// Ensure `requested` is exactly a permutation of `current` — no additions,
+/// removals, or duplicates.
+fn check_same_ids(current: &[&str], requested: &[String], what: &str) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for id in requested {
+        if !seen.insert(id.as_str()) {
+            return Err(format!("Duplicate {} ID '{}'", what, id));
+        }
+        if !current.contains(&id.as_str()) {
+            return Err(format!("Unknown {} ID '{}'", what, id));
+        }
+    }
+    let missing: Vec<&str> = current
+        .iter()
+        .copied()
+        .filter(|id| !seen.contains(id))
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "Missing {} ID(s): {} — pass every {} exactly once",
+            what,
+            missing.join(", "),
+            what
+        ));
+    }
+    Ok(())
+}
+
+#[tool_router(router = tool_router_flows, vis = "pub(crate)")]
+impl ScryerServer {
+    #[tool(
+        description = "Add one or more steps to an existing flow without rewriting it. Each step is appended to the end of the flow, or inserted right after the step given in `after` (which may live inside a branch). Step IDs are generated. To add or restructure branches, use set_flows."
+    )]
+    fn add_steps(
+        &self,
+        Parameters(req): Parameters<AddStepsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let Some(flow_idx) = model.flows.iter().position(|f| f.id == req.flow_id) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Flow '{}' not found",
+                req.flow_id
+            ))]));
+        };
+
+        let mut added = Vec::new();
+        for item in req.steps {
+            let id = scryer_core::next_step_id(&model);
+            let step = FlowStep {
+                id: id.clone(),
+                label: None,
+                description: Some(item.description),
+                position: None,
+                branches: Vec::new(),
+            };
+            let flow = &mut model.flows[flow_idx];
+            match item.after {
+                Some(after) => match find_step_slot(&mut flow.steps, &after) {
+                    Some((list, i)) => list.insert(i + 1, step),
+                    None => {
+                        return Ok(CallToolResult::error(vec![Content::text(format!(
+                            "Step '{}' not found in flow '{}'",
+                            after, req.flow_id
+                        ))]));
+                    }
+                },
+                None => flow.steps.push(step),
+            }
+            added.push(id);
+        }
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Added {} step(s): {}",
+                    added.len(),
+                    added.join(", ")
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
+    #[tool(description = "Update the text of one or more steps in a flow")]
+    fn update_steps(
+        &self,
+        Parameters(req): Parameters<UpdateStepsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let Some(flow) = model.flows.iter_mut().find(|f| f.id == req.flow_id) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Flow '{}' not found",
+                req.flow_id
+            ))]));
+        };
+
+        let mut updated = 0usize;
+        for item in req.steps {
+            let Some((list, i)) = find_step_slot(&mut flow.steps, &item.step_id) else {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Step '{}' not found in flow '{}'",
+                    item.step_id, req.flow_id
+                ))]));
+            };
+            if let Some(description) = item.description {
+                list[i].description = Some(description);
+                list[i].label = None;
+            }
+            updated += 1;
+        }
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Updated {} step(s)",
+                    updated
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
+    #[tool(
+        description = "Delete one or more steps from a flow. Deleting a decision point also deletes its branches and their sub-steps."
+    )]
+    fn delete_steps(
+        &self,
+        Parameters(req): Parameters<DeleteStepsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let Some(flow) = model.flows.iter_mut().find(|f| f.id == req.flow_id) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Flow '{}' not found",
+                req.flow_id
+            ))]));
+        };
+
+        let existing: HashSet<&str> = scryer_core::collect_step_ids(&flow.steps)
+            .into_iter()
+            .collect();
+        for sid in &req.step_ids {
+            if !existing.contains(sid.as_str()) {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Step '{}' not found in flow '{}'",
+                    sid, req.flow_id
+                ))]));
+            }
+        }
+
+        let ids: HashSet<&str> = req.step_ids.iter().map(|s| s.as_str()).collect();
+        let removed = remove_steps(&mut flow.steps, &ids);
+        // Legacy transitions may still point at the removed steps
+        let remaining: HashSet<String> = scryer_core::collect_step_ids(&flow.steps)
+            .into_iter()
+            .map(String::from)
+            .collect();
+        flow.transitions
+            .retain(|t| remaining.contains(&t.source) && remaining.contains(&t.target));
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Deleted {} step(s)",
+                    removed
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
+    #[tool(
+        description = "Reorder the model's flows. Pass every existing flow ID exactly once, in the desired order."
+    )]
+    fn reorder_flows(
+        &self,
+        Parameters(req): Parameters<ReorderFlowsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let current: Vec<&str> = model.flows.iter().map(|f| f.id.as_str()).collect();
+        if let Err(e) = check_same_ids(&current, &req.flow_ids, "flow") {
+            return Ok(CallToolResult::error(vec![Content::text(e)]));
+        }
+
+        let mut flows = std::mem::take(&mut model.flows);
+        flows.sort_by_key(|f| req.flow_ids.iter().position(|id| *id == f.id));
+        model.flows = flows;
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Reordered {} flow(s)",
+                    req.flow_ids.len()
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
+    #[tool(
+        description = "Reorder the steps of one step list — a flow's top level or a single branch. Pass every step ID in that list exactly once, in the desired order."
+    )]
+    fn reorder_steps(
+        &self,
+        Parameters(req): Parameters<ReorderStepsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let Some(flow) = model.flows.iter_mut().find(|f| f.id == req.flow_id) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Flow '{}' not found",
+                req.flow_id
+            ))]));
+        };
+        let Some(first) = req.step_ids.first() else {
+            return Ok(CallToolResult::error(vec![Content::text(
+                "step_ids is empty",
+            )]));
+        };
+        let Some((list, _)) = find_step_slot(&mut flow.steps, first) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Step '{}' not found in flow '{}'",
+                first, req.flow_id
+            ))]));
+        };
+
+        let current: Vec<&str> = list.iter().map(|s| s.id.as_str()).collect();
+        if let Err(e) = check_same_ids(&current, &req.step_ids, "step") {
+            return Ok(CallToolResult::error(vec![Content::text(e)]));
+        }
+        list.sort_by_key(|s| req.step_ids.iter().position(|id| *id == s.id));
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Reordered {} step(s)",
+                    req.step_ids.len()
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use crate::helpers::*;
 use crate::server::ScryerServer;
 use crate::types::*;
+use crate::validate::*;
 use rmcp::{
     handler::server::wrapper::Parameters,
     model::{CallToolResult, Content},
     tool, tool_router, ErrorData as McpError,
 };
-use scryer_core::{C4Kind, Flow, Group};
+use scryer_core::{Flow, Group, StartingLevel};
 use std::collections::HashSet;
 
 #[tool_router(router = tool_router_misc, vis = "pub(crate)")]
@@ -105,6 +106,7 @@ impl ScryerServer {
             )]));
         }
 
+        let mut structure_warnings: Vec<String> = Vec::new();
         for flow in &flows {
             // Validate step ID uniqueness (recursive)
             let all_ids = scryer_core::collect_step_ids(&flow.steps);
@@ -122,6 +124,7 @@ impl ScryerServer {
             // (AI agents often use "label" for step text, but the UI renders "description")
             let mut flow = flow.clone();
             migrate_flow_labels(&mut flow.steps);
+            structure_warnings.extend(check_flow_structure(&flow));
 
             // Replace or append
             if let Some(existing) = model.flows.iter_mut().find(|s| s.id == flow.id) {
@@ -138,11 +141,17 @@ impl ScryerServer {
                     .iter()
                     .map(|s| format!("'{}' ({} steps)", s.name, s.steps.len()))
                     .collect();
-                Ok(CallToolResult::success(vec![Content::text(format!(
-                    "Set {} flow(s): {}",
-                    flows.len(),
-                    summary.join(", ")
-                ))]))
+                let mut msg = format!("Set {} flow(s): {}", flows.len(), summary.join(", "));
+                if !structure_warnings.is_empty() {
+                    msg.push_str(&format!(
+                        "\n\n⚠️ FLOW STRUCTURE: These flows were saved, but review them:\n- {}",
+                        structure_warnings.join("\n- ")
+                    ));
+                }
+                if let Some(warning) = flow_mention_warning(&model) {
+                    msg.push_str(&warning);
+                }
+                Ok(CallToolResult::success(vec![Content::text(msg)]))
             }
             Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
         }
@@ -189,7 +198,7 @@ impl ScryerServer {
     }
 
     #[tool(
-        description = "Create or replace one or more groups. Groups organize nodes that share something beyond topology — common uses: a deployment unit (containers that ship together), a package/module (components in the same folder/bundle), or an ownership boundary. If a group with the given ID exists, it is replaced; otherwise it is appended.\n\nGroups can be nested via `parentGroupId`. Parent and child must contain members at the same C4 level.\n\nRules:\n- A node belongs to at most one group.\n- All `memberIds` in a group must refer to nodes at the same C4 level (containers OR components — other levels not supported).\n- `parentGroupId`, if set, must reference an existing group whose members are at the same C4 level; parent chains must not cycle.\n\nWrite the intent (e.g. \"deploys to Fly.io\", \"bundled as cms module\") into the group's `name` and `description` — that's what agents read.\n\nGroup schema: {id, name, memberIds, description?, parentGroupId?, contract?}."
+        description = "Create or replace one or more groups. Groups organize nodes that share something beyond topology — common uses: a deployment unit (containers that ship together), a package/module (components in the same folder/bundle), or an ownership boundary. If a group with the given ID exists, it is replaced; otherwise it is appended.\n\nGroups can be nested via `parentGroupId`. Parent and child must contain members at the same C4 level.\n\nRules:\n- A node belongs to at most one group. Adding it to a sub-group removes it from the parent group (and vice versa); a group left with only sub-groups is kept.\n- All `memberIds` in a group must refer to nodes at the same C4 level (containers OR components — other levels not supported).\n- `parentGroupId`, if set, must reference an existing group whose members are at the same C4 level; parent chains must not cycle. A sub-group's members must share a C4 parent with its parent group's members.\n\nWrite the intent (e.g. \"deploys to Fly.io\", \"bundled as cms module\") into the group's `name` and `description` — that's what agents read.\n\nGroup schema: {id, name, memberIds, description?, parentGroupId?, contract?, color?}. `color` is a hex accent like \"#22c55e\" (e.g. green for prod, amber for staging)."
     )]
     fn set_groups(
         &self,
@@ -229,35 +238,12 @@ impl ScryerServer {
         }
 
         let node_ids: HashSet<&str> = model.nodes.iter().map(|n| n.id.as_str()).collect();
-        let node_kind = |id: &str| model.nodes.iter().find(|n| n.id == id).map(|n| n.data.kind);
-
-        // Determine the effective C4 level of a group by scanning its members.
-        // Only containers and components may be grouped. All members in a group
-        // must share the same level.
-        let group_level = |g: &Group| -> Result<Option<C4Kind>, String> {
-            let mut level: Option<C4Kind> = None;
-            for mid in &g.member_ids {
-                let k = node_kind(mid).ok_or_else(|| {
-                    format!("Member '{}' in group '{}' not found in model", mid, g.name)
-                })?;
-                if !matches!(k, C4Kind::Container | C4Kind::Component) {
-                    return Err(format!(
-                        "Group '{}' contains a {:?} node; only containers and components can be grouped.",
-                        g.name, k
-                    ));
-                }
-                match level {
-                    None => level = Some(k),
-                    Some(l) if l != k => {
-                        return Err(format!(
-                            "Group '{}' mixes {:?} and {:?} members; all members must be at the same C4 level.",
-                            g.name, l, k
-                        ));
-                    }
-                    _ => {}
-                }
-            }
-            Ok(level)
+        let node_parent = |id: &str| {
+            model
+                .nodes
+                .iter()
+                .find(|n| n.id == id)
+                .and_then(|n| n.parent_id.as_deref())
         };
 
         // Stage the post-update group list so we can validate nesting holistically.
@@ -271,6 +257,12 @@ impl ScryerServer {
         }
 
         for group in &groups {
+            if let Some(color) = &group.color {
+                if let Err(e) = validate_hex_color(color, &format!("group '{}'", group.name)) {
+                    return Ok(CallToolResult::error(vec![Content::text(e)]));
+                }
+            }
+
             // Validate member IDs exist
             for mid in &group.member_ids {
                 if !node_ids.contains(mid.as_str()) {
@@ -282,7 +274,7 @@ impl ScryerServer {
             }
 
             // Same-level invariant (and containers/components only).
-            let child_level = match group_level(group) {
+            let child_level = match group_level(&model, group) {
                 Ok(l) => l,
                 Err(msg) => return Ok(CallToolResult::error(vec![Content::text(msg)])),
             };
@@ -314,7 +306,7 @@ impl ScryerServer {
                             ))]));
                         }
                     };
-                    let parent_level = match group_level(parent) {
+                    let parent_level = match group_level(&model, parent) {
                         Ok(l) => l,
                         Err(msg) => return Ok(CallToolResult::error(vec![Content::text(msg)])),
                     };
@@ -328,6 +320,28 @@ impl ScryerServer {
                     }
                     cursor = parent.parent_group_id.as_deref();
                 }
+
+                // A sub-group must stay inside the same C4 parent as the
+                // group it nests under (e.g. containers of one system).
+                if let Some(parent) = staged.iter().find(|g| &g.id == parent_id) {
+                    let scopes: HashSet<Option<&str>> = parent
+                        .member_ids
+                        .iter()
+                        .map(|mid| node_parent(mid))
+                        .collect();
+                    if !scopes.is_empty() {
+                        if let Some(mid) = group
+                            .member_ids
+                            .iter()
+                            .find(|mid| !scopes.contains(&node_parent(mid)))
+                        {
+                            return Ok(CallToolResult::error(vec![Content::text(format!(
+                                "Member '{}' of group '{}' is outside the parent of group '{}' — a sub-group's members must share a parent node with its parent group's members.",
+                                mid, group.name, parent.name
+                            ))]));
+                        }
+                    }
+                }
             }
 
             // Replace or append in the real model list.
@@ -349,8 +363,24 @@ impl ScryerServer {
                 }
             }
         }
-        // Remove empty groups
-        model.groups.retain(|g| !g.member_ids.is_empty());
+        // Remove empty groups. A group whose members all moved into its
+        // sub-groups still holds those sub-groups, so only drop groups that
+        // are empty and parent nothing — repeating, since a removal can
+        // leave its own parent empty.
+        loop {
+            let parents: HashSet<String> = model
+                .groups
+                .iter()
+                .filter_map(|g| g.parent_group_id.clone())
+                .collect();
+            let before = model.groups.len();
+            model
+                .groups
+                .retain(|g| !g.member_ids.is_empty() || parents.contains(&g.id));
+            if model.groups.len() == before {
+                break;
+            }
+        }
 
         let count = groups.len();
         let names: Vec<&str> = groups.iter().map(|g| g.name.as_str()).collect();
@@ -388,6 +418,168 @@ impl ScryerServer {
         Ok(CallToolResult::success(vec![Content::text(msg)]))
     }
 
+    #[tool(
+        description = "Clean up references to things that no longer exist: nodes whose parent is missing (with their children), edges whose source or target is missing, source map entries for unknown nodes or flows, group members that are gone, and groups left empty. Returns a report of every fix. Use on older models that fail validation because of leftovers from deleted nodes."
+    )]
+    fn repair_model(
+        &self,
+        Parameters(req): Parameters<RepairModelRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let fixes = scryer_core::repair_model(&mut model);
+        if fixes.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Model '{}' has nothing to repair.",
+                model_ref
+            ))]));
+        }
+        let report = format!("- {}", fixes.join("\n- "));
+        if req.dry_run.unwrap_or(false) {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Dry run: {} fix(es) for '{}'. Nothing was written.\n{}",
+                fixes.len(),
+                model_ref,
+                report
+            ))]));
+        }
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Repaired '{}' ({} fix(es)):\n{}",
+                    model_ref,
+                    fixes.len(),
+                    report
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
+    #[tool(
+        description = "Apply the fix attached to a review_node hint. Pass the hint's nodeId and action unchanged. A rename also updates @[Name] mentions of the node in descriptions and flows; a reversed edge gets a new ID matching its direction."
+    )]
+    fn apply_hint(
+        &self,
+        Parameters(req): Parameters<ApplyHintRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let summary =
+            match scryer_suggest::apply_action(&mut model, &req.node_id, &req.action.into()) {
+                Ok(s) => s,
+                Err(e) => return Ok(CallToolResult::error(vec![Content::text(e)])),
+            };
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                Ok(CallToolResult::success(vec![Content::text(summary)]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
+    #[tool(
+        description = "Set where the editor opens this model: a C4 level (system, container, component) and/or a node to focus on. A focused node wins over the level, so big models can open at any depth — including an operation's component."
+    )]
+    fn set_starting_level(
+        &self,
+        Parameters(req): Parameters<SetStartingLevelRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        if req.level.is_none() && req.node_id.is_none() {
+            return Ok(CallToolResult::error(vec![Content::text(
+                "Pass level, node_id, or both",
+            )]));
+        }
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        if let Some(level) = &req.level {
+            model.starting_level = Some(match level.as_str() {
+                "system" => StartingLevel::System,
+                "container" => StartingLevel::Container,
+                "component" => StartingLevel::Component,
+                _ => {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Invalid level '{}'. Must be: system, container, component",
+                        level
+                    ))]));
+                }
+            });
+        }
+        let mut focus = None;
+        if let Some(node_id) = &req.node_id {
+            if node_id.is_empty() {
+                model.starting_node = None;
+            } else {
+                let Some(node) = model.nodes.iter().find(|n| n.id == *node_id) else {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Node '{}' not found",
+                        node_id
+                    ))]));
+                };
+                focus = Some(node.data.name.clone());
+                model.starting_node = Some(node_id.clone());
+            }
+        }
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                let level = match &model.starting_level {
+                    Some(StartingLevel::Container) => "container",
+                    Some(StartingLevel::Component) => "component",
+                    _ => "system",
+                };
+                let msg = match (&focus, &model.starting_node) {
+                    (Some(name), Some(id)) => {
+                        format!("'{}' now opens focused on {} [{}]", model_ref, name, id)
+                    }
+                    _ => format!("'{}' now opens at the {} level", model_ref, level),
+                };
+                Ok(CallToolResult::success(vec![Content::text(msg)]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
     #[tool(description = "Delete a group by ID. Members are ungrouped, not deleted.")]
     fn delete_group(
         &self,
@@ -427,4 +619,35 @@ impl ScryerServer {
             Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
         }
     }
+
+    #[tool(
+        description = "Report which scryer-mcp is running: its version, the global models directory it reads, how many models it can see, whether AI review is configured, and the session's active model. Use when debugging a stale or duplicate install."
+    )]
+    fn server_info(&self) -> Result<CallToolResult, McpError> {
+        let model_count = match scryer_core::list_all_models() {
+            Ok(entries) => entries.len().to_string(),
+            Err(e) => format!("unavailable ({})", e),
+        };
+        let settings = scryer_core::read_settings();
+        let ai = if scryer_core::ai_configured(&settings) {
+            format!(
+                "yes ({})",
+                settings.effective_provider().unwrap_or_default()
+            )
+        } else {
+            "no".to_string()
+        };
+        let active = match self.active_model.lock().unwrap().as_ref() {
+            Some(r) => r.to_string(),
+            None => "none".to_string(),
+        };
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "scryer-mcp {}\nModels directory: {}\nModels: {}\nAI configured: {}\nActive model: {}",
+            env!("CARGO_PKG_VERSION"),
+            scryer_core::models_dir().display(),
+            model_count,
+            ai,
+            active
+        ))]))
+    }
 }
@@ -1,5 +1,36 @@
+mod attachments;
 mod edges;
+mod flows;
 mod misc;
 mod nodes;
 mod read;
 mod task;
+
+#[cfg(test)]
+mod testing {
+    use rmcp::model::CallToolResult;
+    use scryer_core::{C4ModelData, ModelRef};
+
+    /// Store `model` as a project-local model in a fresh temp directory and
+    /// return that directory with the model's ref string.
+    pub(crate) fn stored(tag: &str, model: &C4ModelData) -> (std::path::PathBuf, String) {
+        let root =
+            std::env::temp_dir().join(format!("scryer-tools-{}-{}", tag, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let model_ref = ModelRef::ProjectLocal(root.clone());
+        scryer_core::write_model_at(&model_ref, model).unwrap();
+        (root, model_ref.to_ref_string())
+    }
+
+    /// Whether a tool call errored, with its text content joined.
+    pub(crate) fn text(result: CallToolResult) -> (bool, String) {
+        let text = result
+            .content
+            .iter()
+            .filter_map(|c| c.as_text())
+            .map(|t| t.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        (result.is_error == Some(true), text)
+    }
+}
@@ -17,7 +17,7 @@ use std::collections::HashSet;
 #[tool_router(router = tool_router_nodes, vis = "pub(crate)")]
 impl ScryerServer {
     #[tool(
-        description = "Create or overwrite a model with complete data in one call. Use for initial model creation or full rewrites. Pass the full model JSON with all nodes and edges. Node positions are handled automatically by the UI — do not include position data.\n\nJSON format:\n- Containers MUST have `parentId` set to a system node's ID. Components MUST have `parentId` set to a container's ID. Without `parentId`, nodes render as flat siblings instead of nested.\n- Include `sources`, `technology`, `shape`, and `status` directly in each node's data — do NOT add them in a separate pass.\n- `position` and `type` can be omitted (default to auto-layout and \"c4\").\n- Edge IDs follow the pattern `edge-{source}-{target}`.\n- Edge labels MUST be short (max 30 characters). One verb phrase per edge.\n\nExample:\n{\"nodes\": [\n  {\"id\": \"node-1\", \"data\": {\"name\": \"User\", \"description\": \"End user\", \"kind\": \"person\", \"status\": \"proposed\"}},\n  {\"id\": \"node-2\", \"data\": {\"name\": \"My System\", \"description\": \"Main system\", \"kind\": \"system\", \"status\": \"proposed\"}},\n  {\"id\": \"node-3\", \"parentId\": \"node-2\", \"data\": {\"name\": \"Web App\", \"description\": \"Frontend SPA\", \"kind\": \"container\", \"technology\": \"React\", \"status\": \"proposed\"}},\n  {\"id\": \"node-4\", \"parentId\": \"node-2\", \"data\": {\"name\": \"Database\", \"description\": \"Primary data store\", \"kind\": \"container\", \"technology\": \"PostgreSQL\", \"shape\": \"cylinder\", \"status\": \"proposed\"}}\n], \"edges\": [\n  {\"id\": \"edge-node-1-node-2\", \"source\": \"node-1\", \"target\": \"node-2\", \"data\": {\"label\": \"uses\"}},\n  {\"id\": \"edge-node-3-node-4\", \"source\": \"node-3\", \"target\": \"node-4\", \"data\": {\"label\": \"reads from\", \"method\": \"SQL\"}}\n]}"
+        description = "Create or overwrite a model with complete data in one call. Use for initial model creation or full rewrites. Pass the full model JSON with all nodes and edges. Node positions are handled automatically by the UI — do not include position data.\n\nJSON format:\n- Containers MUST have `parentId` set to a system node's ID. Components MUST have `parentId` set to a container's ID. Operations, processes, and models MUST have `parentId` set to a component's ID. A model that breaks this hierarchy is rejected.\n- Include `sources`, `technology`, `shape`, and `status` directly in each node's data — do NOT add them in a separate pass.\n- `position` and `type` can be omitted (default to auto-layout and \"c4\").\n- Edge IDs follow the pattern `edge-{source}-{target}`.\n- Edge labels MUST be short (max 30 characters). One verb phrase per edge.\n\nExample:\n{\"nodes\": [\n  {\"id\": \"node-1\", \"data\": {\"name\": \"User\", \"description\": \"End user\", \"kind\": \"person\", \"status\": \"proposed\"}},\n  {\"id\": \"node-2\", \"data\": {\"name\": \"My System\", \"description\": \"Main system\", \"kind\": \"system\", \"status\": \"proposed\"}},\n  {\"id\": \"node-3\", \"parentId\": \"node-2\", \"data\": {\"name\": \"Web App\", \"description\": \"Frontend SPA\", \"kind\": \"container\", \"technology\": \"React\", \"status\": \"proposed\"}},\n  {\"id\": \"node-4\", \"parentId\": \"node-2\", \"data\": {\"name\": \"Database\", \"description\": \"Primary data store\", \"kind\": \"container\", \"technology\": \"PostgreSQL\", \"shape\": \"cylinder\", \"status\": \"proposed\"}}\n], \"edges\": [\n  {\"id\": \"edge-node-1-node-2\", \"source\": \"node-1\", \"target\": \"node-2\", \"data\": {\"label\": \"uses\"}},\n  {\"id\": \"edge-node-3-node-4\", \"source\": \"node-3\", \"target\": \"node-4\", \"data\": {\"label\": \"reads from\", \"method\": \"SQL\"}}\n]}"
     )]
     fn set_model(
         &self,
@@ -37,67 +37,18 @@ impl ScryerServer {
             }
         };
 
-        // Validate nodes
-        for node in &model.nodes {
-            if node.data.description.len() > 200
-                && !matches!(
-                    node.data.kind,
-                    C4Kind::Operation | C4Kind::Process | C4Kind::Model
+        let errors = validate_model_data(&model);
+        if !errors.is_empty() {
+            let msg = if errors.len() == 1 {
+                errors[0].clone()
+            } else {
+                format!(
+                    "Model has {} errors:\n- {}",
+                    errors.len(),
+                    errors.join("\n- ")
                 )
-            {
-                return Ok(CallToolResult::error(vec![Content::text(format!(
-                    "Description for '{}' must be 200 characters or less",
-                    node.data.name
-                ))]));
-            }
-            if let Some(tech) = &node.data.technology {
-                if tech.len() > 28 {
-                    return Ok(CallToolResult::error(vec![Content::text(format!(
-                        "Technology '{}' on '{}' exceeds 28 character limit",
-                        tech, node.data.name
-                    ))]));
-                }
-            }
-            if node.data.kind == C4Kind::Operation {
-                if let Err(e) = validate_identifier(
-                    &node.data.name,
-                    &format!("{:?} '{}'", node.data.kind, node.id),
-                ) {
-                    return Ok(CallToolResult::error(vec![Content::text(e)]));
-                }
-            }
-            if node.data.kind == C4Kind::Model {
-                if let Err(e) = validate_type_name(
-                    &node.data.name,
-                    &format!("{:?} '{}'", node.data.kind, node.id),
-                ) {
-                    return Ok(CallToolResult::error(vec![Content::text(e)]));
-                }
-            }
-            if !node.data.properties.is_empty() {
-                if let Err(e) =
-                    validate_property_labels(&node.data.properties, &format!("node '{}'", node.id))
-                {
-                    return Ok(CallToolResult::error(vec![Content::text(e)]));
-                }
-            }
-        }
-
-        // Validate no children under external systems
-        if let Err(e) = validate_no_children_of_external(&model.nodes) {
-            return Ok(CallToolResult::error(vec![Content::text(e)]));
-        }
-
-        // Validate edge labels
-        for edge in &model.edges {
-            if let Some(data) = &edge.data {
-                if data.label.len() > 30 {
-                    return Ok(CallToolResult::error(vec![Content::text(format!(
-                        "Edge label '{}' exceeds 30 character limit",
-                        data.label
-                    ))]));
-                }
-            }
+            };
+            return Ok(CallToolResult::error(vec![Content::text(msg)]));
         }
 
         // Set project_path to cwd if not already set — needed for source map → editor linking
@@ -112,29 +63,37 @@ impl ScryerServer {
             node.position = None;
         }
 
-        // Deduplicate edges by ID (keep first occurrence)
-        {
-            let mut seen = HashSet::new();
-            model.edges.retain(|e| seen.insert(e.id.clone()));
-        }
-
         let node_count = model.nodes.len();
         let edge_count = model.edges.len();
         let cross_level_warnings = check_disconnected_nodes(&model);
         let bidir_warnings = check_bidirectional_edges(&model);
         let mention_warnings = check_mention_edges(&model);
         let cross_container_warnings = check_cross_container_edges(&model);
-        match scryer_core::write_model_at(&model_ref, &model) {
+        let flow_warning = flow_mention_warning(&model);
+        let dry_run = req.dry_run.unwrap_or(false);
+        let written = if dry_run {
+            Ok(())
+        } else {
+            scryer_core::write_model_at(&model_ref, &model)
+        };
+        match written {
             Ok(()) => {
-                let _ = scryer_core::save_baseline_at(&model_ref, &model);
-                // Register the project if project-local
-                if let scryer_core::ModelRef::ProjectLocal(ref path) = model_ref {
-                    let _ = scryer_core::register_project(path);
-                }
-                let mut msg = format!(
-                    "Set model '{}' ({} nodes, {} edges)",
-                    model_ref, node_count, edge_count
-                );
+                let mut msg = if dry_run {
+                    format!(
+                        "Dry run: model '{}' is valid ({} nodes, {} edges). Nothing was written.",
+                        model_ref, node_count, edge_count
+                    )
+                } else {
+                    let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                    // Register the project if project-local
+                    if let scryer_core::ModelRef::ProjectLocal(ref path) = model_ref {
+                        let _ = scryer_core::register_project(path);
+                    }
+                    format!(
+                        "Set model '{}' ({} nodes, {} edges)",
+                        model_ref, node_count, edge_count
+                    )
+                };
                 if !cross_level_warnings.is_empty() {
                     msg.push_str(&format!(
                         "\n\n⚠️ DISCONNECTED NODES: The UI shows one abstraction level at a time. \
@@ -166,12 +125,66 @@ impl ScryerServer {
                         cross_container_warnings.join("\n- ")
                     ));
                 }
+                if let Some(warning) = flow_warning {
+                    msg.push_str(&warning);
+                }
                 Ok(CallToolResult::success(vec![Content::text(msg)]))
             }
             Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
         }
     }
 
+    #[tool(
+        description = "Import a model pasted from another scryer instance (e.g. get_model output). Unlike set_model, the data must pass stricter validation — every parent must exist and be the right kind — positions are kept as-is, and an existing model with the same name is never replaced unless overwrite is true."
+    )]
+    fn import_model(
+        &self,
+        Parameters(req): Parameters<ImportModelRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = scryer_core::ModelRef::parse(&req.name);
+        if model_ref.stored_path().exists() && !req.overwrite.unwrap_or(false) {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Model '{}' already exists. Pass overwrite: true to replace it, or import under another name.",
+                model_ref
+            ))]));
+        }
+        let model: C4ModelData = match serde_json::from_str(&req.data) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Invalid model JSON: {}",
+                    e
+                ))]));
+            }
+        };
+
+        let errors = validate_model_data(&model);
+        if !errors.is_empty() {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Import rejected — {} error(s):\n- {}",
+                errors.len(),
+                errors.join("\n- ")
+            ))]));
+        }
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                if let scryer_core::ModelRef::ProjectLocal(ref path) = model_ref {
+                    let _ = scryer_core::register_project(path);
+                }
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Imported model '{}' ({} nodes, {} edges, {} flows)",
+                    model_ref,
+                    model.nodes.len(),
+                    model.edges.len(),
+                    model.flows.len()
+                ))]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
     #[tool(
         description = "Add one or more nodes to a model. Hierarchy: person/system (top-level), container (parent=system), component (parent=container), operation/process/model (parent=component). All nodes use type 'c4'."
     )]
@@ -197,13 +210,13 @@ impl ScryerServer {
         for item in &req.nodes {
             let kind = parse_kind(&item.kind)?;
 
-            if item.description.len() > 200
-                && !matches!(kind, C4Kind::Operation | C4Kind::Process | C4Kind::Model)
-            {
-                return Ok(CallToolResult::error(vec![Content::text(format!(
-                    "Description for '{}' must be 200 characters or less",
-                    item.name
-                ))]));
+            if let Err(e) = scryer_core::limits::check_description_len(
+                &kind,
+                &item.description,
+                &item.name,
+                model.limits.as_ref(),
+            ) {
+                return Ok(CallToolResult::error(vec![Content::text(e)]));
             }
             if let Some(tech) = &item.technology {
                 if tech.len() > 28 {
@@ -229,6 +242,11 @@ impl ScryerServer {
                     return Ok(CallToolResult::error(vec![Content::text(e)]));
                 }
             }
+            if let Some(tags) = &item.tags {
+                if let Err(e) = validate_tags(tags, &format!("node '{}'", item.name)) {
+                    return Ok(CallToolResult::error(vec![Content::text(e)]));
+                }
+            }
 
             if let Err(e) = validate_parent(&model, &kind, item.parent_id.as_deref()) {
                 return Ok(CallToolResult::error(vec![Content::text(e)]));
@@ -266,6 +284,8 @@ impl ScryerServer {
                     contract: item.contract.clone().unwrap_or_default(),
                     notes: item.notes.clone().unwrap_or_default(),
                     properties: item.properties.clone().unwrap_or_default(),
+                    tags: item.tags.clone().unwrap_or_default(),
+                    owner: item.owner.clone().filter(|o| !o.trim().is_empty()),
                 },
                 parent_id: item.parent_id.clone(),
             });
@@ -334,16 +354,13 @@ impl ScryerServer {
 
         // Validate subtree nodes
         for node in &subtree.nodes {
-            if node.data.description.len() > 200
-                && !matches!(
-                    node.data.kind,
-                    C4Kind::Operation | C4Kind::Process | C4Kind::Model
-                )
-            {
-                return Ok(CallToolResult::error(vec![Content::text(format!(
-                    "Description for '{}' must be 200 characters or less",
-                    node.data.name
-                ))]));
+            if let Err(e) = scryer_core::limits::check_description_len(
+                &node.data.kind,
+                &node.data.description,
+                &node.data.name,
+                model.limits.as_ref(),
+            ) {
+                return Ok(CallToolResult::error(vec![Content::text(e)]));
             }
             if let Some(tech) = &node.data.technology {
                 if tech.len() > 28 {
@@ -376,16 +393,16 @@ impl ScryerServer {
                     return Ok(CallToolResult::error(vec![Content::text(e)]));
                 }
             }
+            if let Err(e) = validate_tags(&node.data.tags, &format!("node '{}'", node.id)) {
+                return Ok(CallToolResult::error(vec![Content::text(e)]));
+            }
         }
 
         // Validate edge labels
         for edge in &subtree.edges {
             if let Some(data) = &edge.data {
-                if data.label.len() > 30 {
-                    return Ok(CallToolResult::error(vec![Content::text(format!(
-                        "Edge label '{}' exceeds 30 character limit",
-                        data.label
-                    ))]));
+                if let Err(e) = scryer_core::limits::check_edge_label_len(&data.label) {
+                    return Ok(CallToolResult::error(vec![Content::text(e)]));
                 }
             }
         }
@@ -411,6 +428,7 @@ impl ScryerServer {
         model.edges.retain(|e| {
             !old_descendants.contains(&e.source) && !old_descendants.contains(&e.target)
         });
+        scryer_core::prune_ref_positions(&mut model, &old_descendants);
 
         // Validate all incoming nodes have parent chains leading to node_id
         let incoming_ids: HashSet<_> = subtree.nodes.iter().map(|n| n.id.clone()).collect();
@@ -500,6 +518,9 @@ impl ScryerServer {
                     edge.target
                 ))]));
             }
+            if let Err(e) = validate_no_self_loop(&edge.source, &edge.target) {
+                return Ok(CallToolResult::error(vec![Content::text(e)]));
+            }
         }
         // Skip subtree edges whose ID already exists in the model (warn the agent)
         let existing_edge_ids: HashSet<_> = model.edges.iter().map(|e| e.id.clone()).collect();
@@ -572,6 +593,7 @@ impl ScryerServer {
                 }
                 let mention_warnings = check_mention_edges(&model);
         let cross_container_warnings = check_cross_container_edges(&model);
+                let flow_warning = flow_mention_warning(&model);
                 if !mention_warnings.is_empty() {
                     msg.push_str(&format!(
                         "\n\n⚠️ MENTIONS WITHOUT EDGES: Descriptions reference nodes with @[Name] \
@@ -587,6 +609,9 @@ impl ScryerServer {
                         cross_container_warnings.join("\n- ")
                     ));
                 }
+                if let Some(warning) = flow_warning {
+                    msg.push_str(&warning);
+                }
                 Ok(CallToolResult::success(vec![Content::text(msg)]))
             }
             Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
@@ -613,6 +638,7 @@ impl ScryerServer {
         };
 
         let mut updated = Vec::new();
+        let limits = model.limits.clone();
         for item in req.nodes {
             let node_idx = match model.nodes.iter().position(|n| n.id == item.node_id) {
                 Some(i) => i,
@@ -662,16 +688,13 @@ impl ScryerServer {
                 node.data.name = name;
             }
             if let Some(desc) = item.description {
-                if desc.len() > 200
-                    && !matches!(
-                        node.data.kind,
-                        C4Kind::Operation | C4Kind::Process | C4Kind::Model
-                    )
-                {
-                    return Ok(CallToolResult::error(vec![Content::text(format!(
-                        "Description for '{}' must be 200 characters or less",
-                        item.node_id
-                    ))]));
+                if let Err(e) = scryer_core::limits::check_description_len(
+                    &node.data.kind,
+                    &desc,
+                    &item.node_id,
+                    limits.as_ref(),
+                ) {
+                    return Ok(CallToolResult::error(vec![Content::text(e)]));
                 }
                 node.data.description = desc;
             }
@@ -721,6 +744,15 @@ impl ScryerServer {
                 }
                 node.data.properties = p;
             }
+            if let Some(tags) = item.tags {
+                if let Err(e) = validate_tags(&tags, &format!("node '{}'", item.node_id)) {
+                    return Ok(CallToolResult::error(vec![Content::text(e)]));
+                }
+                node.data.tags = tags;
+            }
+            if let Some(owner) = item.owner {
+                node.data.owner = Some(owner).filter(|o| !o.trim().is_empty());
+            }
             if let Some(locations) = item.source {
                 if locations.is_empty() {
                     model.source_map.remove(&item.node_id);
@@ -744,12 +776,43 @@ impl ScryerServer {
     }
 
     #[tool(
-        description = "Delete one or more nodes and all their descendants. Connected edges are also removed."
+        description = "Set the status of many nodes at once. Select nodes with node_ids, or with a filter (kind, parent_id, current status) — e.g. parent_id + to: \"implemented\" marks a whole container's children implemented. Person nodes never carry status and are left alone. Returns the IDs that changed."
     )]
-    fn delete_nodes(
+    fn set_status(
         &self,
-        Parameters(req): Parameters<DeleteNodeRequest>,
+        Parameters(req): Parameters<SetStatusRequest>,
     ) -> Result<CallToolResult, McpError> {
+        let Some(to) = parse_status(&req.to) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Invalid status '{}'. Must be: proposed, in_progress, implemented, verified, vagrant",
+                req.to
+            ))]));
+        };
+        let from = match req.status.as_deref() {
+            Some(s) => match parse_status(s) {
+                Some(st) => Some(st),
+                None => {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Invalid status '{}'",
+                        s
+                    ))]));
+                }
+            },
+            None => None,
+        };
+        let kind = req.kind.as_deref().map(parse_kind).transpose()?;
+        let reason = req.reason.trim();
+        if reason.is_empty() {
+            return Ok(CallToolResult::error(vec![Content::text(
+                "`reason` is required when changing status.",
+            )]));
+        }
+        if req.node_ids.is_none() && kind.is_none() && req.parent_id.is_none() && from.is_none() {
+            return Ok(CallToolResult::error(vec![Content::text(
+                "Pass node_ids or at least one filter (kind, parent_id, status).",
+            )]));
+        }
+
         let model_ref = match self.resolve_model(req.model) {
             Ok(r) => r,
             Err(e) => return Ok(e),
@@ -764,40 +827,311 @@ impl ScryerServer {
             }
         };
 
-        let mut to_delete = HashSet::new();
-        for nid in &req.node_ids {
-            to_delete.insert(nid.clone());
+        if let Some(ids) = &req.node_ids {
+            for id in ids {
+                if !model.nodes.iter().any(|n| n.id == *id) {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Node '{}' not found",
+                        id
+                    ))]));
+                }
+            }
         }
-        let mut changed = true;
-        while changed {
-            changed = false;
-            for n in &model.nodes {
-                if let Some(pid) = &n.parent_id {
-                    if to_delete.contains(pid) && !to_delete.contains(&n.id) {
-                        to_delete.insert(n.id.clone());
-                        changed = true;
-                    }
+
+        let targets: Vec<usize> = model
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.data.kind != C4Kind::Person)
+            .filter(|(_, n)| req.node_ids.as_ref().is_none_or(|ids| ids.contains(&n.id)))
+            .filter(|(_, n)| kind.as_ref().is_none_or(|k| n.data.kind == *k))
+            .filter(|(_, n)| {
+                req.parent_id
+                    .as_deref()
+                    .is_none_or(|p| n.parent_id.as_deref() == Some(p))
+            })
+            .filter(|(_, n)| from.is_none() || n.data.status == from)
+            .filter(|(_, n)| n.data.status != Some(to))
+            .map(|(i, _)| i)
+            .collect();
+
+        if to == Status::Verified {
+            let mut unmet_lines = Vec::new();
+            for &i in &targets {
+                let node = &model.nodes[i];
+                let unmet = check_verified_gate(
+                    &model.nodes,
+                    &model.groups,
+                    &node.id,
+                    &node.parent_id,
+                    &node.data.contract,
+                );
+                if !unmet.is_empty() {
+                    unmet_lines.push(format!("'{}':\n{}", node.id, unmet.join("\n")));
                 }
             }
+            if !unmet_lines.is_empty() {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Cannot set these nodes to verified — expect contract items are not yet passed:\n{}\n\nMark each as passed (passed: true) or set status to 'implemented' instead.",
+                    unmet_lines.join("\n")
+                ))]));
+            }
         }
 
-        let before = model.nodes.len();
-        model.nodes.retain(|n| !to_delete.contains(&n.id));
-        model
-            .edges
-            .retain(|e| !to_delete.contains(&e.source) && !to_delete.contains(&e.target));
-        let removed = before - model.nodes.len();
+        if targets.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No nodes matched — nothing changed.",
+            )]));
+        }
+
+        let mut changed = Vec::new();
+        for &i in &targets {
+            let node = &mut model.nodes[i];
+            node.data.status = Some(to);
+            node.data.status_reason = Some(reason.to_string());
+            changed.push(node.id.clone());
+        }
 
         match scryer_core::write_model_at(&model_ref, &model) {
             Ok(()) => {
                 let _ = scryer_core::save_baseline_at(&model_ref, &model);
                 Ok(CallToolResult::success(vec![Content::text(format!(
-                    "Deleted {} node(s)",
-                    removed
+                    "Set {} node(s) to {}: {}",
+                    changed.len(),
+                    status_str(&Some(to)),
+                    changed.join(", ")
                 ))]))
             }
             Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
         }
     }
 
+    #[tool(
+        description = "Delete one or more nodes and all their descendants. Connected edges, source map entries, and group memberships are also removed."
+    )]
+    fn delete_nodes(
+        &self,
+        Parameters(req): Parameters<DeleteNodeRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let removed = remove_subtree(&mut model, &req.node_ids).len();
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                let mut msg = format!("Deleted {} node(s)", removed);
+                if let Some(warning) = flow_mention_warning(&model) {
+                    msg.push_str(&warning);
+                }
+                Ok(CallToolResult::success(vec![Content::text(msg)]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+
+    #[tool(
+        description = "Turn a modeled system into an external one. Sets external: true and deletes everything inside it (external systems can't have children), along with the removed nodes' edges, source map entries, and group memberships. Edges on the system itself are kept. Returns what was removed."
+    )]
+    fn make_external(
+        &self,
+        Parameters(req): Parameters<MakeExternalRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.model) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let mut model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let Some(system) = model.nodes.iter().find(|n| n.id == req.node_id) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Node '{}' not found",
+                req.node_id
+            ))]));
+        };
+        if system.data.kind != C4Kind::System {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "'{}' is a {}, not a system — only systems can be external",
+                system.data.name,
+                kind_str(&system.data.kind)
+            ))]));
+        }
+        let system_name = system.data.name.clone();
+
+        let subtree = subtree_ids(&model, &req.node_id);
+        let mut removed_by_kind: Vec<(&str, usize)> = Vec::new();
+        for node in model
+            .nodes
+            .iter()
+            .filter(|n| n.id != req.node_id && subtree.contains(&n.id))
+        {
+            let kind = kind_str(&node.data.kind);
+            match removed_by_kind.iter_mut().find(|(k, _)| *k == kind) {
+                Some((_, count)) => *count += 1,
+                None => removed_by_kind.push((kind, 1)),
+            }
+        }
+        let removed_by_kind: Vec<String> = removed_by_kind
+            .into_iter()
+            .map(|(kind, count)| format!("{} {}", count, kind))
+            .collect();
+
+        let children: Vec<String> = model
+            .nodes
+            .iter()
+            .filter(|n| n.parent_id.as_deref() == Some(req.node_id.as_str()))
+            .map(|n| n.id.clone())
+            .collect();
+        let edges_before = model.edges.len();
+        let sources_before = model.source_map.len();
+        let groups_before: Vec<String> = model.groups.iter().map(|g| g.id.clone()).collect();
+        let to_delete = remove_subtree(&mut model, &children);
+        let edges_removed = edges_before - model.edges.len();
+        let sources_removed = sources_before - model.source_map.len();
+        if let Some(node) = model.nodes.iter_mut().find(|n| n.id == req.node_id) {
+            node.data.external = Some(true);
+        }
+        scryer_core::normalize_groups(&mut model);
+        let emptied_groups: Vec<String> = groups_before
+            .into_iter()
+            .filter(|id| !model.groups.iter().any(|g| &g.id == id))
+            .collect();
+
+        match scryer_core::write_model_at(&model_ref, &model) {
+            Ok(()) => {
+                let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                let mut msg = format!("Marked '{}' as external.", system_name);
+                if to_delete.is_empty() {
+                    msg.push_str(" It had no children.");
+                } else {
+                    msg.push_str(&format!(
+                        "\n\nRemoved {} descendant node(s): {}",
+                        to_delete.len(),
+                        removed_by_kind.join(", ")
+                    ));
+                    msg.push_str(&format!("\nRemoved {} edge(s)", edges_removed));
+                    if sources_removed > 0 {
+                        msg.push_str(&format!(
+                            "\nRemoved source map entries for {} node(s)",
+                            sources_removed
+                        ));
+                    }
+                    if !emptied_groups.is_empty() {
+                        msg.push_str(&format!(
+                            "\nRemoved emptied group(s): {}",
+                            emptied_groups.join(", ")
+                        ));
+                    }
+                }
+                if let Some(warning) = flow_mention_warning(&model) {
+                    msg.push_str(&warning);
+                }
+                Ok(CallToolResult::success(vec![Content::text(msg)]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::testing::{stored, text};
+    use scryer_core::ModelRef;
+
+    fn system_with_children() -> C4ModelData {
+        serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "user", "data": { "name": "User", "kind": "person" } },
+                { "id": "pay", "data": { "name": "Payments", "kind": "system" } },
+                { "id": "api", "parentId": "pay", "data": { "name": "API", "kind": "container" } },
+                { "id": "ledger", "parentId": "api", "data": { "name": "Ledger", "kind": "component" } },
+                { "id": "shop", "data": { "name": "Shop", "kind": "system" } }
+            ],
+            "edges": [
+                { "id": "e1", "source": "user", "target": "pay" },
+                { "id": "e2", "source": "shop", "target": "pay" },
+                { "id": "e3", "source": "shop", "target": "api" },
+                { "id": "e4", "source": "ledger", "target": "shop" }
+            ],
+            "sourceMap": {
+                "api": [{ "pattern": "pay/api/**" }],
+                "ledger": [{ "pattern": "pay/api/ledger.rs" }],
+                "shop": [{ "pattern": "shop/**" }]
+            },
+            "groups": [
+                { "id": "group-1", "name": "Fly", "memberIds": ["api"] }
+            ]
+        }))
+        .unwrap()
+    }
+
+    /// Node and edge IDs left in the stored model.
+    fn remaining(model: &C4ModelData) -> (Vec<&str>, Vec<&str>) {
+        (
+            model.nodes.iter().map(|n| n.id.as_str()).collect(),
+            model.edges.iter().map(|e| e.id.as_str()).collect(),
+        )
+    }
+
+    #[test]
+    fn make_external_removes_the_subtree_and_keeps_own_edges() {
+        let (root, name) = stored("make-external", &system_with_children());
+        let req: MakeExternalRequest =
+            serde_json::from_value(serde_json::json!({ "model": name, "node_id": "pay" })).unwrap();
+        let (failed, text) = text(ScryerServer::new().make_external(Parameters(req)).unwrap());
+        assert!(!failed, "{text}");
+        assert!(text.contains("Removed 2 descendant node(s)"), "{text}");
+        assert!(text.contains("Removed emptied group(s): group-1"), "{text}");
+
+        let model = scryer_core::read_model_at(&ModelRef::parse(&name)).unwrap();
+        assert_eq!(
+            remaining(&model),
+            (vec!["user", "pay", "shop"], vec!["e1", "e2"])
+        );
+        assert_eq!(model.nodes[1].data.external, Some(true));
+        assert_eq!(model.source_map.keys().collect::<Vec<_>>(), vec!["shop"]);
+        assert!(model.groups.is_empty());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn delete_nodes_cleans_up_like_make_external() {
+        let (root, name) = stored("delete-nodes", &system_with_children());
+        let req: DeleteNodeRequest =
+            serde_json::from_value(serde_json::json!({ "model": name, "node_ids": ["api"] }))
+                .unwrap();
+        let (failed, text) = text(ScryerServer::new().delete_nodes(Parameters(req)).unwrap());
+        assert!(!failed, "{text}");
+        assert!(text.contains("Deleted 2 node(s)"), "{text}");
+
+        let model = scryer_core::read_model_at(&ModelRef::parse(&name)).unwrap();
+        assert_eq!(
+            remaining(&model),
+            (vec!["user", "pay", "shop"], vec!["e1", "e2"])
+        );
+        assert_eq!(model.source_map.keys().collect::<Vec<_>>(), vec!["shop"]);
+        assert!(model.groups.is_empty());
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
@@ -7,8 +7,8 @@ use rmcp::{
     model::{CallToolResult, Content},
     tool, tool_router, ErrorData as McpError,
 };
-use scryer_core::{C4Node, ModelRef, SourceLocation};
-use std::collections::{HashMap, HashSet};
+use scryer_core::{C4Kind, C4ModelData, C4Node, ModelRef, SourceLocation, StartingLevel};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 impl ScryerServer {
     /// Resolve an optional model name to a ModelRef.
@@ -37,8 +37,39 @@ impl ScryerServer {
         *self.active_model.lock().unwrap() = Some(model_ref.clone());
         Ok(model_ref)
     }
+
+    /// Count a full read of `model` and return a reminder once the same
+    /// version has been read more than the threshold number of times. The
+    /// count resets whenever the model is written. `SCRYER_REREAD_WARNING`
+    /// sets the threshold; `0` turns the reminder off.
+    pub(crate) fn note_full_read(
+        &self,
+        model_ref: &ModelRef,
+        model: &C4ModelData,
+    ) -> Option<String> {
+        let threshold = match std::env::var("SCRYER_REREAD_WARNING") {
+            Ok(v) => v.trim().parse::<u32>().unwrap_or(REREAD_WARNING_THRESHOLD),
+            Err(_) => REREAD_WARNING_THRESHOLD,
+        };
+        let mut reads = self.full_reads.lock().unwrap();
+        let entry = reads.entry(model_ref.to_ref_string()).or_insert((None, 0));
+        if entry.0 != model.updated_at {
+            *entry = (model.updated_at.clone(), 0);
+        }
+        entry.1 += 1;
+        (threshold > 0 && entry.1 > threshold).then(|| {
+            format!(
+                "ℹ️ This is read {} of the full model with no changes in between. To find the next thing to build, call get_task — it orders the work for you. For one part of the model, get_node is cheaper; for what changed, get_changes.",
+                entry.1
+            )
+        })
+    }
 }
 
+/// Unchanged full reads of a model allowed before `get_model` adds a
+/// reminder about `get_task`.
+const REREAD_WARNING_THRESHOLD: u32 = 3;
+
 #[tool_router(router = tool_router_read, vis = "pub(crate)")]
 impl ScryerServer {
     #[tool(description = "List available models. Shows the project model (from .scryer/model.scry in the current working directory, marked with *) and any templates (in ~/.scryer/). The project model is auto-selected as the active model. To work on a template instead, pass its name to any tool.")]
@@ -83,7 +114,7 @@ impl ScryerServer {
     }
 
     #[tool(
-        description = "Get the full JSON content of a model. If name is omitted, automatically resolves the model linked to the current working directory (project-local .scryer/model.scry first, then global). Returns {nodes: [{id, parentId?, data: {name, description, kind, technology?, external?, shape?, status?, sources?, contract?}}], edges: [{id, source, target, data: {label, method?}}], flows: [{id, name, description?, steps: [{id, description?, branches?: [{condition, steps}]}]}], sourceMap: {nodeId: [{pattern, line?, endLine?}]}, contract?, startingLevel?}. Positions and node type are omitted (UI-only). Step descriptions can use @[Name] mentions to reference architecture nodes. For scoped reads, prefer get_node. For implementation, use get_task instead — it handles dependency ordering and returns one work unit at a time."
+        description = "Get the full JSON content of a model. If name is omitted, automatically resolves the model linked to the current working directory (project-local .scryer/model.scry first, then global). Returns {nodes: [{id, parentId?, data: {name, description, kind, technology?, external?, shape?, status?, sources?, contract?}}], edges: [{id, source, target, data: {label, method?}}], flows: [{id, name, description?, steps: [{id, description?, branches?: [{condition, steps}]}]}], sourceMap: {nodeId: [{pattern, line?, endLine?}]}, contract?, startingLevel?, startingNode?}. Positions and node type are omitted (UI-only). Pass format: \"compact\" for a much smaller text outline — `[S] node-2 \"My System\" (system) ...` per node, `source --[label]--> target` per edge, plus flows — when you only need structure. Step descriptions can use @[Name] mentions to reference architecture nodes. For scoped reads, prefer get_node. For implementation, use get_task instead — it handles dependency ordering and returns one work unit at a time."
     )]
     fn get_model(
         &self,
@@ -93,17 +124,35 @@ impl ScryerServer {
             Ok(r) => r,
             Err(e) => return Ok(e),
         };
+        let compact = match req.format.as_deref() {
+            None | Some("json") => false,
+            Some("compact") => true,
+            Some(other) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Invalid format '{}'. Must be: json, compact",
+                    other
+                ))]));
+            }
+        };
         match scryer_core::read_model_at(&model_ref) {
             Ok(model) => {
                 let _ = scryer_core::save_baseline_at(&model_ref, &model);
-                let mut val = serde_json::to_value(&model).unwrap();
-                strip_fields_compact(&mut val);
+                let reminder = self.note_full_read(&model_ref, &model);
+                let body = if compact {
+                    scryer_core::compact::serialize_diagram(&model)
+                } else {
+                    let mut val = serde_json::to_value(&model).unwrap();
+                    strip_fields_compact(&mut val);
 
-                let ref_str = model_ref.to_ref_string();
-                externalize_attachments(&mut val, &ref_str);
-                let json = serde_json::to_string(&val)
-                    .unwrap_or_else(|e| format!("Serialization error: {}", e));
-                Ok(CallToolResult::success(vec![Content::text(json)]))
+                    let ref_str = model_ref.to_ref_string();
+                    externalize_attachments(&mut val, &ref_str);
+                    serde_json::to_string(&val)
+                        .unwrap_or_else(|e| format!("Serialization error: {}", e))
+                };
+                // Kept as a separate item so the JSON body still parses
+                let mut content = vec![Content::text(body)];
+                content.extend(reminder.map(Content::text));
+                Ok(CallToolResult::success(content))
             }
             Err(e) => Ok(CallToolResult::error(vec![Content::text(format!(
                 "Failed to read model '{}': {}",
@@ -113,16 +162,35 @@ impl ScryerServer {
     }
 
     #[tool(
-        description = "Get a scoped subtree of a model. Returns the target node, all its descendants, edges between them, edges connecting the subtree to external nodes (with external node names/kinds for context), and the group membership chain. Use this instead of get_model when you only need to inspect or work on a specific system, container, or component. Response is a JSON object with: `node` (the target), `descendants` (array), `internal_edges` (edges within subtree), `external_edges` (edges connecting subtree to outside, with `external_node_name` and `external_node_kind` fields added), `source_map`, and `groups` (the chain of groups this node belongs to, immediate first then ancestors via parentGroupId — each entry carries name, description, and contract so the AI sees inherited group rules)."
+        description = "Page through a very large model instead of reading it in one get_model call. section is \"nodes\" (default), \"edges\", or \"flows\"; each page returns {section, offset, total, items, next_cursor}, where items have the same shape as in get_model. Pass next_cursor back as cursor to get the next page; it is null after the last one. The first page of nodes also includes counts of every section. Use get_model for anything but giant models."
     )]
-    fn get_node(
+    fn stream_model(
         &self,
-        Parameters(req): Parameters<GetNodeRequest>,
+        Parameters(req): Parameters<StreamModelRequest>,
     ) -> Result<CallToolResult, McpError> {
         let model_ref = match self.resolve_model(req.name) {
             Ok(r) => r,
             Err(e) => return Ok(e),
         };
+        // Cursors look like "edges:400" so a page can be resumed from the token alone
+        let (section, offset) = match req.cursor.as_deref() {
+            None => (req.section.as_deref().unwrap_or("nodes").to_string(), 0),
+            Some(cursor) => {
+                let parsed = cursor
+                    .split_once(':')
+                    .and_then(|(s, o)| Some((s.to_string(), o.parse::<usize>().ok()?)));
+                match parsed {
+                    Some((s, o)) if req.section.as_deref().is_none_or(|r| r == s) => (s, o),
+                    _ => {
+                        return Ok(CallToolResult::error(vec![Content::text(format!(
+                            "Invalid cursor '{}' for this section. Pass next_cursor from the previous page unchanged.",
+                            cursor
+                        ))]));
+                    }
+                }
+            }
+        };
+        let limit = req.limit.unwrap_or(200).clamp(1, 1000);
         let model = match scryer_core::read_model_at(&model_ref) {
             Ok(m) => m,
             Err(e) => {
@@ -133,36 +201,132 @@ impl ScryerServer {
             }
         };
 
-        let target = match model.nodes.iter().find(|n| n.id == req.node_id) {
-            Some(n) => n,
-            None => {
+        let page = |len: usize| offset.min(len)..offset.saturating_add(limit).min(len);
+        let (total, items) = match section.as_str() {
+            "nodes" => (
+                model.nodes.len(),
+                serde_json::to_value(&model.nodes[page(model.nodes.len())]),
+            ),
+            "edges" => (
+                model.edges.len(),
+                serde_json::to_value(&model.edges[page(model.edges.len())]),
+            ),
+            "flows" => (
+                model.flows.len(),
+                serde_json::to_value(&model.flows[page(model.flows.len())]),
+            ),
+            other => {
                 return Ok(CallToolResult::error(vec![Content::text(format!(
-                    "Node '{}' not found",
-                    req.node_id
+                    "Invalid section '{}'. Must be: nodes, edges, flows",
+                    other
                 ))]));
             }
         };
+        let mut items = items.unwrap_or_default();
+        strip_fields_compact(&mut items);
+        externalize_attachments(&mut items, &model_ref.to_ref_string());
 
-        // Collect all descendant IDs
-        let mut subtree_ids: HashSet<String> = HashSet::new();
-        subtree_ids.insert(req.node_id.clone());
-        let mut changed = true;
-        while changed {
-            changed = false;
-            for n in &model.nodes {
-                if let Some(pid) = &n.parent_id {
-                    if subtree_ids.contains(pid) && !subtree_ids.contains(&n.id) {
-                        subtree_ids.insert(n.id.clone());
-                        changed = true;
+        let end = offset.saturating_add(limit).min(total);
+        let next_cursor = (end < total).then(|| format!("{}:{}", section, end));
+        let mut result = serde_json::json!({
+            "section": section,
+            "offset": offset,
+            "total": total,
+            "items": items,
+            "next_cursor": next_cursor,
+        });
+        if section == "nodes" && offset == 0 {
+            // Mark the model as read, as get_model does, when a walk starts
+            let _ = scryer_core::save_baseline_at(&model_ref, &model);
+            result["counts"] = serde_json::json!({
+                "nodes": model.nodes.len(),
+                "edges": model.edges.len(),
+                "flows": model.flows.len(),
+            });
+        }
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string(&result).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        description = "Get several models in one call, e.g. to compare two systems. Returns a JSON object mapping each name to its model (same shape as get_model, or a compact text string when format is \"compact\"). Names that can't be read map to {\"error\": \"...\"} instead of failing the whole call."
+    )]
+    fn get_models(
+        &self,
+        Parameters(req): Parameters<GetModelsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let compact = match req.format.as_deref() {
+            None | Some("json") => false,
+            Some("compact") => true,
+            Some(other) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Invalid format '{}'. Must be: json, compact",
+                    other
+                ))]));
+            }
+        };
+
+        let mut result = serde_json::Map::new();
+        for name in req.names {
+            let model_ref = ModelRef::parse(&name);
+            let entry = match scryer_core::read_model_at(&model_ref) {
+                Ok(model) => {
+                    let _ = scryer_core::save_baseline_at(&model_ref, &model);
+                    if compact {
+                        serde_json::Value::String(scryer_core::compact::serialize_diagram(&model))
+                    } else {
+                        let mut val = serde_json::to_value(&model).unwrap();
+                        strip_fields_compact(&mut val);
+                        externalize_attachments(&mut val, &model_ref.to_ref_string());
+                        val
                     }
                 }
-            }
+                Err(e) => serde_json::json!({ "error": e }),
+            };
+            result.insert(name, entry);
         }
 
+        let json = serde_json::to_string(&result)
+            .unwrap_or_else(|e| format!("Serialization error: {}", e));
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    #[tool(
+        description = "Get a scoped subtree of a model. Identify the node by node_id, or by node_name (case-insensitive) if you don't know the ID. Returns the target node, all its descendants, edges between them, edges connecting the subtree to external nodes (with external node names/kinds for context), and the group membership chain. Use this instead of get_model when you only need to inspect or work on a specific system, container, or component. Response is a JSON object with: `node` (the target), `descendants` (array), `internal_edges` (edges within subtree), `external_edges` (edges connecting subtree to outside, with `external_node_name` and `external_node_kind` fields added), `source_map`, and `groups` (the chain of groups this node belongs to, immediate first then ancestors via parentGroupId — each entry carries name, description, and contract so the AI sees inherited group rules). Pass include_ancestors: true to add `ancestors` — the parent chain, root first, with each ancestor's id, name, kind, description, and contract."
+    )]
+    fn get_node(
+        &self,
+        Parameters(req): Parameters<GetNodeRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let node_id =
+            match resolve_node_id(&model, req.node_id.as_deref(), req.node_name.as_deref()) {
+                Ok(id) => id,
+                Err(e) => return Ok(CallToolResult::error(vec![Content::text(e)])),
+            };
+        let target = model.nodes.iter().find(|n| n.id == node_id).unwrap();
+
+        // Collect all descendant IDs
+        let subtree_ids = subtree_ids(&model, &node_id);
+
         let descendants: Vec<&C4Node> = model
             .nodes
             .iter()
-            .filter(|n| subtree_ids.contains(&n.id) && n.id != req.node_id)
+            .filter(|n| subtree_ids.contains(&n.id) && n.id != node_id)
             .collect();
 
         // Partition edges
@@ -206,7 +370,7 @@ impl ScryerServer {
         if let Some(immediate) = model
             .groups
             .iter()
-            .find(|g| g.member_ids.iter().any(|id| id == &req.node_id))
+            .find(|g| g.member_ids.iter().any(|id| id == &node_id))
         {
             let mut cursor: Option<&scryer_core::Group> = Some(immediate);
             let mut seen: HashSet<String> = HashSet::new();
@@ -231,6 +395,23 @@ impl ScryerServer {
             "source_map": source_map,
             "groups": group_chain,
         });
+        if req.include_ancestors {
+            // Same chain get_task inherits contracts and notes from
+            let ancestors: Vec<serde_json::Value> = NodeIndex::new(&model)
+                .ancestors(&node_id)
+                .iter()
+                .map(|a| {
+                    serde_json::json!({
+                        "id": a.id,
+                        "name": a.data.name,
+                        "kind": kind_str(&a.data.kind),
+                        "description": a.data.description,
+                        "contract": a.data.contract,
+                    })
+                })
+                .collect();
+            result["ancestors"] = serde_json::Value::Array(ancestors);
+        }
         strip_ui_fields(&mut result);
         externalize_attachments(&mut result, &ref_str);
 
@@ -239,19 +420,216 @@ impl ScryerServer {
         )]))
     }
 
-    #[tool(description = "Get the C4 modeling rules that govern how diagrams should be structured")]
-    fn get_rules(&self) -> Result<CallToolResult, McpError> {
+    #[tool(
+        description = "List the nodes directly connected to one node by an edge, in either direction. Narrower than get_node: only the node's own edges, not its subtree's. Response is a JSON object with `node` (id, name, kind), `outgoing` (edges where the node is the source), and `incoming` (edges where it is the target). Each edge carries `other_node_name` and `other_node_kind` for the node at the far end."
+    )]
+    fn get_neighbors(
+        &self,
+        Parameters(req): Parameters<GetNeighborsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let node_id =
+            match resolve_node_id(&model, req.node_id.as_deref(), req.node_name.as_deref()) {
+                Ok(id) => id,
+                Err(e) => return Ok(CallToolResult::error(vec![Content::text(e)])),
+            };
+        let target = model.nodes.iter().find(|n| n.id == node_id).unwrap();
+
+        // Same partitioning as get_node, with the node itself as the subtree
+        let mut outgoing: Vec<serde_json::Value> = Vec::new();
+        let mut incoming: Vec<serde_json::Value> = Vec::new();
+        for edge in &model.edges {
+            let is_source = edge.source == node_id;
+            if !is_source && edge.target != node_id {
+                continue;
+            }
+            let mut val = serde_json::to_value(edge).unwrap();
+            let other_id = if is_source {
+                &edge.target
+            } else {
+                &edge.source
+            };
+            if let Some(other) = model.nodes.iter().find(|n| n.id == *other_id) {
+                val.as_object_mut().unwrap().insert(
+                    "other_node_name".to_string(),
+                    serde_json::Value::String(other.data.name.clone()),
+                );
+                val.as_object_mut().unwrap().insert(
+                    "other_node_kind".to_string(),
+                    serde_json::Value::String(kind_str(&other.data.kind).to_string()),
+                );
+            }
+            if is_source {
+                outgoing.push(val);
+            } else {
+                incoming.push(val);
+            }
+        }
+
+        let mut result = serde_json::json!({
+            "node": {
+                "id": target.id,
+                "name": target.data.name,
+                "kind": kind_str(&target.data.kind),
+            },
+            "outgoing": outgoing,
+            "incoming": incoming,
+        });
+        strip_ui_fields(&mut result);
+
         Ok(CallToolResult::success(vec![Content::text(
-            scryer_core::rules::RULES,
+            serde_json::to_string_pretty(&result).unwrap(),
         )]))
     }
 
+    #[tool(
+        description = "Run the AI architecture review on one node's subtree instead of the whole model. Takes the same scope as get_node — the node, its descendants, and the nodes they connect to for context — and returns hints only for nodes inside the subtree. Hints with an action can be applied with apply_hint. Use it to review a large model container by container. Requires AI to be configured in Scryer's settings."
+    )]
+    async fn review_node(
+        &self,
+        Parameters(req): Parameters<ReviewNodeRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let settings = scryer_core::read_settings();
+        if !scryer_core::ai_configured(&settings) {
+            return Ok(CallToolResult::error(vec![Content::text(
+                "AI review is not configured. Set a provider and model in Scryer's settings first.",
+            )]));
+        }
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        let node_id =
+            match resolve_node_id(&model, req.node_id.as_deref(), req.node_name.as_deref()) {
+                Ok(id) => id,
+                Err(e) => return Ok(CallToolResult::error(vec![Content::text(e)])),
+            };
+
+        // The subtree plus just enough around it to read the diagram: the
+        // node's ancestors and the far end of every edge leaving the subtree.
+        let subtree_ids = subtree_ids(&model, &node_id);
+        let mut context_ids: HashSet<&str> = subtree_ids.iter().map(|s| s.as_str()).collect();
+        let mut cursor = model
+            .nodes
+            .iter()
+            .find(|n| n.id == node_id)
+            .and_then(|n| n.parent_id.as_deref());
+        while let Some(pid) = cursor {
+            if !context_ids.insert(pid) {
+                break;
+            }
+            cursor = model
+                .nodes
+                .iter()
+                .find(|n| n.id == pid)
+                .and_then(|n| n.parent_id.as_deref());
+        }
+        let edges: Vec<scryer_core::C4Edge> = model
+            .edges
+            .iter()
+            .filter(|e| subtree_ids.contains(&e.source) || subtree_ids.contains(&e.target))
+            .cloned()
+            .collect();
+        for e in &edges {
+            context_ids.insert(e.source.as_str());
+            context_ids.insert(e.target.as_str());
+        }
+        let nodes: Vec<C4Node> = model
+            .nodes
+            .iter()
+            .filter(|n| context_ids.contains(n.id.as_str()))
+            .cloned()
+            .collect();
+        let scoped = scryer_core::C4ModelData {
+            nodes,
+            edges,
+            starting_level: None,
+            starting_node: None,
+            source_map: Default::default(),
+            project_path: None,
+            ref_positions: Default::default(),
+            groups: vec![],
+            flows: vec![],
+            limits: model.limits.clone(),
+            created_at: None,
+            updated_at: None,
+        };
+
+        let hints: Vec<scryer_suggest::Hint> =
+            match scryer_suggest::try_get_hints(&scoped, &settings).await {
+                Ok(hints) => hints
+                    .into_iter()
+                    .filter(|h| subtree_ids.contains(&h.node_id))
+                    .collect(),
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Failed to get suggestions for '{}': {}",
+                        node_id, e
+                    ))]));
+                }
+            };
+        if hints.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "No suggestions for the {} node(s) under '{}'.",
+                subtree_ids.len(),
+                node_id
+            ))]));
+        }
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&hints).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        description = "Get the C4 modeling rules that govern how diagrams should be structured. Pass section to fetch just one part: \"rules\", \"workflow\", \"authority\", or a rule number."
+    )]
+    fn get_rules(
+        &self,
+        Parameters(req): Parameters<GetRulesRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let Some(section) = req.section else {
+            return Ok(CallToolResult::success(vec![Content::text(
+                scryer_core::rules::RULES,
+            )]));
+        };
+        match scryer_core::rules::rules_section(&section) {
+            Some(text) => Ok(CallToolResult::success(vec![Content::text(text)])),
+            None => Ok(CallToolResult::error(vec![Content::text(format!(
+                "Unknown rules section '{}'. Use one of: {}, or a rule number.",
+                section,
+                scryer_core::rules::SECTIONS.join(", ")
+            ))])),
+        }
+    }
+
     #[tool(
         description = "Validate a model against C4 rules. Returns all warnings: disconnected nodes, bidirectional edges, mentions without edges, cross-container component edges. Run this after making changes to catch modeling errors."
     )]
     fn validate_model(
         &self,
-        Parameters(req): Parameters<GetModelRequest>,
+        Parameters(req): Parameters<ValidateModelRequest>,
     ) -> Result<CallToolResult, McpError> {
         let model_ref = match self.resolve_model(req.name) {
             Ok(r) => r,
@@ -305,7 +683,523 @@ impl ScryerServer {
     }
 
     #[tool(
-        description = "Show what changed in a model since the AI last read or wrote it. Returns a human-readable diff listing: nodes added/removed/modified, edges added/removed/modified, contract changes, flows added/removed/modified. Baseline is set automatically on get_model, get_node, set_model, and any write operation. Call this to see what the user changed without re-reading the full model."
+        description = "Get the source file locations mapped to nodes and flows — the read counterpart of update_source_map. Pass node_id for a single node or flow, or omit it for the whole mapping. Response is a JSON array of {id, name, kind, locations}, sorted by ID, where kind is the node kind or \"flow\"."
+    )]
+    fn get_source_map(
+        &self,
+        Parameters(req): Parameters<GetSourceMapRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let describe = |id: &str| -> Option<(String, &'static str)> {
+            if let Some(n) = model.nodes.iter().find(|n| n.id == id) {
+                return Some((n.data.name.clone(), kind_str(&n.data.kind)));
+            }
+            model
+                .flows
+                .iter()
+                .find(|f| f.id == id)
+                .map(|f| (f.name.clone(), "flow"))
+        };
+
+        let mut ids: Vec<&String> = match &req.node_id {
+            Some(id) => {
+                if describe(id).is_none() {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Node or flow '{}' not found",
+                        id
+                    ))]));
+                }
+                model.source_map.keys().filter(|k| *k == id).collect()
+            }
+            None => model.source_map.keys().collect(),
+        };
+        ids.sort();
+
+        let entries: Vec<serde_json::Value> = ids
+            .into_iter()
+            .map(|id| {
+                let (name, kind) = describe(id).unwrap_or_default();
+                serde_json::json!({
+                    "id": id,
+                    "name": name,
+                    "kind": kind,
+                    "locations": model.source_map[id],
+                })
+            })
+            .collect();
+
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&entries).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        description = "List operation nodes that aren't linked to source code yet — no source map entry and no sources — grouped by parent component. Use it as the to-do list for update_source_map. Pass node_id to scope to a subtree."
+    )]
+    fn get_unlinked_operations(
+        &self,
+        Parameters(req): Parameters<GetUnlinkedOperationsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        if let Some(scope) = &req.node_id {
+            if !model.nodes.iter().any(|n| n.id == *scope) {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Node '{}' not found",
+                    scope
+                ))]));
+            }
+        }
+
+        // Parent ID -> unlinked operations, in model order of first appearance
+        let mut by_parent: Vec<(Option<&str>, Vec<&C4Node>)> = Vec::new();
+        let mut count = 0;
+        for node in &model.nodes {
+            if node.data.kind != C4Kind::Operation {
+                continue;
+            }
+            if let Some(scope) = &req.node_id {
+                if node.id != *scope && !is_descendant_of(&model, &node.id, scope) {
+                    continue;
+                }
+            }
+            let linked = !node.data.sources.is_empty()
+                || model
+                    .source_map
+                    .get(&node.id)
+                    .is_some_and(|l| !l.is_empty());
+            if linked {
+                continue;
+            }
+            let parent = node.parent_id.as_deref();
+            match by_parent.iter_mut().find(|(p, _)| *p == parent) {
+                Some((_, ops)) => ops.push(node),
+                None => by_parent.push((parent, vec![node])),
+            }
+            count += 1;
+        }
+
+        if count == 0 {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "Every operation is linked to source code.",
+            )]));
+        }
+
+        let mut output = format!(
+            "{} unlinked operation(s) in {} component(s):\n",
+            count,
+            by_parent.len()
+        );
+        for (parent, ops) in &by_parent {
+            let heading = match parent.and_then(|pid| model.nodes.iter().find(|n| n.id == pid)) {
+                Some(p) => format!("{} [{}]", p.data.name, p.id),
+                None => "(no parent)".to_string(),
+            };
+            output.push_str(&format!("\n{}\n", heading));
+            for op in ops {
+                output.push_str(&format!("  - {} [{}]\n", op.data.name, op.id));
+            }
+        }
+        output.push_str("\nLink each with `update_source_map` once you know where it lives.");
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+
+    #[tool(
+        description = "Find the shortest chain of relationships connecting two nodes, e.g. how the frontend reaches the database. Edges are followed in their direction unless undirected is true. Returns one line per hop with node names and edge labels, or reports that no path exists."
+    )]
+    fn path_between(
+        &self,
+        Parameters(req): Parameters<PathBetweenRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        for id in [&req.source_id, &req.target_id] {
+            if !model.nodes.iter().any(|n| n.id == *id) {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Node '{}' not found",
+                    id
+                ))]));
+            }
+        }
+        let undirected = req.undirected.unwrap_or(false);
+        let name_of = |id: &str| {
+            model
+                .nodes
+                .iter()
+                .find(|n| n.id == id)
+                .map(|n| n.data.name.as_str())
+                .unwrap_or("?")
+        };
+
+        // BFS, remembering for each reached node the edge (and direction) used to reach it
+        let mut came_from: HashMap<&str, (&str, &scryer_core::C4Edge, bool)> = HashMap::new();
+        let mut visited: HashSet<&str> = HashSet::from([req.source_id.as_str()]);
+        let mut queue = std::collections::VecDeque::from([req.source_id.as_str()]);
+        while let Some(current) = queue.pop_front() {
+            if current == req.target_id {
+                break;
+            }
+            for edge in &model.edges {
+                let (next, forward) = if edge.source == current {
+                    (edge.target.as_str(), true)
+                } else if undirected && edge.target == current {
+                    (edge.source.as_str(), false)
+                } else {
+                    continue;
+                };
+                if visited.insert(next) {
+                    came_from.insert(next, (current, edge, forward));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if req.source_id != req.target_id && !came_from.contains_key(req.target_id.as_str()) {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "No {}path from '{}' [{}] to '{}' [{}]",
+                if undirected { "" } else { "directed " },
+                name_of(&req.source_id),
+                req.source_id,
+                name_of(&req.target_id),
+                req.target_id
+            ))]));
+        }
+
+        let mut hops = Vec::new();
+        let mut cursor = req.target_id.as_str();
+        while let Some(&(prev, edge, forward)) = came_from.get(cursor) {
+            hops.push((prev, cursor, edge, forward));
+            cursor = prev;
+        }
+        hops.reverse();
+
+        let mut out = format!(
+            "Path from '{}' to '{}' ({} hop(s)):",
+            name_of(&req.source_id),
+            name_of(&req.target_id),
+            hops.len()
+        );
+        for (from, to, edge, forward) in hops {
+            let label = edge
+                .data
+                .as_ref()
+                .map(|d| d.label.as_str())
+                .filter(|l| !l.is_empty())
+                .unwrap_or("uses");
+            let arrow = if forward {
+                format!("--[{}]-->", label)
+            } else {
+                format!("<--[{}]--", label)
+            };
+            out.push_str(&format!(
+                "\n- {} [{}] {} {} [{}]",
+                name_of(from),
+                from,
+                arrow,
+                name_of(to),
+                to
+            ));
+        }
+        Ok(CallToolResult::success(vec![Content::text(out)]))
+    }
+
+    #[tool(
+        description = "Find nodes matching all of the given filters: kind, status, and tag (case-insensitive). Returns one line per match with ID, name, kind, status, and tags. Use this to slice a large model by concern, e.g. every node tagged \"pii\"."
+    )]
+    fn find_nodes(
+        &self,
+        Parameters(req): Parameters<FindNodesRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        let kind = req.kind.as_deref().map(parse_kind).transpose()?;
+        let status = match req.status.as_deref() {
+            Some(s) => match parse_status(s) {
+                Some(st) => Some(st),
+                None => {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Invalid status '{}'",
+                        s
+                    ))]));
+                }
+            },
+            None => None,
+        };
+
+        let matches: Vec<String> = model
+            .nodes
+            .iter()
+            .filter(|n| kind.as_ref().is_none_or(|k| n.data.kind == *k))
+            .filter(|n| status.is_none() || n.data.status == status)
+            .filter(|n| {
+                req.tag
+                    .as_deref()
+                    .is_none_or(|tag| n.data.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            })
+            .map(|n| {
+                let mut line = format!(
+                    "- {} \"{}\" ({}, {})",
+                    n.id,
+                    n.data.name,
+                    kind_str(&n.data.kind),
+                    status_str(&n.data.status)
+                );
+                if !n.data.tags.is_empty() {
+                    line.push_str(&format!(" tags=[{}]", n.data.tags.join(", ")));
+                }
+                line
+            })
+            .collect();
+
+        if matches.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No nodes match.",
+            )]));
+        }
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "{} node(s):\n{}",
+            matches.len(),
+            matches.join("\n")
+        ))]))
+    }
+
+    #[tool(
+        description = "List edges matching all of the given filters: label_contains (case-insensitive substring), method (case-insensitive), and involving_node (a node ID at either end). With no filters, lists every edge. Returns a JSON array of {id, source, source_name, target, target_name, label, method} rows. The edge counterpart to find_nodes — e.g. every gRPC edge, or everything touching the database."
+    )]
+    fn list_edges(
+        &self,
+        Parameters(req): Parameters<ListEdgesRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        if let Some(id) = &req.involving_node {
+            if !model.nodes.iter().any(|n| n.id == *id) {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Node '{}' not found",
+                    id
+                ))]));
+            }
+        }
+
+        let label_filter = req.label_contains.as_deref().map(str::to_lowercase);
+        let name_of = |id: &str| {
+            model
+                .nodes
+                .iter()
+                .find(|n| n.id == id)
+                .map(|n| n.data.name.as_str())
+        };
+        let rows: Vec<serde_json::Value> = model
+            .edges
+            .iter()
+            .filter(|e| {
+                req.involving_node
+                    .as_deref()
+                    .is_none_or(|id| e.source == id || e.target == id)
+            })
+            .filter(|e| {
+                let label = e.data.as_ref().map(|d| d.label.as_str()).unwrap_or("");
+                label_filter
+                    .as_deref()
+                    .is_none_or(|f| label.to_lowercase().contains(f))
+            })
+            .filter(|e| {
+                req.method.as_deref().is_none_or(|m| {
+                    e.data
+                        .as_ref()
+                        .and_then(|d| d.method.as_deref())
+                        .is_some_and(|em| em.eq_ignore_ascii_case(m))
+                })
+            })
+            .map(|e| {
+                serde_json::json!({
+                    "id": e.id,
+                    "source": e.source,
+                    "source_name": name_of(&e.source),
+                    "target": e.target,
+                    "target_name": name_of(&e.target),
+                    "label": e.data.as_ref().map(|d| d.label.as_str()).unwrap_or(""),
+                    "method": e.data.as_ref().and_then(|d| d.method.as_deref()),
+                })
+            })
+            .collect();
+
+        if rows.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No edges match.",
+            )]));
+        }
+        Ok(CallToolResult::success(vec![Content::text(
+            serde_json::to_string_pretty(&rows).unwrap(),
+        )]))
+    }
+
+    #[tool(
+        description = "Export the model at one abstraction level as text for other tools. format \"dot\" returns a GraphViz digraph (nodes colored by status and shaped by kind, clusters for expanded parents and groups, labeled edges); \"svg\" returns a standalone SVG document. level is system (default), container, or component. \"nodes_csv\" and \"edges_csv\" return a spreadsheet inventory of every node (id, name, kind, parent, technology, status, owner, tags, description) or edge (id, source, target, label, method), regardless of level."
+    )]
+    fn export(
+        &self,
+        Parameters(req): Parameters<ExportRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        let level = match req.level.as_deref().unwrap_or("system") {
+            "system" => StartingLevel::System,
+            "container" => StartingLevel::Container,
+            "component" => StartingLevel::Component,
+            other => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Invalid level '{}'. Must be: system, container, component",
+                    other
+                ))]));
+            }
+        };
+        let out = match req.format.to_ascii_lowercase().as_str() {
+            "dot" => scryer_core::export::export_dot(&model, &level),
+            "svg" => scryer_core::render::render_svg(&model, &level),
+            "nodes_csv" => scryer_core::export::export_nodes_csv(&model),
+            "edges_csv" => scryer_core::export::export_edges_csv(&model),
+            other => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Invalid format '{}'. Must be: dot, svg, nodes_csv, edges_csv",
+                    other
+                ))]));
+            }
+        };
+        Ok(CallToolResult::success(vec![Content::text(out)]))
+    }
+
+    #[tool(
+        description = "List nodes grouped by owner — a responsibility map of the architecture. Owners are sorted by name; systems and containers without an owner are listed last so gaps in ownership are visible."
+    )]
+    fn get_ownership(
+        &self,
+        Parameters(req): Parameters<GetOwnershipRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+
+        let mut by_owner: BTreeMap<&str, Vec<&C4Node>> = BTreeMap::new();
+        let mut unowned: Vec<&C4Node> = Vec::new();
+        for node in &model.nodes {
+            match node.data.owner.as_deref() {
+                Some(owner) => by_owner.entry(owner).or_default().push(node),
+                None if matches!(node.data.kind, C4Kind::System | C4Kind::Container)
+                    && node.data.external != Some(true) =>
+                {
+                    unowned.push(node)
+                }
+                None => {}
+            }
+        }
+
+        let line = |n: &C4Node| {
+            format!(
+                "  - {} \"{}\" ({})",
+                n.id,
+                n.data.name,
+                kind_str(&n.data.kind)
+            )
+        };
+        let mut out = Vec::new();
+        for (owner, nodes) in &by_owner {
+            out.push(format!("{} ({}):", owner, nodes.len()));
+            out.extend(nodes.iter().map(|n| line(n)));
+        }
+        if !unowned.is_empty() {
+            out.push(format!("Unowned systems/containers ({}):", unowned.len()));
+            out.extend(unowned.iter().map(|n| line(n)));
+        }
+        if out.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No owners set and no systems or containers to assign.",
+            )]));
+        }
+        Ok(CallToolResult::success(vec![Content::text(out.join("\n"))]))
+    }
+
+    #[tool(
+        description = "Show what changed in a model since the AI last read or wrote it. Returns a human-readable diff listing: nodes added/removed/modified, edges added/removed/modified, contract changes, flows added/removed/modified. Baseline is set automatically on get_model, get_node, set_model, and any write operation. Call this to see what the user changed without re-reading the full model. Pass node_id to limit the report to that node's subtree and the edges inside it (flow changes are then omitted)."
     )]
     fn get_changes(
         &self,
@@ -334,7 +1228,91 @@ impl ScryerServer {
             }
         };
 
-        let diff = compute_diff(&baseline, &current);
+        let Some(node_id) = req.node_id else {
+            let diff = compute_diff(&baseline, &current)
+                .unwrap_or_else(|| "No changes since last seen.".to_string());
+            return Ok(CallToolResult::success(vec![Content::text(diff)]));
+        };
+        if !current.nodes.iter().any(|n| n.id == node_id)
+            && !baseline.nodes.iter().any(|n| n.id == node_id)
+        {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Node '{}' not found",
+                node_id
+            ))]));
+        }
+        // Union of both subtrees, so descendants removed or moved out since the
+        // baseline still show up
+        let mut ids = subtree_ids(&current, &node_id);
+        ids.extend(subtree_ids(&baseline, &node_id));
+        let diff = compute_diff(
+            &scope_to_nodes(&baseline, &ids),
+            &scope_to_nodes(&current, &ids),
+        )
+        .unwrap_or_else(|| format!("No changes under '{}' since last seen.", node_id));
         Ok(CallToolResult::success(vec![Content::text(diff)]))
     }
+
+    #[tool(
+        description = "Diff two models against each other, e.g. a fork against its origin. Reports what changes going from name_a to name_b, in the same format as get_changes. Neither model's baseline or the active model is touched."
+    )]
+    fn diff_models(
+        &self,
+        Parameters(req): Parameters<DiffModelsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut models = Vec::with_capacity(2);
+        for name in [&req.name_a, &req.name_b] {
+            let model_ref = ModelRef::parse(name);
+            match scryer_core::read_model_at(&model_ref) {
+                Ok(m) => models.push(m),
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Failed to read model '{}': {}",
+                        model_ref, e
+                    ))]));
+                }
+            }
+        }
+
+        let output = match compute_diff(&models[0], &models[1]) {
+            Some(diff) => format!("{} -> {}\n\n{}", req.name_a, req.name_b, diff),
+            None => format!("'{}' and '{}' are identical.", req.name_a, req.name_b),
+        };
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::testing::{stored, text};
+
+    #[test]
+    fn get_node_keeps_property_types() {
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "comp", "type": "c4", "position": { "x": 0, "y": 0 },
+                  "data": { "name": "Orders", "kind": "component" } },
+                { "id": "order", "parentId": "comp", "type": "c4", "position": { "x": 0, "y": 0 },
+                  "data": { "name": "Order", "kind": "model", "properties": [
+                      { "label": "total", "type": "Decimal" }
+                  ] } }
+            ],
+            "edges": []
+        }))
+        .unwrap();
+        let (root, name) = stored("get-node", &model);
+
+        let req: GetNodeRequest =
+            serde_json::from_value(serde_json::json!({ "name": name, "node_id": "comp" })).unwrap();
+        let (failed, text) = text(ScryerServer::new().get_node(Parameters(req)).unwrap());
+        assert!(!failed, "{text}");
+        let result: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            result["descendants"][0]["data"]["properties"][0]["type"],
+            "Decimal"
+        );
+        assert!(result["node"].get("type").is_none(), "{text}");
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
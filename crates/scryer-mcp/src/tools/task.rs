@@ -1,5 +1,5 @@
 use crate::helpers::*;
-use crate::instructions::TASK_INSTRUCTIONS;
+use crate::messages::{task_messages, LANGUAGES};
 use crate::server::ScryerServer;
 use crate::types::*;
 use rmcp::{
@@ -7,17 +7,37 @@ use rmcp::{
     model::{CallToolResult, Content},
     tool, tool_router, ErrorData as McpError,
 };
-use scryer_core::{C4Kind, C4Node, Contract, Status};
+use scryer_core::{C4Kind, C4Node, Status};
 
 #[tool_router(router = tool_router_task, vis = "pub(crate)")]
 impl ScryerServer {
     #[tool(
-        description = "Get the next implementation task. Returns one logical work unit at a time, ordered by dependencies. Workflow: call get_task → build the returned task → mark nodes as implemented via update_nodes (with a reason) → call get_task again for the next task. Pass node_id to scope to a subtree."
+        description = "Get the next implementation task. Returns one logical work unit at a time, ordered by dependencies. Workflow: call get_task → build the returned task → mark nodes as implemented via update_nodes (with a reason) → call get_task again for the next task. Pass node_id to scope to a subtree. Pass skip_ids to pass over nodes you can't make progress on right now. Pass status to run a focused pass on only proposed or only in_progress nodes. Pass language (\"en\", \"es\") to render the task's headings and instructions in that language. The progress footer counts remaining ready and blocked nodes with a rough points estimate (one per node plus one per unbuilt operation/process/model)."
     )]
     fn get_task(
         &self,
         Parameters(req): Parameters<GetTaskRequest>,
     ) -> Result<CallToolResult, McpError> {
+        let status_filter = match req.status.as_deref() {
+            None => None,
+            Some(s) => match parse_status(s) {
+                Some(st @ (Status::Proposed | Status::InProgress)) => Some(st),
+                _ => {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "Invalid status filter '{}'. Must be: proposed, in_progress",
+                        s
+                    ))]));
+                }
+            },
+        };
+        let language = req.language.as_deref().unwrap_or("en");
+        let Some(msg) = task_messages(language) else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Unsupported language '{}'. Must be one of: {}",
+                language,
+                LANGUAGES.join(", ")
+            ))]));
+        };
         let model_ref = match self.resolve_model(req.name) {
             Ok(r) => r,
             Err(e) => return Ok(e),
@@ -33,63 +53,16 @@ impl ScryerServer {
         };
 
         let scope_filter: Option<&str> = req.node_id.as_deref();
+        let is_skipped = |id: &str| req.skip_ids.iter().any(|s| s == id);
+        let status_matches =
+            |node: &C4Node| status_filter.is_none_or(|st| node.data.status == Some(st));
 
-        // Helper: check if node_id is a descendant of ancestor_id
-        let is_descendant_of = |node_id: &str, ancestor_id: &str| -> bool {
-            let mut cur = node_id.to_string();
-            loop {
-                let parent = model
-                    .nodes
-                    .iter()
-                    .find(|n| n.id == cur)
-                    .and_then(|n| n.parent_id.clone());
-                match parent {
-                    Some(pid) if pid == ancestor_id => return true,
-                    Some(pid) => cur = pid,
-                    None => return false,
-                }
-            }
-        };
+        // Index once up front — the helpers below run per node, and linear
+        // lookups inside them made this quadratic on large models.
+        let index = NodeIndex::new(&model);
 
         // Helper: get ancestor chain from node up to root (excluding the node itself)
-        let get_ancestor_chain = |node_id: &str| -> Vec<&C4Node> {
-            let mut chain = Vec::new();
-            let mut cur = node_id.to_string();
-            loop {
-                let parent = model
-                    .nodes
-                    .iter()
-                    .find(|n| n.id == cur)
-                    .and_then(|n| n.parent_id.clone());
-                match parent {
-                    Some(pid) => {
-                        if let Some(pnode) = model.nodes.iter().find(|n| n.id == pid) {
-                            chain.push(pnode);
-                            cur = pid;
-                        } else {
-                            break;
-                        }
-                    }
-                    None => break,
-                }
-            }
-            chain.reverse();
-            chain
-        };
-
-        // Helper: merge contract (additive — ancestors + node)
-        let merge_contract = |chain: &[&C4Node], node: &C4Node| -> Contract {
-            let mut merged = Contract::default();
-            for ancestor in chain {
-                merged.expect.extend(ancestor.data.contract.expect.iter().cloned());
-                merged.ask.extend(ancestor.data.contract.ask.iter().cloned());
-                merged.never.extend(ancestor.data.contract.never.iter().cloned());
-            }
-            merged.expect.extend(node.data.contract.expect.iter().cloned());
-            merged.ask.extend(node.data.contract.ask.iter().cloned());
-            merged.never.extend(node.data.contract.never.iter().cloned());
-            merged
-        };
+        let get_ancestor_chain = |node_id: &str| -> Vec<&C4Node> { index.ancestors(node_id) };
 
         // Helper: collect notes from ancestors + node
         let collect_notes = |chain: &[&C4Node], node: &C4Node| -> Vec<String> {
@@ -107,9 +80,8 @@ impl ScryerServer {
 
         // Helper: check if a node has children with status (task-eligible children)
         let has_status_children = |node: &C4Node| -> bool {
-            model.nodes.iter().any(|n| {
-                n.parent_id.as_deref() == Some(&node.id)
-                    && n.data.status.is_some()
+            index.children(&node.id).iter().any(|n| {
+                n.data.status.is_some()
                     && match node.data.kind {
                         C4Kind::Container => n.data.kind == C4Kind::Component,
                         C4Kind::System => n.data.kind == C4Kind::Container,
@@ -125,12 +97,8 @@ impl ScryerServer {
                 C4Kind::System => C4Kind::Container,
                 _ => return true,
             };
-            model.nodes.iter()
-                .filter(|n| {
-                    n.parent_id.as_deref() == Some(&node.id)
-                        && n.data.kind == child_kind
-                        && n.data.status.is_some()
-                })
+            index.children(&node.id).iter()
+                .filter(|n| n.data.kind == child_kind && n.data.status.is_some())
                 .all(|n| matches!(n.data.status, Some(Status::Implemented) | Some(Status::Verified) | Some(Status::Vagrant)))
         };
 
@@ -162,19 +130,15 @@ impl ScryerServer {
                     return false;
                 }
                 // Skip external systems' children
-                if let Some(pid) = &n.parent_id {
-                    if let Some(parent) = model.nodes.iter().find(|p| p.id == *pid) {
-                        if parent.data.external == Some(true) {
-                            return false;
-                        }
-                    }
+                if index.has_external_parent(n) {
+                    return false;
                 }
                 // Skip containers whose components are the real tasks
                 if n.data.kind == C4Kind::Container && has_status_children(n) {
                     return false;
                 }
                 if let Some(scope) = scope_filter {
-                    n.id == scope || is_descendant_of(&n.id, scope)
+                    n.id == scope || index.is_descendant_of(&n.id, scope)
                 } else {
                     true
                 }
@@ -183,7 +147,7 @@ impl ScryerServer {
 
         if task_nodes.is_empty() {
             return Ok(CallToolResult::success(vec![Content::text(
-                format_done_message(&model),
+                format_done_message(&model, &model_ref.to_string(), msg),
             )]));
         }
 
@@ -216,13 +180,14 @@ impl ScryerServer {
 
             if propagate_nodes.is_empty() {
                 return Ok(CallToolResult::success(vec![Content::text(
-                    format_done_message(&model),
+                    format_done_message(&model, &model_ref.to_string(), msg),
                 )]));
             }
 
             let mut output = format!(
-                "All {} tasks complete.\n\nMark these parent nodes as implemented:\n```\nupdate_nodes(model: \"{}\", nodes: [{}])\n```",
-                completed,
+                "{}\n\n{}\n```\nupdate_nodes(model: \"{}\", nodes: [{}])\n```",
+                msg.all_complete_count(completed),
+                msg.mark_parents,
                 model_ref,
                 propagate_nodes.iter()
                     .map(|(id, _)| format!("{{node_id: \"{}\", status: \"implemented\", reason: \"Needs review\", source: [{{pattern: \"src/module/**/*.ts\"}}]}}", id))
@@ -242,32 +207,32 @@ impl ScryerServer {
                 if !matches!(node.data.status, Some(Status::Implemented) | Some(Status::Verified)) {
                     continue;
                 }
-                for member in model.nodes.iter().filter(|n| {
-                    n.parent_id.as_deref() == Some(&node.id)
-                        && matches!(n.data.kind, C4Kind::Operation | C4Kind::Process | C4Kind::Model)
+                for member in index.children(&node.id).iter().copied().filter(|n| {
+                    matches!(n.data.kind, C4Kind::Operation | C4Kind::Process | C4Kind::Model)
                         && n.data.status.is_some()
-                        && matches!(n.data.status, Some(Status::Proposed))
+                        && matches!(n.data.status, Some(Status::Proposed) | Some(Status::InProgress))
 
                 }) {
                     pending_members.push((member, &node.data.name));
                 }
             }
             if !pending_members.is_empty() {
-                output.push_str("\n\nThese member nodes are still proposed — mark as `implemented` with a reason explaining what was built:\n");
+                output.push_str(&format!("\n\n{}\n", msg.members_pending));
                 for (member, parent_name) in &pending_members {
                     output.push_str(&format!(
-                        "  - {} [{}] ({}, {}) in {}\n",
+                        "  - {} [{}] ({}, {}) {} {}\n",
                         member.data.name,
                         member.id,
                         kind_str(&member.data.kind),
                         status_str(&member.data.status),
+                        msg.in_parent,
                         parent_name
                     ));
                 }
             }
 
             if !model.flows.is_empty() {
-                output.push_str("\n\nThen call `get_task` again to validate flows.");
+                output.push_str(&format!("\n\n{}", msg.validate_flows_next));
             }
 
             return Ok(CallToolResult::success(vec![Content::text(output)]));
@@ -280,13 +245,15 @@ impl ScryerServer {
         // Only enforce dependencies between sibling components (same parent container).
         // Containers are always choosable — edge direction between containers represents
         // architectural relationships, not build-order constraints.
+        // Async edges (publish to a queue someone else consumes) don't block either —
+        // the producer can be built before its consumer exists.
         let deps_satisfied = |node: &C4Node| -> bool {
             if node.data.kind != C4Kind::Component {
                 return true;
             }
-            for edge in &model.edges {
-                if edge.source == node.id {
-                    if let Some(target) = model.nodes.iter().find(|n| n.id == edge.target) {
+            for edge in index.outgoing(&node.id) {
+                if !is_async_edge(edge) {
+                    if let Some(target) = index.get(&edge.target) {
                         // Only block on sibling components (same parent)
                         if target.data.kind == C4Kind::Component
                             && target.parent_id == node.parent_id
@@ -300,18 +267,76 @@ impl ScryerServer {
             true
         };
 
-        // Classify work nodes into ready vs blocked
+        // Classify work nodes into ready vs blocked. Skipped nodes are in neither —
+        // they stay unsatisfied (so dependents still wait on them) but aren't handed out.
         let mut ready_nodes: Vec<&C4Node> = Vec::new();
         let mut blocked_nodes: Vec<&C4Node> = Vec::new();
+        let mut skipped_nodes: Vec<&C4Node> = Vec::new();
+        let mut other_status_nodes: Vec<&C4Node> = Vec::new();
 
         for node in &work_nodes {
-            if deps_satisfied(node) {
+            if is_skipped(&node.id) {
+                skipped_nodes.push(node);
+            } else if !status_matches(node) {
+                other_status_nodes.push(node);
+            } else if deps_satisfied(node) {
                 ready_nodes.push(node);
             } else {
                 blocked_nodes.push(node);
             }
         }
 
+        // Resume started work before picking up fresh proposals
+        ready_nodes.sort_by_key(|n| !matches!(n.data.status, Some(Status::InProgress)));
+
+        // Crude burndown: a point per remaining node plus one per member
+        // (operation/process/model) it still has to build.
+        let points = |nodes: &[&C4Node]| -> usize {
+            nodes
+                .iter()
+                .map(|n| {
+                    1 + index
+                        .children(&n.id)
+                        .iter()
+                        .filter(|c| {
+                            matches!(c.data.kind, C4Kind::Operation | C4Kind::Process | C4Kind::Model)
+                                && !is_satisfied(c)
+                        })
+                        .count()
+                })
+                .sum()
+        };
+        let remaining = msg.remaining_suffix(
+            ready_nodes.len(),
+            blocked_nodes.len(),
+            points(&ready_nodes) + points(&blocked_nodes),
+        );
+
+        // Everything left is skipped or waiting on something skipped
+        if ready_nodes.is_empty() && !skipped_nodes.is_empty() {
+            let names: Vec<String> = skipped_nodes
+                .iter()
+                .map(|n| format!("  - {} [{}]", n.data.name, n.id))
+                .collect();
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "{}\n\n{}\n\n{}\n\n---\n{}{}",
+                msg.skipped_intro,
+                names.join("\n"),
+                msg.skipped_retry,
+                msg.progress_line(completed_tasks, total_tasks, None),
+                remaining
+            ))]));
+        }
+
+        if ready_nodes.is_empty() && !other_status_nodes.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "{}\n\n---\n{}{}",
+                msg.status_filter_empty(status_str(&status_filter), other_status_nodes.len()),
+                msg.progress_line(completed_tasks, total_tasks, None),
+                remaining
+            ))]));
+        }
+
         // Cycle detection: if nothing is ready but work remains, we have a cycle
         if ready_nodes.is_empty() && !blocked_nodes.is_empty() {
             let cycle_names: Vec<String> = blocked_nodes
@@ -319,8 +344,10 @@ impl ScryerServer {
                 .map(|n| format!("  - {} [{}]", n.data.name, n.id))
                 .collect();
             return Ok(CallToolResult::success(vec![Content::text(format!(
-                "Dependency cycle detected. The following nodes all block each other:\n\n{}\n\nFix the model by removing or redirecting edges to break the cycle.",
-                cycle_names.join("\n")
+                "{}\n\n{}\n\n{}",
+                msg.cycle_intro,
+                cycle_names.join("\n"),
+                msg.cycle_fix
             ))]));
         }
 
@@ -342,6 +369,12 @@ impl ScryerServer {
             if member_containers.len() != group.member_ids.len() || member_containers.is_empty() {
                 continue;
             }
+            if member_containers
+                .iter()
+                .any(|n| is_skipped(&n.id) || !status_matches(n))
+            {
+                continue;
+            }
 
             // All group members must be proposed
             let all_members_proposed = member_containers.iter().all(|n| {
@@ -351,20 +384,20 @@ impl ScryerServer {
             // Skip if scoped to a node not in this group
             if let Some(scope) = scope_filter {
                 let in_group = member_containers.iter().any(|n| n.id == scope)
-                    || member_containers.iter().any(|n| is_descendant_of(scope, &n.id));
+                    || member_containers.iter().any(|n| index.is_descendant_of(scope, &n.id));
                 if !in_group { continue; }
             }
 
             if !member_containers.is_empty() && all_members_proposed {
                 // Scaffold task for this deployment group — step 0, not counted in task total
                 let mut output = format!(
-                    "# Setup\n\n## Scaffold: {}\n\n",
-                    group.name
+                    "# {}\n\n## {}: {}\n\n",
+                    msg.setup, msg.scaffold, group.name
                 );
                 if let Some(desc) = &group.description {
                     output.push_str(&format!("{}\n\n", desc));
                 }
-                output.push_str("Set up the project structure for these containers:\n\n");
+                output.push_str(&format!("{}\n\n", msg.scaffold_intro));
                 for mc in &member_containers {
                     output.push_str(&format!("- **{}** [{}]", mc.data.name, mc.id));
                     if let Some(tech) = &mc.data.technology {
@@ -378,21 +411,21 @@ impl ScryerServer {
 
                 // Include group contract if present
                 if !group.contract.is_empty() {
-                    output.push_str(&format!("\n{} — Group Contract (MUST follow):\n", group.name));
+                    output.push_str(&format!("\n{} — {}\n", group.name, msg.group_contract));
                     if !group.contract.expect.is_empty() {
-                        output.push_str("  MUST:\n");
+                        output.push_str(&format!("  {}\n", msg.must));
                         for item in &group.contract.expect {
                             output.push_str(&format!("    - {}\n", item));
                         }
                     }
                     if !group.contract.ask.is_empty() {
-                        output.push_str("  ASK USER FIRST:\n");
+                        output.push_str(&format!("  {}\n", msg.ask_user));
                         for item in &group.contract.ask {
                             output.push_str(&format!("    - {}\n", item));
                         }
                     }
                     if !group.contract.never.is_empty() {
-                        output.push_str("  NEVER:\n");
+                        output.push_str(&format!("  {}\n", msg.never));
                         for item in &group.contract.never {
                             output.push_str(&format!("    - {}\n", item));
                         }
@@ -405,16 +438,17 @@ impl ScryerServer {
                     let contract = merge_contract(&ancestors, mc);
                     let notes = collect_notes(&ancestors, mc);
                     output.push_str(&format_contract_and_notes(
-                        &mc.data.name, &contract, &notes,
+                        &mc.data.name, &contract, &notes, msg,
                     ));
                 }
 
-                output.push_str(&format!("\n---\n\n{}\n\n", TASK_INSTRUCTIONS));
+                output.push_str(&format!("\n---\n\n{}\n\n", msg.instructions));
 
                 // Node IDs to mark implemented
                 let ids: Vec<&str> = member_containers.iter().map(|n| n.id.as_str()).collect();
                 output.push_str(&format!(
-                    "After scaffolding, mark these as implemented with a reason explaining what was scaffolded:\n```\nupdate_nodes(model: \"{}\", nodes: [{}])\n```\n",
+                    "{}\n```\nupdate_nodes(model: \"{}\", nodes: [{}])\n```\n",
+                    msg.after_scaffold,
                     model_ref,
                     ids.iter().map(|id| format!("{{node_id: \"{}\", status: \"implemented\", reason: \"Needs implementation\"}}", id)).collect::<Vec<_>>().join(", ")
                 ));
@@ -422,9 +456,9 @@ impl ScryerServer {
                 // Next up
                 let next_name = find_next_name(&blocked_nodes, &ready_nodes, &member_containers);
                 output.push_str(&format!(
-                    "\n---\nProgress: {}/{} tasks complete{}",
-                    completed_tasks, total_tasks,
-                    if let Some(name) = next_name { format!(" | Next up: {}", name) } else { String::new() }
+                    "\n---\n{}{}",
+                    msg.progress_line(completed_tasks, total_tasks, next_name),
+                    remaining
                 ));
 
                 return Ok(CallToolResult::success(vec![Content::text(output)]));
@@ -456,14 +490,16 @@ impl ScryerServer {
                 if node.data.external == Some(true) { continue; }
                 // Skip if parent is external
                 if let Some(pid) = &node.parent_id {
-                    if let Some(parent) = model.nodes.iter().find(|p| p.id == *pid) {
+                    if let Some(parent) = index.get(pid) {
                         if parent.data.external == Some(true) { continue; }
                     }
                 }
                 // Include if the container itself or any of its children need work
-                let self_needs_work = !is_satisfied(node);
-                let children_need_work = model.nodes.iter().any(|n| {
-                    n.parent_id.as_deref() == Some(&node.id)
+                let self_needs_work =
+                    !is_satisfied(node) && !is_skipped(&node.id) && status_matches(node);
+                let children_need_work = index.children(&node.id).iter().any(|n| {
+                    !is_skipped(&n.id)
+                        && status_matches(n)
                         && n.data.status.is_some()
                         && !matches!(n.data.status, Some(Status::Implemented) | Some(Status::Verified) | Some(Status::Vagrant))
                 });
@@ -474,8 +510,8 @@ impl ScryerServer {
 
             if choosable_containers.len() > 1 {
                 let mut output = format!(
-                    "# Task {} of {}\n\n## Choose next task\n\nThese containers are ready to build. Pick the one that makes the most sense to start with.\n\n",
-                    completed_tasks + 1, total_tasks
+                    "# {} {} {} {}\n\n## {}\n\n{}\n\n",
+                    msg.task, completed_tasks + 1, msg.of, total_tasks, msg.choose_next, msg.choose_intro
                 );
 
                 // Collect which containers belong to groups
@@ -493,7 +529,7 @@ impl ScryerServer {
 
                 // Show groups first
                 for (_gid, (name, desc, members)) in &grouped {
-                    output.push_str(&format!("**Group: {}**", name));
+                    output.push_str(&format!("**{}: {}**", msg.group, name));
                     if let Some(d) = desc {
                         output.push_str(&format!(" — {}", d));
                     }
@@ -509,7 +545,7 @@ impl ScryerServer {
                         }
                         let notes = collect_notes(&get_ancestor_chain(&node.id), node);
                         for n in &notes {
-                            output.push_str(&format!("    Note: {}\n", n));
+                            output.push_str(&format!("    {}: {}\n", msg.note, n));
                         }
                     }
                 }
@@ -526,14 +562,15 @@ impl ScryerServer {
                     }
                     let notes = collect_notes(&get_ancestor_chain(&node.id), node);
                     for n in &notes {
-                        output.push_str(&format!("  Note: {}\n", n));
+                        output.push_str(&format!("  {}: {}\n", msg.note, n));
                     }
                 }
 
-                output.push_str("\nCall `get_task` again with `node_id` set to the chosen container's ID.");
+                output.push_str(&format!("\n{}", msg.choose_call));
                 output.push_str(&format!(
-                    "\n\n---\nProgress: {}/{} tasks complete",
-                    completed_tasks, total_tasks
+                    "\n\n---\n{}{}",
+                    msg.progress_line(completed_tasks, total_tasks, None),
+                    remaining
                 ));
                 return Ok(CallToolResult::success(vec![Content::text(output)]));
             }
@@ -554,20 +591,19 @@ impl ScryerServer {
             // Check for inter-dependencies among siblings
             let sibling_ids: std::collections::HashSet<&str> =
                 siblings.iter().map(|n| n.id.as_str()).collect();
-            let has_inter_deps = model.edges.iter().any(|e| {
-                sibling_ids.contains(e.source.as_str())
-                    && sibling_ids.contains(e.target.as_str())
-            });
+            let depends_on_sibling = |n: &C4Node| {
+                index
+                    .outgoing(&n.id)
+                    .iter()
+                    .any(|e| !is_async_edge(e) && sibling_ids.contains(e.target.as_str()))
+            };
+            let has_inter_deps = siblings.iter().any(|n| depends_on_sibling(*n));
 
             if has_inter_deps {
                 // Return only the first sibling that has no deps on other siblings
                 siblings
                     .iter()
-                    .filter(|n| {
-                        !model.edges.iter().any(|e| {
-                            e.source == n.id && sibling_ids.contains(e.target.as_str())
-                        })
-                    })
+                    .filter(|n| !depends_on_sibling(**n))
                     .copied()
                     .collect::<Vec<_>>()
                     .into_iter()
@@ -581,9 +617,10 @@ impl ScryerServer {
 
         if work_unit.is_empty() {
             // Shouldn't happen but safety fallback
-            return Ok(CallToolResult::success(vec![Content::text(
-                "All tasks complete. Nothing to build.",
-            )]));
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "{} {}",
+                msg.all_complete, msg.nothing_to_build
+            ))]));
         }
 
         // Format the work unit
@@ -591,22 +628,14 @@ impl ScryerServer {
         let global_total: usize = model.nodes.iter().filter(|n| {
             let eligible = matches!(n.data.kind, C4Kind::Container | C4Kind::Component);
             if !eligible || n.data.status.is_none() { return false; }
-            if let Some(pid) = &n.parent_id {
-                if let Some(parent) = model.nodes.iter().find(|p| p.id == *pid) {
-                    if parent.data.external == Some(true) { return false; }
-                }
-            }
+            if index.has_external_parent(n) { return false; }
             if n.data.kind == C4Kind::Container && has_status_children(n) { return false; }
             true
         }).count();
         let global_completed: usize = model.nodes.iter().filter(|n| {
             let eligible = matches!(n.data.kind, C4Kind::Container | C4Kind::Component);
             if !eligible || n.data.status.is_none() { return false; }
-            if let Some(pid) = &n.parent_id {
-                if let Some(parent) = model.nodes.iter().find(|p| p.id == *pid) {
-                    if parent.data.external == Some(true) { return false; }
-                }
-            }
+            if index.has_external_parent(n) { return false; }
             if n.data.kind == C4Kind::Container && has_status_children(n) { return false; }
             is_satisfied(n)
         }).count();
@@ -622,17 +651,17 @@ impl ScryerServer {
             let group_name = model.groups.iter().find(|g| {
                 work_unit.iter().any(|n| g.member_ids.contains(&n.id))
             }).map(|g| g.name.clone());
-            format!("Scaffold: {}", group_name.unwrap_or_else(|| work_unit[0].data.name.clone()))
+            format!("{}: {}", msg.scaffold, group_name.unwrap_or_else(|| work_unit[0].data.name.clone()))
         } else if work_unit.len() == 1 {
-            format!("Build: {}", work_unit[0].data.name)
+            format!("{}: {}", msg.build, work_unit[0].data.name)
         } else {
             let names: Vec<&str> = work_unit.iter().map(|n| n.data.name.as_str()).collect();
-            format!("Build: {}", names.join(" + "))
+            format!("{}: {}", msg.build, names.join(" + "))
         };
 
         let mut output = format!(
-            "# Task {} of {}\n\n## {}\n\nBuild ONLY what this task describes. Do not scaffold or set up other parts of the project.\n\n",
-            task_num, global_total, unit_label
+            "# {} {} {} {}\n\n## {}\n\n{}\n\n",
+            msg.task, task_num, msg.of, global_total, unit_label, msg.build_only
         );
 
         for node in &work_unit {
@@ -650,27 +679,27 @@ impl ScryerServer {
                 output.push_str(&format!("{}\n", node.data.description));
             }
             if let Some(tech) = &node.data.technology {
-                output.push_str(&format!("Technology: {}\n", tech));
+                output.push_str(&format!("{}: {}\n", msg.technology, tech));
             }
-            output.push_str(&format!("Status: {}\n", status_str(&node.data.status)));
+            output.push_str(&format!("{}: {}\n", msg.status, status_str(&node.data.status)));
 
             // Contract — framed as binding requirements so agents don't skip them
             if !contract.is_empty() {
-                output.push_str("\nContract (you MUST follow these requirements):\n");
+                output.push_str(&format!("\n{}\n", msg.contract));
                 if !contract.expect.is_empty() {
-                    output.push_str("  MUST:\n");
+                    output.push_str(&format!("  {}\n", msg.must));
                     for item in &contract.expect {
                         output.push_str(&format!("    - {}\n", item));
                     }
                 }
                 if !contract.ask.is_empty() {
-                    output.push_str("  ASK USER FIRST:\n");
+                    output.push_str(&format!("  {}\n", msg.ask_user));
                     for item in &contract.ask {
                         output.push_str(&format!("    - {}\n", item));
                     }
                 }
                 if !contract.never.is_empty() {
-                    output.push_str("  NEVER:\n");
+                    output.push_str(&format!("  {}\n", msg.never));
                     for item in &contract.never {
                         output.push_str(&format!("    - {}\n", item));
                     }
@@ -679,22 +708,21 @@ impl ScryerServer {
 
             // Notes
             if !notes.is_empty() {
-                output.push_str("\nNotes:\n");
+                output.push_str(&format!("\n{}\n", msg.notes));
                 for d in &notes {
                     output.push_str(&format!("  - {}\n", d));
                 }
             }
 
             // Child processes
-            let child_processes: Vec<&C4Node> = model
-                .nodes
+            let child_processes: Vec<&C4Node> = index
+                .children(&node.id)
                 .iter()
-                .filter(|n| {
-                    n.parent_id.as_deref() == Some(&node.id) && n.data.kind == C4Kind::Process
-                })
+                .copied()
+                .filter(|n| n.data.kind == C4Kind::Process)
                 .collect();
             if !child_processes.is_empty() {
-                output.push_str("\nProcesses:\n");
+                output.push_str(&format!("\n{}\n", msg.processes));
                 for p in &child_processes {
                     output.push_str(&format!(
                         "  - {} [{}] ({})\n",
@@ -709,15 +737,14 @@ impl ScryerServer {
             }
 
             // Child models
-            let child_models: Vec<&C4Node> = model
-                .nodes
+            let child_models: Vec<&C4Node> = index
+                .children(&node.id)
                 .iter()
-                .filter(|n| {
-                    n.parent_id.as_deref() == Some(&node.id) && n.data.kind == C4Kind::Model
-                })
+                .copied()
+                .filter(|n| n.data.kind == C4Kind::Model)
                 .collect();
             if !child_models.is_empty() {
-                output.push_str("\nModels:\n");
+                output.push_str(&format!("\n{}\n", msg.models));
                 for m in &child_models {
                     output.push_str(&format!(
                         "  - {} [{}] ({})\n",
@@ -731,6 +758,9 @@ impl ScryerServer {
                     if !m.data.properties.is_empty() {
                         for prop in &m.data.properties {
                             output.push_str(&format!("    .{}", prop.label));
+                            if let Some(ty) = &prop.type_ {
+                                output.push_str(&format!(": {}", ty));
+                            }
                             if !prop.description.is_empty() {
                                 output.push_str(&format!(" — {}", prop.description));
                             }
@@ -741,15 +771,14 @@ impl ScryerServer {
             }
 
             // Operations
-            let operations: Vec<&C4Node> = model
-                .nodes
+            let operations: Vec<&C4Node> = index
+                .children(&node.id)
                 .iter()
-                .filter(|n| {
-                    n.parent_id.as_deref() == Some(&node.id) && n.data.kind == C4Kind::Operation
-                })
+                .copied()
+                .filter(|n| n.data.kind == C4Kind::Operation)
                 .collect();
             if !operations.is_empty() {
-                output.push_str("\nOperations:\n");
+                output.push_str(&format!("\n{}\n", msg.operations));
                 for op in &operations {
                     output.push_str(&format!(
                         "  - {} [{}] ({})\n",
@@ -765,7 +794,7 @@ impl ScryerServer {
 
             // Sources
             if !node.data.sources.is_empty() {
-                output.push_str("\nSources:\n");
+                output.push_str(&format!("\n{}\n", msg.sources));
                 for r in &node.data.sources {
                     output.push_str(&format!("  - {} — {}\n", r.pattern, r.comment));
                 }
@@ -777,25 +806,27 @@ impl ScryerServer {
                 .iter()
                 .filter_map(|e| {
                     if e.source == node.id {
-                        let target = model.nodes.iter().find(|n| n.id == e.target);
+                        let target = index.get(&e.target);
                         let label = e.data.as_ref().map(|d| d.label.as_str()).unwrap_or("");
                         target.map(|t| {
                             format!(
-                                "  -> {} \"{}\" ({})",
+                                "  -> {} \"{}\" ({}){}",
                                 t.data.name,
                                 label,
-                                kind_str(&t.data.kind)
+                                kind_str(&t.data.kind),
+                                edge_coupling(e)
                             )
                         })
                     } else if e.target == node.id {
-                        let source = model.nodes.iter().find(|n| n.id == e.source);
+                        let source = index.get(&e.source);
                         let label = e.data.as_ref().map(|d| d.label.as_str()).unwrap_or("");
                         source.map(|s| {
                             format!(
-                                "  <- {} \"{}\" ({})",
+                                "  <- {} \"{}\" ({}){}",
                                 s.data.name,
                                 label,
-                                kind_str(&s.data.kind)
+                                kind_str(&s.data.kind),
+                                edge_coupling(e)
                             )
                         })
                     } else {
@@ -805,7 +836,7 @@ impl ScryerServer {
                 .collect();
 
             if !deps.is_empty() {
-                output.push_str("\nDependencies:\n");
+                output.push_str(&format!("\n{}\n", msg.dependencies));
                 for dep in &deps {
                     output.push_str(&format!("{}\n", dep));
                 }
@@ -814,12 +845,13 @@ impl ScryerServer {
             output.push('\n');
         }
 
-        output.push_str(&format!("---\n\n{}\n\n", TASK_INSTRUCTIONS));
+        output.push_str(&format!("---\n\n{}\n\n", msg.instructions));
 
         // Mark-as-implemented hint
         let ids: Vec<&str> = work_unit.iter().map(|n| n.id.as_str()).collect();
         output.push_str(&format!(
-            "After building, mark as implemented with a reason and set source locations:\n```\nupdate_nodes(model: \"{}\", nodes: [{}])\n```\n",
+            "{}\n```\nupdate_nodes(model: \"{}\", nodes: [{}])\n```\n",
+            msg.after_build,
             model_ref,
             ids.iter().map(|id| format!("{{node_id: \"{}\", status: \"implemented\", reason: \"Needs error handling\", source: [{{pattern: \"src/module/file.ts\", line: 1, endLine: 50}}]}}", id)).collect::<Vec<_>>().join(", ")
         ));
@@ -828,17 +860,16 @@ impl ScryerServer {
         let mut pending_members: Vec<(&C4Node, &str)> = Vec::new(); // (node, parent_name)
         for node in &work_unit {
             if node.data.kind == C4Kind::Component {
-                for member in model.nodes.iter().filter(|n| {
-                    n.parent_id.as_deref() == Some(&node.id)
-                        && matches!(n.data.kind, C4Kind::Operation | C4Kind::Process | C4Kind::Model)
-                        && matches!(n.data.status, Some(Status::Proposed))
+                for member in index.children(&node.id).iter().copied().filter(|n| {
+                    matches!(n.data.kind, C4Kind::Operation | C4Kind::Process | C4Kind::Model)
+                        && matches!(n.data.status, Some(Status::Proposed) | Some(Status::InProgress))
                 }) {
                     pending_members.push((member, &node.data.name));
                 }
             }
         }
         if !pending_members.is_empty() {
-            output.push_str("\nAlso mark these member nodes as `implemented` with a reason explaining what was built:\n");
+            output.push_str(&format!("\n{}\n", msg.also_mark_members));
             for (member, parent_name) in &pending_members {
                 output.push_str(&format!(
                     "  - {} [{}] ({}, {}) in {}\n",
@@ -854,11 +885,240 @@ impl ScryerServer {
         // Next up
         let next_name = find_next_name(&blocked_nodes, &ready_nodes, &work_unit);
         output.push_str(&format!(
-            "\n---\nProgress: {}/{} tasks complete{}",
-            global_completed, global_total,
-            if let Some(name) = next_name { format!(" | Next up: {}", name) } else { String::new() }
+            "\n---\n{}{}",
+            msg.progress_line(global_completed, global_total, next_name),
+            remaining
         ));
 
         Ok(CallToolResult::success(vec![Content::text(output)]))
     }
+
+    #[tool(
+        description = "Report how complete the model is as a spec. For every container and component (outside external systems) checks: has a status, has a description, has source locations (sources or source map), and — for components — has member operations/processes/models. Returns per-check percentages and the nodes missing the most. Pass node_id to scope to a subtree."
+    )]
+    fn get_coverage(
+        &self,
+        Parameters(req): Parameters<GetCoverageRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let model_ref = match self.resolve_model(req.name) {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
+        let model = match scryer_core::read_model_at(&model_ref) {
+            Ok(m) => m,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read model '{}': {}",
+                    model_ref, e
+                ))]));
+            }
+        };
+        if let Some(scope) = &req.node_id {
+            if !model.nodes.iter().any(|n| n.id == *scope) {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Node '{}' not found",
+                    scope
+                ))]));
+            }
+        }
+
+        // Same population get_task draws from, minus the status requirement —
+        // a missing status is itself a coverage gap.
+        let nodes: Vec<&C4Node> = model
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.data.kind, C4Kind::Container | C4Kind::Component))
+            .filter(|n| n.data.external != Some(true) && !has_external_parent(&model, n))
+            .filter(|n| match req.node_id.as_deref() {
+                Some(scope) => n.id == scope || is_descendant_of(&model, &n.id, scope),
+                None => true,
+            })
+            .collect();
+
+        if nodes.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No containers or components to assess.",
+            )]));
+        }
+
+        const CHECKS: [&str; 4] = ["status", "description", "sources", "members"];
+        let mut passed = [0usize; 4];
+        let mut applicable = [0usize; 4];
+        let mut gaps: Vec<(&C4Node, Vec<&str>)> = Vec::new();
+        for node in &nodes {
+            let has_members = model.nodes.iter().any(|c| {
+                c.parent_id.as_deref() == Some(node.id.as_str())
+                    && matches!(
+                        c.data.kind,
+                        C4Kind::Operation | C4Kind::Process | C4Kind::Model
+                    )
+            });
+            let results = [
+                Some(node.data.status.is_some()),
+                Some(!node.data.description.trim().is_empty()),
+                Some(
+                    !node.data.sources.is_empty()
+                        || model
+                            .source_map
+                            .get(&node.id)
+                            .is_some_and(|l| !l.is_empty()),
+                ),
+                (node.data.kind == C4Kind::Component).then_some(has_members),
+            ];
+            let mut missing = Vec::new();
+            for (i, result) in results.iter().enumerate() {
+                let Some(ok) = result else { continue };
+                applicable[i] += 1;
+                if *ok {
+                    passed[i] += 1;
+                } else {
+                    missing.push(CHECKS[i]);
+                }
+            }
+            if !missing.is_empty() {
+                gaps.push((node, missing));
+            }
+        }
+
+        let percent = |p: usize, a: usize| if a == 0 { 100 } else { p * 100 / a };
+        let total_passed: usize = passed.iter().sum();
+        let total_applicable: usize = applicable.iter().sum();
+        let mut output = format!(
+            "Coverage for '{}': {}% across {} container(s)/component(s)\n",
+            model_ref,
+            percent(total_passed, total_applicable),
+            nodes.len()
+        );
+        for i in 0..CHECKS.len() {
+            if applicable[i] == 0 {
+                continue;
+            }
+            output.push_str(&format!(
+                "\n- {}: {}/{} ({}%)",
+                CHECKS[i],
+                passed[i],
+                applicable[i],
+                percent(passed[i], applicable[i])
+            ));
+        }
+
+        if gaps.is_empty() {
+            output.push_str("\n\nEvery node passes all checks.");
+        } else {
+            // Most missing checks first, then model order
+            gaps.sort_by_key(|(_, missing)| std::cmp::Reverse(missing.len()));
+            output.push_str(&format!(
+                "\n\nNeeds the most work ({} node(s) with gaps):",
+                gaps.len()
+            ));
+            for (node, missing) in gaps.iter().take(10) {
+                output.push_str(&format!(
+                    "\n- {} [{}] ({}) — missing: {}",
+                    node.data.name,
+                    node.id,
+                    kind_str(&node.data.kind),
+                    missing.join(", ")
+                ));
+            }
+            if gaps.len() > 10 {
+                output.push_str(&format!("\n- ... and {} more", gaps.len() - 10));
+            }
+        }
+
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::testing::{stored, text};
+    use scryer_core::C4ModelData;
+
+    /// Call `get_task` and return whether it errored, with its text.
+    fn get_task(req: serde_json::Value) -> (bool, String) {
+        let req: GetTaskRequest = serde_json::from_value(req).unwrap();
+        text(ScryerServer::new().get_task(Parameters(req)).unwrap())
+    }
+
+    #[test]
+    fn hands_out_work_on_a_large_model() {
+        // 20 systems x 50 containers x 20 components — ~21k nodes — with each
+        // component depending on its previous sibling and each container
+        // calling the next, ~20k edges: enough that per-node linear scans of
+        // nodes or edges would stall the call.
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for s in 0..20 {
+            let sys = format!("sys-{}", s);
+            nodes.push(serde_json::json!({ "id": sys, "data": { "name": sys, "kind": "system" } }));
+            for c in 0..50 {
+                let con = format!("{}-con-{}", sys, c);
+                nodes.push(serde_json::json!({ "id": con, "parentId": sys, "data": { "name": con, "kind": "container", "status": "proposed" } }));
+                if c > 0 {
+                    let prev = format!("{}-con-{}", sys, c - 1);
+                    edges.push(serde_json::json!({ "id": format!("{}-e", con), "source": prev, "target": con, "data": { "label": "calls" } }));
+                }
+                for k in 0..20 {
+                    let comp = format!("{}-comp-{}", con, k);
+                    nodes.push(serde_json::json!({ "id": comp, "parentId": con, "data": { "name": comp, "kind": "component", "status": "proposed" } }));
+                    if k > 0 {
+                        let prev = format!("{}-comp-{}", con, k - 1);
+                        edges.push(serde_json::json!({ "id": format!("{}-e", comp), "source": comp, "target": prev, "data": { "label": "uses" } }));
+                    }
+                }
+            }
+        }
+        let model: C4ModelData =
+            serde_json::from_value(serde_json::json!({ "nodes": nodes, "edges": edges })).unwrap();
+        let (root, name) = stored("large", &model);
+
+        let (failed, text) = get_task(serde_json::json!({ "name": name }));
+        assert!(!failed, "{text}");
+        assert!(text.contains("sys-0-con-0"), "{text}");
+
+        let (failed, text) =
+            get_task(serde_json::json!({ "name": name, "node_id": "sys-7-con-3" }));
+        assert!(!failed, "{text}");
+        // Only the first component is free of unbuilt sibling dependencies.
+        assert!(text.contains("## Build: sys-7-con-3-comp-0\n"), "{text}");
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn renders_in_the_requested_language() {
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "sys", "data": { "name": "Shop", "kind": "system" } },
+                { "id": "api", "parentId": "sys", "data": { "name": "API", "kind": "container", "status": "proposed" } },
+                { "id": "db", "parentId": "sys", "data": { "name": "DB", "kind": "container", "status": "implemented" } }
+            ],
+            "edges": []
+        }))
+        .unwrap();
+        let (root, name) = stored("es", &model);
+
+        let (failed, text) = get_task(serde_json::json!({ "name": name, "language": "es" }));
+        assert!(!failed, "{text}");
+        assert!(text.contains("Construir: API"), "{text}");
+        assert!(text.contains("Progreso: 1/2 tareas completadas"), "{text}");
+        assert!(!text.contains("Progress:"), "{text}");
+
+        let (failed, text) = get_task(
+            serde_json::json!({ "name": name, "language": "es", "status": "in_progress" }),
+        );
+        assert!(!failed, "{text}");
+        assert!(
+            text.starts_with("No hay trabajo in_progress listo."),
+            "{text}"
+        );
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn rejects_an_unsupported_language() {
+        let (failed, text) = get_task(serde_json::json!({ "name": "unused", "language": "fr" }));
+        assert!(failed);
+        assert!(text.contains("Unsupported language 'fr'"), "{text}");
+    }
 }
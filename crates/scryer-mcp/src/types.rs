@@ -6,6 +6,42 @@ pub(crate) struct GetModelRequest {
     /// Name of the model to retrieve. If omitted, resolves the model linked to the current working directory.
     #[serde(alias = "model")]
     pub name: Option<String>,
+    /// Output format: "json" (default) or "compact" — one line per node and edge plus a flow outline. Compact omits contracts, notes, source maps, and groups.
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct StreamModelRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// What to page through: "nodes" (default), "edges", or "flows". Can be omitted when passing a cursor.
+    pub section: Option<String>,
+    /// Continuation token from the previous page's next_cursor. Omit to start at the beginning.
+    pub cursor: Option<String>,
+    /// Items per page (default 200, max 1000)
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetModelsRequest {
+    /// Names of the models to retrieve
+    pub names: Vec<String>,
+    /// Output format for every model: "json" (default) or "compact"
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ValidateModelRequest {
+    /// Name of the model to validate. If omitted, resolves the model linked to the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetRulesRequest {
+    /// Return only part of the rules: "rules" (the numbered C4 rules), "workflow", "authority" (or "hierarchy"), or a rule number like "12". Omit for everything.
+    pub section: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -14,7 +50,34 @@ pub(crate) struct GetNodeRequest {
     #[serde(alias = "model")]
     pub name: Option<String>,
     /// ID of the node to inspect (e.g. "node-3"). Returns this node, all its descendants, edges between them, and edges connecting them to external nodes (with external node names for context).
-    pub node_id: String,
+    pub node_id: Option<String>,
+    /// Display name of the node, matched case-insensitively. Used when node_id is omitted.
+    pub node_name: Option<String>,
+    /// Also return `ancestors`: the parent chain (root first) with each ancestor's name, kind, description, and contract.
+    #[serde(default)]
+    pub include_ancestors: bool,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetNeighborsRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// ID of the node whose direct connections to list.
+    pub node_id: Option<String>,
+    /// Display name of the node, matched case-insensitively. Used when node_id is omitted.
+    pub node_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ReviewNodeRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// ID of the node whose subtree to review (e.g. "node-3")
+    pub node_id: Option<String>,
+    /// Display name of the node, matched case-insensitively. Used when node_id is omitted.
+    pub node_name: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -24,13 +87,25 @@ pub(crate) struct SetModelRequest {
     pub name: Option<String>,
     /// The complete model as a JSON string. Must be a valid C4ModelData object with nodes, edges, and optional startingLevel. See get_model output for the exact schema.
     pub data: String,
+    /// Validate only: report every error and warning without writing the model (default false)
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ImportModelRequest {
+    /// Name to import the model as. Use "project:/path/to/repo" for a project-local model.
+    pub name: String,
+    /// The model JSON exactly as exported (e.g. get_model output from another machine)
+    pub data: String,
+    /// Replace an existing model with the same name (default false)
+    pub overwrite: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub(crate) struct AddNodeItem {
     /// Display name for the node
     pub name: String,
-    /// Description of what this node does or represents (max 200 characters, no limit for operation/process/model nodes)
+    /// Description of what this node does or represents (max 200 characters by default, no limit for operation/process/model nodes; a model's `limits` block can change both)
     pub description: String,
     /// Node kind: "person", "system", "container", "component", "operation", "process", or "model"
     pub kind: String,
@@ -44,7 +119,7 @@ pub(crate) struct AddNodeItem {
     pub shape: Option<String>,
     /// Source file locations as JSON array of {"pattern": "glob", "comment": "description"} objects. Pattern is a file glob (e.g. "src/auth/**/*.rs"), comment describes what those files do.
     pub sources: Option<Vec<scryer_core::Reference>>,
-    /// Status: "proposed", "implemented", "verified", or "vagrant"
+    /// Status: "proposed", "in_progress", "implemented", "verified", or "vagrant"
     pub status: Option<String>,
     /// Implementation contract: expect/ask/never rules
     pub contract: Option<Contract>,
@@ -52,6 +127,10 @@ pub(crate) struct AddNodeItem {
     pub notes: Option<Vec<String>>,
     /// Properties (model-kind nodes only): label/description pairs
     pub properties: Option<Vec<ModelProperty>>,
+    /// Free-form tags, e.g. ["pii", "hot-path"]. Must be non-empty and unique.
+    pub tags: Option<Vec<String>>,
+    /// Team or person that owns this node, e.g. "payments-team"
+    pub owner: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -68,7 +147,7 @@ pub(crate) struct UpdateNodeItem {
     pub node_id: String,
     /// New display name
     pub name: Option<String>,
-    /// New description (max 200 characters)
+    /// New description (max 200 characters by default, no limit for operation/process/model nodes; a model's `limits` block can change both)
     pub description: Option<String>,
     /// New technology label (max 28 characters)
     pub technology: Option<String>,
@@ -78,7 +157,7 @@ pub(crate) struct UpdateNodeItem {
     pub shape: Option<String>,
     /// New source file locations as JSON array of {"pattern": "glob", "comment": "description"} objects
     pub sources: Option<Vec<scryer_core::Reference>>,
-    /// New status: "proposed", "implemented", "verified", or "vagrant". "verified" requires all inherited expect contract items to have passed: true.
+    /// New status: "proposed", "in_progress", "implemented", "verified", or "vagrant". "verified" requires all inherited expect contract items to have passed: true.
     pub status: Option<String>,
     /// Required when changing status. State what's still missing or what was just completed — e.g. "Needs auth middleware and rate limiting", "Missing error handling". For verified: "All contract items pass". Keep it short and factual.
     pub reason: Option<String>,
@@ -93,6 +172,10 @@ pub(crate) struct UpdateNodeItem {
     /// For containers/components, a glob: [{"pattern": "src/auth/**/*.ts"}]
     /// Pass an empty array to clear.
     pub source: Option<Vec<SourceLocation>>,
+    /// Replacement tags. Pass an empty array to clear.
+    pub tags: Option<Vec<String>>,
+    /// New owner. Pass an empty string to clear.
+    pub owner: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -103,6 +186,24 @@ pub(crate) struct UpdateNodeRequest {
     pub nodes: Vec<UpdateNodeItem>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct SetStatusRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// Explicit node IDs to update. If omitted, the filter fields select the nodes.
+    pub node_ids: Option<Vec<String>>,
+    /// Filter: only nodes of this kind
+    pub kind: Option<String>,
+    /// Filter: only direct children of this node
+    pub parent_id: Option<String>,
+    /// Filter: only nodes currently at this status
+    pub status: Option<String>,
+    /// New status: "proposed", "in_progress", "implemented", "verified", or "vagrant"
+    pub to: String,
+    /// Why the status is changing — recorded on every matched node
+    pub reason: String,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub(crate) struct SetNodeRequest {
     /// Name of the model. If omitted, resolves from the current working directory.
@@ -121,16 +222,29 @@ pub(crate) struct DeleteNodeRequest {
     pub node_ids: Vec<String>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct MakeExternalRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// ID of the system to mark external. Its descendants are deleted.
+    pub node_id: String,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub(crate) struct AddEdgeItem {
     /// Source node ID
     pub source: String,
     /// Target node ID
     pub target: String,
-    /// Short relationship label (max 30 characters), e.g. "reads from", "sends events"
+    /// Short relationship label (max 30 characters by default), e.g. "reads from", "sends events"
     pub label: String,
     /// Method/protocol, e.g. "REST/JSON", "gRPC"
     pub method: Option<String>,
+    /// Whether the relationship is asynchronous (fire-and-forget via a queue or event bus)
+    #[serde(rename = "async")]
+    pub async_: Option<bool>,
+    /// Explicit edge ID. If omitted, defaults to "edge-{source}-{target}", with a "-2", "-3", … suffix when the pair already has an edge.
+    pub id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -149,6 +263,9 @@ pub(crate) struct UpdateEdgeItem {
     pub label: Option<String>,
     /// New method
     pub method: Option<String>,
+    /// Mark the relationship as asynchronous (true) or synchronous (false)
+    #[serde(rename = "async")]
+    pub async_: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -183,11 +300,96 @@ pub(crate) struct UpdateSourceMapRequest {
     pub entries: Vec<SourceMapEntry>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetSourceMapRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// Node or flow ID to look up. If omitted, returns every mapped node and flow.
+    pub node_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetUnlinkedOperationsRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// Optional node ID to scope the search to a subtree (e.g. one container). If omitted, covers the entire model.
+    pub node_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct PathBetweenRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// ID of the node to start from
+    pub source_id: String,
+    /// ID of the node to reach
+    pub target_id: String,
+    /// Follow edges in either direction (default false: only source → target)
+    pub undirected: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct FindNodesRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// Only nodes of this kind
+    pub kind: Option<String>,
+    /// Only nodes with this status
+    pub status: Option<String>,
+    /// Only nodes carrying this tag (case-insensitive)
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ListEdgesRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// Only edges whose label contains this text (case-insensitive)
+    pub label_contains: Option<String>,
+    /// Only edges with this method/protocol, e.g. "gRPC" (case-insensitive)
+    pub method: Option<String>,
+    /// Only edges with this node ID as source or target
+    pub involving_node: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ExportRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// Output format: "dot" (GraphViz), "svg", "nodes_csv", or "edges_csv"
+    pub format: String,
+    /// Abstraction level to export: "system" (default), "container", or "component". Ignored by the CSV formats, which always list everything
+    pub level: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetOwnershipRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub(crate) struct GetChangesRequest {
     /// Name of the model to check for changes. If omitted, resolves from the current working directory.
     #[serde(alias = "model")]
     pub name: Option<String>,
+    /// Only report changes to this node, its descendants, and edges between them. Flow changes are left out when set.
+    pub node_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct DiffModelsRequest {
+    /// The model to diff from (e.g. the original)
+    pub name_a: String,
+    /// The model to diff to (e.g. the fork). Changes are reported as going from name_a to name_b.
+    pub name_b: String,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -197,6 +399,22 @@ pub(crate) struct GetTaskRequest {
     pub name: Option<String>,
     /// Optional node ID to scope tasks to a subtree. If omitted, derives tasks for the entire model.
     pub node_id: Option<String>,
+    /// Node IDs to pass over for this call (e.g. blocked on something outside your control). They still count toward progress and come back once omitted.
+    #[serde(default)]
+    pub skip_ids: Vec<String>,
+    /// Only hand out work on nodes with this status: "proposed" or "in_progress". Dependencies on nodes of other statuses are still respected.
+    pub status: Option<String>,
+    /// Language for the task's fixed text (headings, instructions, progress line): "en" (default) or "es". Node content is returned as written.
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct GetCoverageRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    #[serde(alias = "model")]
+    pub name: Option<String>,
+    /// Optional node ID to scope the report to a subtree. If omitted, covers the entire model.
+    pub node_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -215,11 +433,75 @@ pub(crate) struct DeleteFlowRequest {
     pub flow_id: String,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct AddStepItem {
+    /// Step text — one meaningful system interaction. Use @[Name] mentions to reference architecture nodes.
+    pub description: String,
+    /// Insert directly after this step ID, in the same list (works inside branches). Omit to append to the end of the flow.
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct AddStepsRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// ID of the flow to add steps to
+    pub flow_id: String,
+    /// Steps to add, in order. IDs are generated ('step-N').
+    pub steps: Vec<AddStepItem>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct UpdateStepItem {
+    /// ID of the step to update
+    pub step_id: String,
+    /// New step text
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct UpdateStepsRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// ID of the flow containing the steps
+    pub flow_id: String,
+    /// Steps to update
+    pub steps: Vec<UpdateStepItem>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct DeleteStepsRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// ID of the flow containing the steps
+    pub flow_id: String,
+    /// IDs of steps to delete. A deleted decision point takes its branches with it.
+    pub step_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ReorderFlowsRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// Every flow ID in the model, in the desired order
+    pub flow_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ReorderStepsRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// ID of the flow containing the steps
+    pub flow_id: String,
+    /// Every step ID of one step list (the flow's top level, or a single branch), in the desired order
+    pub step_ids: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub(crate) struct SetGroupsRequest {
     /// Name of the model. If omitted, resolves from the current working directory.
     pub model: Option<String>,
-    /// JSON string: a single group object or array of groups. Each group has: id, kind ("deployment" or "package"), name, memberIds (array of node IDs). Optional: description, contract (same format as node contracts: {expect, ask, never}).
+    /// JSON string: a single group object or array of groups. Each group has: id, kind ("deployment" or "package"), name, memberIds (array of node IDs). Optional: description, contract (same format as node contracts: {expect, ask, never}), color (hex, e.g. "#22c55e").
     pub data: String,
 }
 
@@ -231,6 +513,88 @@ pub(crate) struct DeleteGroupRequest {
     pub group_id: String,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct SetStartingLevelRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// Level the editor opens at: "system", "container", or "component". Omit to leave unchanged.
+    pub level: Option<String>,
+    /// ID of a node to open focused on — the editor drills into the view that shows it. Pass an empty string to clear. Omit to leave unchanged.
+    pub node_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct RepairModelRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// Report what would be fixed without writing the model (default false)
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ApplyHintRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// The hint's nodeId, as returned by review_node
+    pub node_id: String,
+    /// The hint's action, as returned by review_node
+    pub action: HintActionInput,
+}
+
+/// Mirrors `scryer_suggest::HintAction`, which has no JSON schema of its own.
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub(crate) enum HintActionInput {
+    /// {"type": "rename", "name": "New Name"}
+    Rename { name: String },
+    /// {"type": "reverseEdge", "edgeId": "edge-node-3-node-4"}
+    #[serde(rename_all = "camelCase")]
+    ReverseEdge { edge_id: String },
+}
+
+impl From<HintActionInput> for scryer_suggest::HintAction {
+    fn from(action: HintActionInput) -> Self {
+        match action {
+            HintActionInput::Rename { name } => Self::Rename { name },
+            HintActionInput::ReverseEdge { edge_id } => Self::ReverseEdge { edge_id },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct AttachFileRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// Node to attach the image to
+    pub node_id: String,
+    /// Path to a png, jpeg, gif, webp, or svg file on disk
+    pub path: String,
+    /// Contract section holding the attachment: "expect" (default), "ask", or "never"
+    pub section: Option<String>,
+    /// Index of an existing contract item in that section to attach the image to
+    pub item_index: Option<usize>,
+    /// Text for a new contract item carrying the image. Required when item_index is omitted.
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct ListAttachmentsRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// Only list attachments on this node. If omitted, lists every node's attachments.
+    pub node_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub(crate) struct RemoveAttachmentRequest {
+    /// Name of the model. If omitted, resolves from the current working directory.
+    pub model: Option<String>,
+    /// Node holding the attachment
+    pub node_id: String,
+    /// Attachment ID from list_attachments, e.g. "expect-0"
+    pub attachment_id: String,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub(crate) struct GetStructureRequest {
     /// Absolute path to the project directory to scan
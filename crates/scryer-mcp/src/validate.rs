@@ -1,4 +1,4 @@
-use crate::helpers::kind_str;
+use crate::helpers::{contract_override_target, kind_str, mentions};
 use scryer_core::{C4Kind, C4ModelData, C4Node, ModelProperty};
 use std::collections::{HashMap, HashSet};
 
@@ -44,7 +44,10 @@ pub(crate) fn validate_type_name(name: &str, node_label: &str) -> Result<(), Str
     }
 }
 
+/// Check property labels are valid, unique identifiers and that any given
+/// type is a single non-blank line.
 pub(crate) fn validate_property_labels(properties: &[ModelProperty], node_label: &str) -> Result<(), String> {
+    let mut seen = HashSet::new();
     for prop in properties {
         if !is_valid_identifier(&prop.label) {
             return Err(format!(
@@ -52,10 +55,53 @@ pub(crate) fn validate_property_labels(properties: &[ModelProperty], node_label:
                 prop.label, node_label
             ));
         }
+        if !seen.insert(prop.label.as_str()) {
+            return Err(format!(
+                "Duplicate property label '{}' on {}",
+                prop.label, node_label
+            ));
+        }
+        if let Some(ty) = &prop.type_ {
+            if ty.trim().is_empty() || ty.contains('\n') {
+                return Err(format!(
+                    "Property '{}' on {} has an invalid type '{}' (must be a non-blank single line)",
+                    prop.label, node_label, ty
+                ));
+            }
+        }
     }
     Ok(())
 }
 
+/// Check that a node's tags are non-blank and unique.
+pub(crate) fn validate_tags(tags: &[String], node_label: &str) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for tag in tags {
+        if tag.trim().is_empty() {
+            return Err(format!("Empty tag on {}", node_label));
+        }
+        if !seen.insert(tag.as_str()) {
+            return Err(format!("Duplicate tag '{}' on {}", tag, node_label));
+        }
+    }
+    Ok(())
+}
+
+/// Check that `color` is a `#rgb` or `#rrggbb` hex color.
+pub(crate) fn validate_hex_color(color: &str, label: &str) -> Result<(), String> {
+    let valid = color.strip_prefix('#').is_some_and(|hex| {
+        matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    });
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Color '{}' on {} must be a hex color like #22c55e",
+            color, label
+        ))
+    }
+}
+
 /// Check that no node is parented under an external system.
 pub(crate) fn validate_no_children_of_external(nodes: &[C4Node]) -> Result<(), String> {
     let external_ids: HashSet<&str> = nodes
@@ -77,6 +123,163 @@ pub(crate) fn validate_no_children_of_external(nodes: &[C4Node]) -> Result<(), S
     Ok(())
 }
 
+/// Check that no two nodes, and no two edges, share an ID.
+pub(crate) fn validate_unique_ids(model: &C4ModelData) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for node in &model.nodes {
+        if !seen.insert(node.id.as_str()) {
+            return Err(format!("Duplicate node ID '{}'", node.id));
+        }
+    }
+    let mut seen = HashSet::new();
+    for edge in &model.edges {
+        if !seen.insert(edge.id.as_str()) {
+            return Err(format!("Duplicate edge ID '{}'", edge.id));
+        }
+    }
+    Ok(())
+}
+
+/// Check that an edge doesn't point a node at itself.
+pub(crate) fn validate_no_self_loop(source: &str, target: &str) -> Result<(), String> {
+    if source == target {
+        return Err(format!(
+            "Edge from '{}' to itself is not allowed. Relationships must connect two different nodes.",
+            source
+        ));
+    }
+    Ok(())
+}
+
+/// Run every hard check `set_model` applies to an incoming model and collect
+/// all failures, so callers can fix them in one pass.
+pub(crate) fn validate_model_data(model: &C4ModelData) -> Vec<String> {
+    let mut errors = Vec::new();
+    if let Err(e) = validate_unique_ids(model) {
+        errors.push(e);
+    }
+
+    for node in &model.nodes {
+        if let Err(e) = scryer_core::limits::check_description_len(
+            &node.data.kind,
+            &node.data.description,
+            &node.data.name,
+            model.limits.as_ref(),
+        ) {
+            errors.push(e);
+        }
+        if let Some(tech) = &node.data.technology {
+            if tech.len() > 28 {
+                errors.push(format!(
+                    "Technology '{}' on '{}' exceeds 28 character limit",
+                    tech, node.data.name
+                ));
+            }
+        }
+        let label = format!("{:?} '{}'", node.data.kind, node.id);
+        if node.data.kind == C4Kind::Operation {
+            if let Err(e) = validate_identifier(&node.data.name, &label) {
+                errors.push(e);
+            }
+        }
+        if node.data.kind == C4Kind::Model {
+            if let Err(e) = validate_type_name(&node.data.name, &label) {
+                errors.push(e);
+            }
+        }
+        if !node.data.properties.is_empty() {
+            if let Err(e) =
+                validate_property_labels(&node.data.properties, &format!("node '{}'", node.id))
+            {
+                errors.push(e);
+            }
+        }
+        if let Err(e) = validate_tags(&node.data.tags, &format!("node '{}'", node.id)) {
+            errors.push(e);
+        }
+        if let Err(e) = validate_parent(model, &node.data.kind, node.parent_id.as_deref()) {
+            errors.push(format!("Node '{}': {}", node.id, e));
+        }
+    }
+
+    if let Err(e) = validate_no_children_of_external(&model.nodes) {
+        errors.push(e);
+    }
+    if let Err(e) = scryer_core::validate_no_parent_cycles(&model.nodes) {
+        errors.push(e.to_string());
+    }
+
+    let node_ids: HashSet<&str> = model.nodes.iter().map(|n| n.id.as_str()).collect();
+    for edge in &model.edges {
+        if !node_ids.contains(edge.source.as_str()) {
+            errors.push(format!(
+                "Edge '{}' source '{}' not found",
+                edge.id, edge.source
+            ));
+        }
+        if !node_ids.contains(edge.target.as_str()) {
+            errors.push(format!(
+                "Edge '{}' target '{}' not found",
+                edge.id, edge.target
+            ));
+        }
+        if let Err(e) = validate_no_self_loop(&edge.source, &edge.target) {
+            errors.push(e);
+        }
+        if let Some(data) = &edge.data {
+            if let Err(e) = scryer_core::limits::check_edge_label_len(&data.label) {
+                errors.push(e);
+            }
+        }
+    }
+    for group in &model.groups {
+        if let Err(e) = group_level(model, group) {
+            errors.push(e);
+        }
+    }
+    errors
+}
+
+/// The C4 level shared by every member of `group`, or `None` if it has no
+/// members in the model. Only containers and components can be grouped, and
+/// a group can't mix the two; the error names the first member that breaks
+/// either rule. Members missing from the model are skipped.
+pub(crate) fn group_level(
+    model: &C4ModelData,
+    group: &scryer_core::Group,
+) -> Result<Option<C4Kind>, String> {
+    let mut level: Option<(C4Kind, &str)> = None;
+    for mid in &group.member_ids {
+        let Some(node) = model.nodes.iter().find(|n| n.id == *mid) else {
+            continue;
+        };
+        let kind = node.data.kind;
+        if !matches!(kind, C4Kind::Container | C4Kind::Component) {
+            return Err(format!(
+                "Member '{}' of group '{}' is a {}; only containers and components can be grouped.",
+                mid,
+                group.name,
+                kind_str(&kind)
+            ));
+        }
+        match level {
+            None => level = Some((kind, mid)),
+            Some((l, first)) if l != kind => {
+                return Err(format!(
+                    "Member '{}' of group '{}' is a {}, but '{}' is a {}; all members must be at the same C4 level.",
+                    mid,
+                    group.name,
+                    kind_str(&kind),
+                    first,
+                    kind_str(&l)
+                ));
+            }
+            _ => {}
+        }
+    }
+    Ok(level.map(|(l, _)| l))
+}
+
 /// Find nodes that will appear disconnected (no edges) at their viewing level.
 pub(crate) fn check_disconnected_nodes(model: &C4ModelData) -> Vec<String> {
     let mut warnings: Vec<String> = Vec::new();
@@ -382,7 +585,106 @@ pub(crate) fn check_cross_container_edges(model: &C4ModelData) -> Vec<String> {
     warnings
 }
 
+/// Check a flow's step tree for structural problems: empty flows, dead-end
+/// branches, decision points with a single path, and unlabeled conditions.
+pub(crate) fn check_flow_structure(flow: &scryer_core::Flow) -> Vec<String> {
+    fn walk(steps: &[scryer_core::FlowStep], warnings: &mut Vec<String>) {
+        for step in steps {
+            if step.branches.len() == 1 {
+                warnings.push(format!(
+                    "Step '{}' has a single branch — a decision point needs at least two paths",
+                    step.id
+                ));
+            }
+            let mut conditions = HashSet::new();
+            for branch in &step.branches {
+                if branch.condition.trim().is_empty() {
+                    warnings.push(format!("Step '{}' has a branch with no condition", step.id));
+                } else if !conditions.insert(branch.condition.trim()) {
+                    warnings.push(format!(
+                        "Step '{}' has duplicate branch condition '{}'",
+                        step.id, branch.condition
+                    ));
+                }
+                if branch.steps.is_empty() {
+                    warnings.push(format!(
+                        "Step '{}' branch '{}' has no steps",
+                        step.id, branch.condition
+                    ));
+                }
+                walk(&branch.steps, warnings);
+            }
+        }
+    }
+
+    let mut warnings = Vec::new();
+    if flow.steps.is_empty() {
+        warnings.push(format!("Flow '{}' has no steps", flow.name));
+    }
+    if !flow.transitions.is_empty() {
+        warnings.push(format!(
+            "Flow '{}' uses legacy transitions, which are ignored — express ordering with step order and branches",
+            flow.name
+        ));
+    }
+    walk(&flow.steps, &mut warnings);
+    warnings
+}
+
+/// Check that @[Name] mentions in flow steps still point at nodes in the
+/// model. Flows only reference architecture through these mentions, so a
+/// deleted or renamed node silently breaks them.
+pub(crate) fn check_flow_mentions(model: &C4ModelData) -> Vec<String> {
+    fn walk(
+        flow: &scryer_core::Flow,
+        steps: &[scryer_core::FlowStep],
+        names: &HashSet<&str>,
+        warnings: &mut Vec<String>,
+    ) {
+        for step in steps {
+            let text = step
+                .description
+                .as_deref()
+                .or(step.label.as_deref())
+                .unwrap_or("");
+            for mentioned_name in mentions(text) {
+                if !names.contains(mentioned_name) {
+                    warnings.push(format!(
+                        "Flow '{}' step '{}' mentions @[{}], which is not in the model",
+                        flow.name, step.id, mentioned_name
+                    ));
+                }
+            }
+            for branch in &step.branches {
+                walk(flow, &branch.steps, names, warnings);
+            }
+        }
+    }
+
+    let names: HashSet<&str> = model.nodes.iter().map(|n| n.data.name.as_str()).collect();
+    let mut warnings = Vec::new();
+    for flow in &model.flows {
+        walk(flow, &flow.steps, &names, &mut warnings);
+    }
+    warnings
+}
+
+/// The warning block write tools append when [`check_flow_mentions`] finds
+/// broken mentions, or `None` if every mention resolves.
+pub(crate) fn flow_mention_warning(model: &C4ModelData) -> Option<String> {
+    let warnings = check_flow_mentions(model);
+    if warnings.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "\n\n⚠️ BROKEN FLOW MENTIONS: Flow steps reference nodes with @[Name] \
+        that are not in the model. Fix the steps with set_flows:\n- {}",
+        warnings.join("\n- ")
+    ))
+}
+
 /// Check if a node can be set to "verified" by verifying all inherited expect contract items are passed.
+/// Items removed by a closer node's override (see [`crate::helpers::merge_contract`]) don't count.
 pub(crate) fn check_verified_gate(
     nodes: &[C4Node],
     groups: &[scryer_core::Group],
@@ -391,19 +693,28 @@ pub(crate) fn check_verified_gate(
     own_contract: &scryer_core::Contract,
 ) -> Vec<String> {
     let mut unmet = Vec::new();
+    let mut overridden: HashSet<String> = HashSet::new();
     for ci in &own_contract.expect {
-        if ci.passed() != Some(true) {
+        if let Some(target) = contract_override_target(ci) {
+            overridden.insert(target);
+        } else if ci.passed() != Some(true) {
             unmet.push(format!("  - {}", ci.text()));
         }
     }
     let mut cur_id = parent_id.clone();
     while let Some(pid) = &cur_id {
         if let Some(anc) = nodes.iter().find(|n| n.id == *pid) {
+            let mut anc_overrides = Vec::new();
             for ci in &anc.data.contract.expect {
-                if ci.passed() != Some(true) {
+                if let Some(target) = contract_override_target(ci) {
+                    anc_overrides.push(target);
+                } else if ci.passed() != Some(true)
+                    && !overridden.contains(&ci.text().trim().to_lowercase())
+                {
                     unmet.push(format!("  - {} (from {})", ci.text(), anc.data.name));
                 }
             }
+            overridden.extend(anc_overrides);
             cur_id = anc.parent_id.clone();
         } else {
             break;
@@ -493,3 +804,100 @@ pub(crate) fn validate_parent(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_loop_is_rejected() {
+        let err = validate_no_self_loop("node-1", "node-1").unwrap_err();
+        assert!(err.contains("node-1"));
+        assert!(validate_no_self_loop("node-1", "node-2").is_ok());
+    }
+
+    #[test]
+    fn duplicate_property_label_is_rejected() {
+        let prop = |label: &str| ModelProperty {
+            label: label.to_string(),
+            description: String::new(),
+            type_: None,
+        };
+        let err = validate_property_labels(
+            &[prop("userId"), prop("email"), prop("userId")],
+            "node 'User'",
+        )
+        .unwrap_err();
+        assert!(err.contains("Duplicate property label 'userId'"));
+        assert!(validate_property_labels(&[prop("userId"), prop("email")], "node 'User'").is_ok());
+    }
+
+    #[test]
+    fn dangling_edge_is_rejected() {
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "node-1", "data": { "name": "User", "kind": "person" } },
+            ],
+            "edges": [
+                { "id": "edge-node-1-node-9", "source": "node-1", "target": "node-9", "data": { "label": "uses" } },
+            ],
+        }))
+        .unwrap();
+
+        let errors = validate_model_data(&model);
+        assert_eq!(
+            errors,
+            ["Edge 'edge-node-1-node-9' target 'node-9' not found"]
+        );
+    }
+
+    #[test]
+    fn mixed_level_group_is_rejected() {
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "node-1", "data": { "name": "Shop", "kind": "system" } },
+                { "id": "node-2", "parentId": "node-1", "data": { "name": "API", "kind": "container" } },
+                { "id": "node-3", "parentId": "node-2", "data": { "name": "Orders", "kind": "component" } },
+            ],
+            "edges": [],
+            "groups": [
+                { "id": "group-1", "name": "Fly.io", "memberIds": ["node-2", "node-3"] },
+            ],
+        }))
+        .unwrap();
+
+        let err = group_level(&model, &model.groups[0]).unwrap_err();
+        assert!(err.contains("Member 'node-3'"), "{err}");
+        assert!(
+            err.contains("is a component, but 'node-2' is a container"),
+            "{err}"
+        );
+        assert_eq!(validate_model_data(&model), [err]);
+    }
+
+    #[test]
+    fn code_level_node_outside_component_is_rejected() {
+        let model: C4ModelData = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "node-1", "data": { "name": "Shop", "kind": "system" } },
+                { "id": "node-2", "parentId": "node-1", "data": { "name": "API", "kind": "container" } },
+                { "id": "node-3", "parentId": "node-2", "data": { "name": "Orders", "kind": "component" } },
+                { "id": "node-4", "parentId": "node-3", "data": { "name": "createOrder", "kind": "operation" } },
+                { "id": "node-5", "parentId": "node-1", "data": { "name": "cancelOrder", "kind": "operation" } },
+                { "id": "node-6", "parentId": "node-2", "data": { "name": "Checkout", "kind": "process" } },
+                { "id": "node-7", "data": { "name": "Order", "kind": "model" } },
+            ],
+            "edges": [],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            validate_model_data(&model),
+            [
+                "Node 'node-5': operation parent must be a component, got System",
+                "Node 'node-6': process parent must be a component, got Container",
+                "Node 'node-7': model nodes require a parent_id (must be inside a component)",
+            ]
+        );
+    }
+}
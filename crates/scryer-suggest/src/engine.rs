@@ -16,12 +16,40 @@ fn map_backend(provider: &str) -> Result<LLMBackend, String> {
     }
 }
 
+/// Canned output for the `mock` provider: `SCRYER_MOCK_LLM_RESPONSE` holds the
+/// raw text, or `SCRYER_MOCK_LLM_FILE` points at a fixture file.
+fn mock_response() -> Result<String, String> {
+    if let Ok(text) = std::env::var("SCRYER_MOCK_LLM_RESPONSE") {
+        return Ok(text);
+    }
+    if let Ok(path) = std::env::var("SCRYER_MOCK_LLM_FILE") {
+        return std::fs::read_to_string(&path).map_err(|e| format!("mock fixture {path}: {e}"));
+    }
+    Err("mock provider: set SCRYER_MOCK_LLM_RESPONSE or SCRYER_MOCK_LLM_FILE".to_string())
+}
+
 pub async fn generate(
     settings: &AiSettings,
     system: &str,
     user_msg: &str,
 ) -> Result<String, String> {
-    let backend = map_backend(&settings.provider)?;
+    let provider = settings.effective_provider().ok_or_else(|| {
+        format!(
+            "no provider set and none inferable from model '{}'",
+            settings.model
+        )
+    })?;
+    if settings.provider.trim().is_empty() {
+        tracing::info!(
+            "inferred provider '{}' from model '{}'",
+            provider,
+            settings.model
+        );
+    }
+    if provider == "mock" {
+        return mock_response();
+    }
+    let backend = map_backend(provider)?;
 
     let mut builder = LLMBuilder::new()
         .backend(backend)
@@ -44,3 +72,13 @@ pub async fn generate(
         None => Err("LLM returned no text".to_string()),
     }
 }
+
+/// Send a trivial prompt to check that the provider, model, and key work.
+pub async fn test_connection(settings: &AiSettings) -> Result<(), String> {
+    if settings.model.is_empty() {
+        return Err("no model selected".to_string());
+    }
+    generate(settings, "You are a connectivity check.", "Reply with OK.")
+        .await
+        .map(|_| ())
+}
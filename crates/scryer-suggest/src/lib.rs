@@ -1,4 +1,5 @@
 pub mod engine;
+pub mod lint;
 pub mod models;
 mod parse;
 mod prompt;
@@ -11,6 +12,9 @@ pub struct Hint {
     pub node_id: String,
     pub message: String,
     pub severity: HintSeverity,
+    /// A mechanical fix the UI can apply in one click, when the hint has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<HintAction>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,26 +24,187 @@ pub enum HintSeverity {
     Warning,
 }
 
+/// Structured fix attached to a hint. Mirrors `HintAction` in the frontend,
+/// which applies it to the open model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum HintAction {
+    /// Rename the hinted node.
+    Rename { name: String },
+    /// Swap the source and target of an edge.
+    #[serde(rename_all = "camelCase")]
+    ReverseEdge { edge_id: String },
+}
+
+/// Apply a hint's `action` to `model`. `node_id` is the hinted node. Renames
+/// also rewrite `@[Name]` mentions of the node; reversed edges get the ID
+/// their new direction calls for. Returns a one-line summary of the change.
+pub fn apply_action(
+    model: &mut scryer_core::C4ModelData,
+    node_id: &str,
+    action: &HintAction,
+) -> Result<String, String> {
+    match action {
+        HintAction::Rename { name } => {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err("New name is empty".to_string());
+            }
+            let node = model
+                .nodes
+                .iter_mut()
+                .find(|n| n.id == node_id)
+                .ok_or_else(|| format!("Node '{}' not found", node_id))?;
+            let old = std::mem::replace(&mut node.data.name, name.to_string());
+            let mentions = scryer_core::rename_mentions(model, &old, name);
+            Ok(format!(
+                "Renamed '{}' to '{}' ({} mention(s) updated)",
+                old, name, mentions
+            ))
+        }
+        HintAction::ReverseEdge { edge_id } => {
+            let idx = model
+                .edges
+                .iter()
+                .position(|e| e.id == *edge_id)
+                .ok_or_else(|| format!("Edge '{}' not found", edge_id))?;
+            let edge = model.edges.remove(idx);
+            let new_id = scryer_core::next_edge_id(model, &edge.target, &edge.source);
+            let summary = format!(
+                "Reversed '{}': now {} -> {} ('{}')",
+                edge.id, edge.target, edge.source, new_id
+            );
+            model.edges.insert(
+                idx,
+                scryer_core::C4Edge {
+                    id: new_id,
+                    source: edge.target,
+                    target: edge.source,
+                    data: edge.data,
+                },
+            );
+            Ok(summary)
+        }
+    }
+}
+
 /// Run LLM hint analysis on a diagram via API. Returns empty vec on failure.
 pub async fn get_hints(
     model: &scryer_core::C4ModelData,
     settings: &scryer_core::AiSettings,
 ) -> Vec<Hint> {
+    try_get_hints(model, settings).await.unwrap_or_else(|e| {
+        tracing::warn!("generate error: {}", e);
+        vec![]
+    })
+}
+
+/// Like [`get_hints`], but hands back the provider error instead of an empty
+/// list, so callers can tell "no hints" from "the request failed".
+pub async fn try_get_hints(
+    model: &scryer_core::C4ModelData,
+    settings: &scryer_core::AiSettings,
+) -> Result<Vec<Hint>, String> {
     let system = prompt::system_prompt();
     let user_msg = prompt::user_message(model);
 
-    eprintln!("[scryer-suggest] sending to {} ({})", settings.provider, settings.model);
+    tracing::info!(provider = %settings.provider, model = %settings.model, "requesting hints");
 
-    match engine::generate(settings, &system, &user_msg).await {
-        Ok(raw) => {
-            eprintln!("[scryer-suggest] raw LLM output:\n{}", raw);
-            let hints = parse::parse_llm_output(&raw, model);
-            eprintln!("[scryer-suggest] parsed {} hints", hints.len());
-            hints
-        }
-        Err(e) => {
-            eprintln!("[scryer-suggest] generate error: {}", e);
-            vec![]
-        }
+    let raw = engine::generate(settings, &system, &user_msg).await?;
+    tracing::debug!("raw LLM output:\n{}", raw);
+    let hints = parse::parse_llm_output(&raw, model);
+    tracing::info!("parsed {} hints", hints.len());
+    Ok(hints)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> scryer_core::C4ModelData {
+        serde_json::from_str(
+            r#"{
+                "nodes": [
+                    {"id": "node-1", "position": {"x": 0, "y": 0},
+                     "data": {"name": "Web App", "description": "", "kind": "system"}},
+                    {"id": "node-2", "position": {"x": 0, "y": 0},
+                     "data": {"name": "Postgres + Redis", "description": "", "kind": "container"},
+                     "parentId": "node-1"}
+                ],
+                "edges": []
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn mock_backend_round_trips_hints() {
+        std::env::set_var(
+            "SCRYER_MOCK_LLM_RESPONSE",
+            "Here you go:\n```json\n[{\"node\":\"postgres + redis\",\"msg\":\"Rename to Data Store\",\"sev\":\"w\",\"rename\":\"Data Store\"},\
+             {\"node\":\"Nope\",\"msg\":\"dropped\",\"sev\":\"i\"}]\n```",
+        );
+        let settings = scryer_core::AiSettings {
+            provider: "mock".to_string(),
+            model: "fixture".to_string(),
+            ..Default::default()
+        };
+        assert!(scryer_core::ai_configured(&settings));
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let hints = rt.block_on(get_hints(&model(), &settings));
+
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].node_id, "node-2");
+        assert_eq!(hints[0].message, "Rename to Data Store");
+        assert!(matches!(hints[0].severity, HintSeverity::Warning));
+        assert_eq!(
+            hints[0].action,
+            Some(HintAction::Rename {
+                name: "Data Store".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn provider_errors_are_surfaced() {
+        let settings = scryer_core::AiSettings {
+            provider: "bogus".to_string(),
+            model: "fixture".to_string(),
+            ..Default::default()
+        };
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let err = rt.block_on(try_get_hints(&model(), &settings)).unwrap_err();
+
+        assert!(err.contains("unknown provider: bogus"), "{err}");
+        assert!(rt.block_on(get_hints(&model(), &settings)).is_empty());
+    }
+
+    #[test]
+    fn rename_action_rewrites_mentions() {
+        let mut model = model();
+        model.nodes[0].data.description = "Caches in @[Postgres + Redis]".to_string();
+        model.flows = serde_json::from_value(serde_json::json!([
+            { "id": "flow-1", "name": "Checkout", "steps": [
+                { "id": "step-1", "description": "@[Web App] writes to @[Postgres + Redis]" },
+            ] },
+        ]))
+        .unwrap();
+        let action = HintAction::Rename {
+            name: "Data Store".to_string(),
+        };
+
+        apply_action(&mut model, "node-2", &action).unwrap();
+        assert_eq!(model.nodes[1].data.name, "Data Store");
+        assert_eq!(model.nodes[0].data.description, "Caches in @[Data Store]");
+        assert_eq!(
+            model.flows[0].steps[0].description.as_deref(),
+            Some("@[Web App] writes to @[Data Store]")
+        );
     }
 }
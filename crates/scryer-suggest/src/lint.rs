@@ -0,0 +1,208 @@
+//! Offline checks that need no AI: cheap, conservative heuristics for rules
+//! that can be partly mechanized. They only flag edges that are clearly
+//! backwards, so a clean lint doesn't mean the model follows every rule.
+
+use std::collections::{HashMap, HashSet};
+
+use scryer_core::{C4Kind, C4ModelData, C4Node, C4Shape};
+
+use crate::{Hint, HintAction, HintSeverity};
+
+/// Technologies that mark a node as a data store.
+const DATA_STORE_TECH: &[&str] = &[
+    "postgres",
+    "mysql",
+    "mariadb",
+    "sqlite",
+    "mongo",
+    "redis",
+    "dynamodb",
+    "cassandra",
+    "oracle",
+    "sql server",
+    "mssql",
+    "cockroach",
+    "couchdb",
+    "firestore",
+    "neo4j",
+    "clickhouse",
+    "database",
+];
+
+/// Verbs in the label of an edge that reads or writes data. Matched as whole
+/// words, allowing "-s", "-es", "-ing", "-ed", and "-ies" endings, so
+/// "ready" or "payload" don't count.
+const DATA_ACCESS_VERBS: &[&str] = &[
+    "read", "write", "query", "store", "fetch", "save", "persist", "load", "select", "insert",
+    "update", "delete", "lookup",
+];
+
+/// Label fragments of a system reaching out to a person, which rule 2 allows.
+/// Matched anywhere in the label: a looser match here only suppresses hints.
+const NOTIFY_VERBS: &[&str] = &[
+    "send", "notif", "mail", "alert", "push", "deliver", "sms", "remind", "message",
+];
+
+fn is_data_store(node: &C4Node) -> bool {
+    if node.data.shape == Some(C4Shape::Cylinder) {
+        return true;
+    }
+    let tech = node.data.technology.as_deref().unwrap_or("").to_lowercase();
+    DATA_STORE_TECH.iter().any(|t| tech.contains(t))
+}
+
+fn has_any(text: &str, words: &[&str]) -> bool {
+    words.iter().any(|w| text.contains(w))
+}
+
+/// Whether `text` has a word that is one of `verbs` or an inflection of it
+/// ("writes", "writing", "stored", "queries"), or reads "look(s) up".
+fn has_verb(text: &str, verbs: &[&str]) -> bool {
+    let words: Vec<&str> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let inflects = |word: &str, verb: &str| {
+        let ending = |stem: &str| word.strip_prefix(stem);
+        matches!(ending(verb), Some("" | "s" | "es" | "ing" | "ed"))
+            || matches!(verb.strip_suffix('e').and_then(ending), Some("ing" | "ed"))
+            || verb.strip_suffix('y').and_then(ending) == Some("ies")
+    };
+    words.iter().any(|w| verbs.iter().any(|v| inflects(w, v)))
+        || words
+            .windows(2)
+            .any(|p| matches!(p[0], "look" | "looks" | "looking") && p[1] == "up")
+}
+
+/// Rule 2 (arrows point from initiator to provider) checks:
+/// - an edge into a person should start at the person, unless it reads as
+///   a notification ("sends email to") or the person already has an edge back;
+/// - a data store shouldn't be the source of a read/write edge to something
+///   that isn't a data store.
+///
+/// Each hint carries a `reverseEdge` action.
+pub fn lint(model: &C4ModelData) -> Vec<Hint> {
+    let by_id: HashMap<&str, &C4Node> = model.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let pairs: HashSet<(&str, &str)> = model
+        .edges
+        .iter()
+        .map(|e| (e.source.as_str(), e.target.as_str()))
+        .collect();
+
+    let mut hints = Vec::new();
+    for edge in &model.edges {
+        let (Some(source), Some(target)) = (
+            by_id.get(edge.source.as_str()),
+            by_id.get(edge.target.as_str()),
+        ) else {
+            continue;
+        };
+        let data = edge.data.as_ref();
+        let label = data.map(|d| d.label.to_lowercase()).unwrap_or_default();
+        let method = data
+            .and_then(|d| d.method.as_deref())
+            .unwrap_or("")
+            .to_lowercase();
+        let shown = data
+            .map(|d| d.label.as_str())
+            .filter(|l| !l.is_empty())
+            .unwrap_or("(unlabeled)");
+        let reversed = pairs.contains(&(edge.target.as_str(), edge.source.as_str()));
+
+        if target.data.kind == C4Kind::Person
+            && source.data.kind != C4Kind::Person
+            && !reversed
+            && !has_any(&label, NOTIFY_VERBS)
+        {
+            hints.push(Hint {
+                node_id: target.id.clone(),
+                message: format!(
+                    "Edge \"{}\" points from {} to {}. People initiate interactions, so it should run {} → {}.",
+                    shown, source.data.name, target.data.name, target.data.name, source.data.name
+                ),
+                severity: HintSeverity::Warning,
+                action: Some(HintAction::ReverseEdge {
+                    edge_id: edge.id.clone(),
+                }),
+            });
+            continue;
+        }
+
+        if is_data_store(source)
+            && !is_data_store(target)
+            && target.data.kind != C4Kind::Person
+            && !reversed
+            && (has_verb(&label, DATA_ACCESS_VERBS) || method.contains("sql"))
+        {
+            hints.push(Hint {
+                node_id: source.id.clone(),
+                message: format!(
+                    "Edge \"{}\" points from {} to {}, but {} is a data store that {} reads or writes. Arrows point from the initiator, so it should run {} → {}.",
+                    shown,
+                    source.data.name,
+                    target.data.name,
+                    source.data.name,
+                    target.data.name,
+                    target.data.name,
+                    source.data.name
+                ),
+                severity: HintSeverity::Warning,
+                action: Some(HintAction::ReverseEdge {
+                    edge_id: edge.id.clone(),
+                }),
+            });
+        }
+    }
+    hints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(edges: serde_json::Value) -> C4ModelData {
+        serde_json::from_value(serde_json::json!({
+            "nodes": [
+                { "id": "node-1", "data": { "name": "Customer", "kind": "person" } },
+                { "id": "node-2", "data": { "name": "Shop", "kind": "system" } },
+                { "id": "node-3", "parentId": "node-2", "data": { "name": "API", "kind": "container" } },
+                { "id": "node-4", "parentId": "node-2", "data": { "name": "DB", "kind": "container", "technology": "PostgreSQL" } },
+            ],
+            "edges": edges,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn backwards_data_access_is_flagged() {
+        let hints = lint(&model(serde_json::json!([
+            { "id": "edge-node-4-node-3", "source": "node-4", "target": "node-3", "data": { "label": "reads orders" } },
+        ])));
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].node_id, "node-4");
+        assert_eq!(
+            hints[0].action,
+            Some(HintAction::ReverseEdge {
+                edge_id: "edge-node-4-node-3".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn data_access_verbs_match_whole_words() {
+        let hints = lint(&model(serde_json::json!([
+            { "id": "edge-node-4-node-3", "source": "node-4", "target": "node-3", "data": { "label": "signals ready" } },
+            { "id": "edge-node-4-node-3-2", "source": "node-4", "target": "node-3", "data": { "label": "streams payload downloads" } },
+        ])));
+        assert!(hints.is_empty(), "{hints:?}");
+    }
+
+    #[test]
+    fn notifications_and_forward_edges_pass() {
+        let hints = lint(&model(serde_json::json!([
+            { "id": "edge-node-2-node-1", "source": "node-2", "target": "node-1", "data": { "label": "sends receipts" } },
+            { "id": "edge-node-3-node-4", "source": "node-3", "target": "node-4", "data": { "label": "reads from", "method": "SQL" } },
+        ])));
+        assert!(hints.is_empty(), "{hints:?}");
+    }
+}
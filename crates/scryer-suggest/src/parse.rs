@@ -1,6 +1,6 @@
 use scryer_core::C4ModelData;
 
-use crate::{Hint, HintSeverity};
+use crate::{Hint, HintAction, HintSeverity};
 
 
 #[derive(serde::Deserialize)]
@@ -8,6 +8,8 @@ struct LlmHint {
     node: String,
     msg: String,
     sev: Option<String>,
+    /// New name, when the hint is a straight rename of a node.
+    rename: Option<String>,
 }
 
 /// Parse raw LLM output into Hint structs, matching node names back to IDs.
@@ -31,10 +33,12 @@ pub fn parse_llm_output(raw: &str, model: &C4ModelData) -> Vec<Hint> {
         .into_iter()
         .filter_map(|lh| {
             let node_id = resolve_node_id(&lh.node, model)?;
+            let action = rename_action(lh.rename.as_deref(), &node_id, model);
             Some(Hint {
                 node_id,
                 message: lh.msg,
                 severity: map_severity(lh.sev.as_deref()),
+                action,
             })
         })
         .collect()
@@ -132,6 +136,19 @@ fn resolve_node_id(name: &str, model: &C4ModelData) -> Option<String> {
     None
 }
 
+/// Turn the LLM's `rename` into an action, but only for nodes (not flow
+/// steps) and only when it actually changes the name.
+fn rename_action(rename: Option<&str>, node_id: &str, model: &C4ModelData) -> Option<HintAction> {
+    let name = rename?.trim();
+    let node = model.nodes.iter().find(|n| n.id == node_id)?;
+    if name.is_empty() || name == node.data.name {
+        return None;
+    }
+    Some(HintAction::Rename {
+        name: name.to_string(),
+    })
+}
+
 fn map_severity(s: Option<&str>) -> HintSeverity {
     match s {
         Some("w") => HintSeverity::Warning,
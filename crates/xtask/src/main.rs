@@ -1,27 +1,54 @@
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use sha2::{Digest, Sha256};
+
 fn main() {
     let args: Vec<String> = env::args().collect();
     let task = args.get(1).map(|s| s.as_str()).unwrap_or("");
     let debug = args.iter().any(|a| a == "--debug");
+    let target = args
+        .iter()
+        .position(|a| a == "--target")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
 
     match task {
-        "build-sidecar" => build_sidecar(!debug),
+        "build-sidecar" => {
+            if let Err(e) = build_sidecar(!debug, target) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+        "build-sidecars" => {
+            let triples: Vec<String> = args[2..]
+                .iter()
+                .filter(|a| !a.starts_with("--"))
+                .cloned()
+                .collect();
+            build_sidecars(!debug, triples);
+        }
+        "verify-sidecars" | "--verify" => verify_sidecars(),
         _ => {
-            eprintln!("Usage: cargo run -p xtask -- build-sidecar [--debug]");
+            eprintln!("Usage: cargo run -p xtask -- build-sidecar [--debug] [--target <triple>]");
+            eprintln!("       cargo run -p xtask -- build-sidecars [--debug] [<triple>...]");
+            eprintln!("       cargo run -p xtask -- --verify");
             std::process::exit(1);
         }
     }
 }
 
-fn build_sidecar(release: bool) {
-    let triple = get_target_triple();
+/// Build scryer-mcp for `target` (the host triple when omitted) and copy it
+/// into `src-tauri/binaries/` with the `-<triple>` suffix Tauri expects.
+/// Returns the path of the copied binary.
+fn build_sidecar(release: bool, target: Option<String>) -> Result<PathBuf, String> {
+    let cross = target.is_some();
+    let triple = target.unwrap_or_else(get_target_triple);
     let root = workspace_root();
     let out_dir = root.join("src-tauri").join("binaries");
 
-    std::fs::create_dir_all(&out_dir).expect("failed to create binaries dir");
+    std::fs::create_dir_all(&out_dir).map_err(|e| format!("failed to create binaries dir: {e}"))?;
 
     let profile = if release { "release" } else { "debug" };
     println!("Building scryer-mcp ({profile}) for {triple}...");
@@ -30,17 +57,20 @@ fn build_sidecar(release: bool) {
     if release {
         args.push("--release");
     }
+    if cross {
+        args.extend(["--target", triple.as_str()]);
+    }
 
     let status = Command::new("cargo")
         .args(&args)
         .status()
-        .expect("failed to run cargo build");
+        .map_err(|e| format!("failed to run cargo build: {e}"))?;
 
     if !status.success() {
-        std::process::exit(status.code().unwrap_or(1));
+        return Err(format!("cargo build failed for {triple}"));
     }
 
-    let (src_name, dst_name) = if cfg!(windows) {
+    let (src_name, dst_name) = if triple.contains("windows") {
         (
             "scryer-mcp.exe".to_string(),
             format!("scryer-mcp-{triple}.exe"),
@@ -49,18 +79,165 @@ fn build_sidecar(release: bool) {
         ("scryer-mcp".to_string(), format!("scryer-mcp-{triple}"))
     };
 
-    let src = root.join("target").join(profile).join(&src_name);
+    // Cargo only nests output under the triple when --target is passed
+    let mut src = root.join("target");
+    if cross {
+        src.push(&triple);
+    }
+    let src = src.join(profile).join(&src_name);
     let dst = out_dir.join(&dst_name);
 
-    std::fs::copy(&src, &dst).unwrap_or_else(|e| {
-        panic!(
-            "failed to copy {} -> {}: {e}",
-            src.display(),
-            dst.display()
-        );
-    });
+    std::fs::copy(&src, &dst)
+        .map_err(|e| format!("failed to copy {} -> {}: {e}", src.display(), dst.display()))?;
 
     println!("Sidecar copied to {}", dst.display());
+
+    let checksum = write_checksum(&dst)?;
+    println!("Checksum written to {}", checksum.display());
+    Ok(dst)
+}
+
+fn sha256_hex(path: &Path) -> Result<String, String> {
+    let bytes =
+        std::fs::read(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+/// Write `<path>.sha256` in `sha256sum` format (`<hex>  <file name>`).
+fn write_checksum(path: &Path) -> Result<PathBuf, String> {
+    let hex = sha256_hex(path)?;
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let mut checksum = path.as_os_str().to_owned();
+    checksum.push(".sha256");
+    let checksum = PathBuf::from(checksum);
+    std::fs::write(&checksum, format!("{hex}  {file_name}\n"))
+        .map_err(|e| format!("failed to write {}: {e}", checksum.display()))?;
+    Ok(checksum)
+}
+
+/// Recompute the hash of every sidecar in `src-tauri/binaries/` that has a
+/// `.sha256` next to it. Exits non-zero on any mismatch or missing binary.
+fn verify_sidecars() {
+    let dir = workspace_root().join("src-tauri").join("binaries");
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", dir.display()))
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|x| x == "sha256"))
+        .collect();
+    entries.sort();
+
+    let mut bad = 0;
+    for checksum in &entries {
+        let binary = checksum.with_extension("");
+        let expected = std::fs::read_to_string(checksum)
+            .ok()
+            .and_then(|s| s.split_whitespace().next().map(|h| h.to_ascii_lowercase()));
+        let name = binary.file_name().unwrap_or_default().to_string_lossy();
+        match (expected, sha256_hex(&binary)) {
+            (Some(expected), Ok(actual)) if expected == actual => println!("OK       {name}"),
+            (Some(_), Ok(_)) => {
+                println!("MISMATCH {name}");
+                bad += 1;
+            }
+            (None, _) => {
+                println!("INVALID  {}", checksum.display());
+                bad += 1;
+            }
+            (_, Err(e)) => {
+                println!("MISSING  {name} ({e})");
+                bad += 1;
+            }
+        }
+    }
+
+    if entries.is_empty() {
+        println!("No checksums found in {}", dir.display());
+    }
+    if bad > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Build the sidecar for each of `triples`, or for every triple listed in
+/// `crates/xtask/sidecar-targets.txt` when none are given. Triples whose
+/// rustup target isn't installed are skipped with a warning. Exits non-zero
+/// if any build fails.
+fn build_sidecars(release: bool, triples: Vec<String>) {
+    let triples = if triples.is_empty() {
+        read_target_manifest()
+    } else {
+        triples
+    };
+    let installed = installed_targets();
+
+    let mut built = Vec::new();
+    let mut skipped = Vec::new();
+    let mut failed = Vec::new();
+    for triple in triples {
+        if installed.as_ref().is_some_and(|t| !t.contains(&triple)) {
+            eprintln!("warning: skipping {triple} (run `rustup target add {triple}`)");
+            skipped.push(triple);
+            continue;
+        }
+        match build_sidecar(release, Some(triple.clone())) {
+            Ok(_) => built.push(triple),
+            Err(e) => {
+                eprintln!("{e}");
+                failed.push(triple);
+            }
+        }
+    }
+
+    println!();
+    println!("Built:   {}", summary(&built));
+    println!("Skipped: {}", summary(&skipped));
+    println!("Failed:  {}", summary(&failed));
+    if !failed.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn summary(triples: &[String]) -> String {
+    if triples.is_empty() {
+        "none".to_string()
+    } else {
+        triples.join(", ")
+    }
+}
+
+/// Release triples, one per line. Blank lines and `#` comments are ignored.
+fn read_target_manifest() -> Vec<String> {
+    let path = workspace_root()
+        .join("crates")
+        .join("xtask")
+        .join("sidecar-targets.txt");
+    let contents = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+    contents
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or("").trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect()
+}
+
+/// Targets installed via rustup, or `None` if rustup isn't available (in
+/// which case every build is attempted).
+fn installed_targets() -> Option<Vec<String>> {
+    let out = Command::new("rustup")
+        .args(["target", "list", "--installed"])
+        .output()
+        .ok()?;
+    if !out.status.success() {
+        return None;
+    }
+    Some(
+        String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .map(|l| l.trim().to_string())
+            .collect(),
+    )
 }
 
 fn get_target_triple() -> String {
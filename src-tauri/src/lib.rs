@@ -1,6 +1,7 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 
 use notify::{recommended_watcher, EventKind, RecursiveMode, Watcher};
 use tauri::{Emitter, Manager, path::BaseDirectory};
@@ -43,6 +44,10 @@ fn ensure_full_path() {
 /// Managed state wrapping the AI settings.
 struct SettingsState(Arc<Mutex<scryer_core::AiSettings>>);
 
+/// Why `settings.json` couldn't be loaded, if it couldn't. Cleared on the
+/// next successful save or reload.
+struct SettingsError(Arc<Mutex<Option<String>>>);
+
 /// Managed state for the ACP runtime (agent orchestration).
 struct AcpState(Mutex<Option<scryer_acp::AcpRuntime>>);
 
@@ -56,6 +61,121 @@ struct WatcherState {
     project: Option<(PathBuf, notify::RecommendedWatcher)>,
 }
 
+/// Content hashes of what the UI last wrote, keyed by model ref string.
+/// Watchers drop an event only if the file on disk still matches, so an
+/// external write landing right after a UI save is never swallowed.
+#[derive(Clone, Default)]
+struct SelfWrites(Arc<Mutex<HashMap<String, u64>>>);
+
+impl SelfWrites {
+    fn record(&self, key: &str, data: &str) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), content_hash(data.as_bytes()));
+    }
+
+    /// Whether `path` holds exactly what the UI last wrote for `key`.
+    /// A match consumes the entry.
+    fn is_own_write(&self, key: &str, path: &std::path::Path) -> bool {
+        let mut map = self.0.lock().unwrap();
+        let Some(&expected) = map.get(key) else {
+            return false;
+        };
+        let Ok(bytes) = scryer_core::read_model_file(path).map(String::into_bytes) else {
+            return false;
+        };
+        if content_hash(&bytes) == expected {
+            map.remove(key);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn content_hash(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Quiet period before a burst of watcher events is reported to the frontend.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Coalesces bursts of watcher events per key (atomic writes and editors fire
+/// several events per save) so the frontend reloads once per burst.
+#[derive(Clone, Default)]
+struct Debouncer(Arc<Mutex<HashMap<String, Instant>>>);
+
+impl Debouncer {
+    /// Record an event for `key`. `emit` runs once the key has been quiet for
+    /// `WATCH_DEBOUNCE`; further events in the meantime extend the wait.
+    fn trigger(&self, key: String, emit: impl FnOnce() + Send + 'static) {
+        let already_pending = self
+            .0
+            .lock()
+            .unwrap()
+            .insert(key.clone(), Instant::now())
+            .is_some();
+        if already_pending {
+            return;
+        }
+        let pending = self.0.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(WATCH_DEBOUNCE);
+            let mut map = pending.lock().unwrap();
+            let Some(last) = map.get(&key) else { return };
+            if last.elapsed() >= WATCH_DEBOUNCE {
+                map.remove(&key);
+                drop(map);
+                emit();
+                return;
+            }
+        });
+    }
+}
+
+/// A model as `read_model` returns it: the JSON plus the etag of the file
+/// it was read from, to pass back as `expected_etag` when writing.
+#[derive(Clone, serde::Serialize)]
+struct ModelRead {
+    data: String,
+    etag: String,
+}
+
+/// What `read_model` last returned, keyed by model ref string and stamped
+/// with the file's mtime. An entry is only served while the mtime still
+/// matches; watchers also drop entries on external changes in case the
+/// filesystem's mtime resolution hides an edit.
+#[derive(Clone, Default)]
+struct ModelCache(Arc<Mutex<HashMap<String, (SystemTime, ModelRead)>>>);
+
+impl ModelCache {
+    fn get(&self, key: &str, path: &std::path::Path) -> Option<ModelRead> {
+        let mtime = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+        let map = self.0.lock().unwrap();
+        let (cached_at, read) = map.get(key)?;
+        (*cached_at == mtime).then(|| read.clone())
+    }
+
+    fn store(&self, key: &str, path: &std::path::Path, read: &ModelRead) {
+        let mut map = self.0.lock().unwrap();
+        match std::fs::metadata(path).and_then(|m| m.modified()) {
+            Ok(mtime) => {
+                map.insert(key.to_string(), (mtime, read.clone()));
+            }
+            Err(_) => {
+                map.remove(key);
+            }
+        }
+    }
+
+    fn invalidate(&self, key: &str) {
+        self.0.lock().unwrap().remove(key);
+    }
+}
 
 #[tauri::command]
 fn list_models() -> Result<serde_json::Value, String> {
@@ -70,6 +190,8 @@ fn watch_project(
     ref_str: String,
     app: tauri::AppHandle,
     watcher_state: tauri::State<'_, Mutex<WatcherState>>,
+    self_writes: tauri::State<'_, SelfWrites>,
+    cache: tauri::State<'_, ModelCache>,
 ) -> Result<(), String> {
     let model_ref = scryer_core::ModelRef::parse(&ref_str);
     let mut state = watcher_state.lock().unwrap();
@@ -94,6 +216,9 @@ fn watch_project(
         let _ = std::fs::create_dir_all(&dir);
         let handle = app.clone();
         let ref_string = ref_str.clone();
+        let debouncer = Debouncer::default();
+        let self_writes = self_writes.inner().clone();
+        let cache = cache.inner().clone();
         let mut watcher = recommended_watcher(move |res: Result<notify::Event, notify::Error>| {
             let Ok(event) = res else { return };
             if !matches!(
@@ -103,17 +228,28 @@ fn watch_project(
                 return;
             }
             for path in &event.paths {
-                if path.extension().map_or(true, |e| e != "scry") {
-                    continue;
-                }
-                let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                let Some(stem) = path
+                    .file_name()
+                    .and_then(|s| s.to_str())
+                    .and_then(scryer_core::model_file_stem)
+                else {
                     continue;
                 };
-                if stem.ends_with(".baseline") || stem.starts_with(".tmp") {
+                if stem.starts_with(".tmp") {
                     continue;
                 }
                 // Emit the ref string so the frontend can match against currentModel
-                let _ = handle.emit("model-changed", ref_string.clone());
+                let handle = handle.clone();
+                let ref_string = ref_string.clone();
+                let self_writes = self_writes.clone();
+                let cache = cache.clone();
+                let path = path.clone();
+                debouncer.trigger(ref_string.clone(), move || {
+                    if !self_writes.is_own_write(&ref_string, &path) {
+                        cache.invalidate(&ref_string);
+                        let _ = handle.emit("model-changed", ref_string);
+                    }
+                });
             }
         })
         .map_err(|e| e.to_string())?;
@@ -162,14 +298,21 @@ fn rename_template(old_name: String, new_name: String) -> Result<(), String> {
         return Err("Name cannot be empty".to_string());
     }
     let dir = scryer_core::models_dir();
-    let old_path = dir.join(format!("{}.scry", old_name));
-    let new_path = dir.join(format!("{}.scry", new_name));
+    let old_ref = scryer_core::ModelRef::Global(old_name.clone());
+    let new_ref = scryer_core::ModelRef::Global(new_name.clone());
+    let old_path = old_ref.stored_path();
     if !old_path.exists() {
         return Err(format!("Template '{}' not found", old_name));
     }
-    if new_path.exists() {
+    if new_ref.stored_path().exists() {
         return Err(format!("Template '{}' already exists", new_name));
     }
+    // Keep the storage format: a compressed template stays compressed
+    let new_path = if old_path == old_ref.gz_path() {
+        new_ref.gz_path()
+    } else {
+        new_ref.model_path()
+    };
     std::fs::rename(&old_path, &new_path).map_err(|e| e.to_string())?;
     // Rename baseline too if it exists
     let old_baseline = dir.join(format!("{}.baseline.scry", old_name));
@@ -177,13 +320,24 @@ fn rename_template(old_name: String, new_name: String) -> Result<(), String> {
     if old_baseline.exists() {
         let _ = std::fs::rename(&old_baseline, &new_baseline);
     }
+    let old_attachments = dir.join(format!("{}.attachments", old_name));
+    let new_attachments = dir.join(format!("{}.attachments", new_name));
+    if old_attachments.exists() {
+        let _ = std::fs::rename(&old_attachments, &new_attachments);
+    }
     Ok(())
 }
 
 #[tauri::command]
-fn read_model(name: String) -> Result<String, String> {
+fn read_model(name: String, cache: tauri::State<'_, ModelCache>) -> Result<ModelRead, String> {
     let model_ref = scryer_core::ModelRef::parse(&name);
-    let raw = scryer_core::read_model_raw_at(&model_ref)?;
+    let path = model_ref.stored_path();
+    if let Some(read) = cache.get(&name, &path) {
+        let _ = scryer_core::touch_recent_model(&name);
+        return Ok(read);
+    }
+    let (raw, etag) = scryer_core::read_model_raw_tagged_at(&model_ref)?;
+    let _ = scryer_core::touch_recent_model(&name);
     // Migrate old kind values ("function", "unit", "member") → "operation"
     // and ensure operation nodes have type "operation" (was "c4")
     let mut val: serde_json::Value = serde_json::from_str(&raw).map_err(|e| e.to_string())?;
@@ -210,25 +364,101 @@ fn read_model(name: String) -> Result<String, String> {
             }
         }
     }
-    if migrated {
-        let updated = serde_json::to_string_pretty(&val).map_err(|e| e.to_string())?;
-        scryer_core::write_model_raw_at(&model_ref, &updated)?;
-        Ok(updated)
+    let read = if migrated {
+        let data = serde_json::to_string_pretty(&val).map_err(|e| e.to_string())?;
+        let etag = scryer_core::write_model_raw_if_match_at(&model_ref, &data, Some(&etag))?;
+        ModelRead { data, etag }
     } else {
-        Ok(raw)
+        ModelRead { data: raw, etag }
+    };
+    cache.store(&name, &model_ref.stored_path(), &read);
+    Ok(read)
+}
+
+#[tauri::command]
+fn search_models(query: String) -> Vec<scryer_core::SearchHit> {
+    scryer_core::search_models(&query)
+}
+
+#[tauri::command]
+fn get_recent_models() -> Vec<scryer_core::RecentModel> {
+    scryer_core::recent_models()
+}
+
+/// Write a model, with group membership normalized as for typed writes.
+/// With `expected_etag` (from `read_model` or an earlier write), the write
+/// is refused with a "Conflict: …" error if the file changed on disk since.
+/// Returns the etag of what was written.
+#[tauri::command]
+fn write_model(
+    name: String,
+    data: String,
+    expected_etag: Option<String>,
+    self_writes: tauri::State<'_, SelfWrites>,
+    cache: tauri::State<'_, ModelCache>,
+) -> Result<String, String> {
+    let model_ref = scryer_core::ModelRef::parse(&name);
+    let data = scryer_core::normalize_groups_raw(&data)?;
+    let etag =
+        scryer_core::write_model_raw_if_match_at(&model_ref, &data, expected_etag.as_deref())?;
+    // The file holds attachment paths rather than inlined images, so it can't
+    // stand in for what read_model returns; let the next read refill the cache.
+    cache.invalidate(&name);
+    // Hash what actually landed on disk — attachments are externalized on write
+    if let Ok(written) = scryer_core::read_model_file(&model_ref.stored_path()) {
+        self_writes.record(&name, &written);
     }
+    Ok(etag)
 }
 
+/// Render a model at the given level as a standalone SVG document.
 #[tauri::command]
-fn write_model(name: String, data: String) -> Result<(), String> {
+fn export_svg(name: String, level: scryer_core::StartingLevel) -> Result<String, String> {
     let model_ref = scryer_core::ModelRef::parse(&name);
-    scryer_core::write_model_raw_at(&model_ref, &data)
+    let model = scryer_core::read_model_at(&model_ref)?;
+    Ok(scryer_core::render::render_svg(&model, &level))
+}
+
+/// Copy a global model under a new name ("Save As").
+#[tauri::command]
+fn duplicate_model(
+    src: String,
+    dst: String,
+    self_writes: tauri::State<'_, SelfWrites>,
+) -> Result<(), String> {
+    let dst = dst
+        .trim()
+        .to_lowercase()
+        .replace(|c: char| !c.is_alphanumeric() && c != '-' && c != '_', "-");
+    if dst.is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    scryer_core::copy_model(&src, &dst)?;
+    let model_ref = scryer_core::ModelRef::Global(dst.clone());
+    if let Ok(written) = scryer_core::read_model_file(&model_ref.stored_path()) {
+        self_writes.record(&dst, &written);
+    }
+    Ok(())
+}
+
+/// Zip every global model into `path`. Returns the number of models archived.
+#[tauri::command]
+fn backup_models(path: String, include_baselines: bool) -> Result<usize, String> {
+    scryer_core::backup::backup_to(std::path::Path::new(&path), include_baselines)
+}
+
+/// Unpack a backup into the models directory. Name collisions are skipped
+/// (and reported) unless `overwrite` is set.
+#[tauri::command]
+fn restore_models(path: String, overwrite: bool) -> Result<serde_json::Value, String> {
+    let report = scryer_core::backup::restore_from(std::path::Path::new(&path), overwrite)?;
+    serde_json::to_value(report).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 fn delete_model(name: String) -> Result<(), String> {
     let model_ref = scryer_core::ModelRef::parse(&name);
-    scryer_core::delete_model_at(&model_ref)
+    scryer_core::delete_model_at(&model_ref).map_err(String::from)
 }
 
 #[tauri::command]
@@ -250,6 +480,72 @@ fn list_templates(app: tauri::AppHandle) -> Result<Vec<String>, String> {
     Ok(names)
 }
 
+/// Gallery info for a bundled template, from its `<name>.meta.json` sidecar
+/// when present, otherwise derived from the template itself.
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct TemplateMeta {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    node_count: usize,
+}
+
+#[tauri::command]
+fn list_templates_meta(app: tauri::AppHandle) -> Result<Vec<TemplateMeta>, String> {
+    let dir = app
+        .path()
+        .resolve("templates", BaseDirectory::Resource)
+        .map_err(|e| e.to_string())?;
+    let mut metas = Vec::new();
+    for name in list_templates(app)? {
+        let model = std::fs::read_to_string(dir.join(format!("{}.scry", name)))
+            .ok()
+            .and_then(|raw| serde_json::from_str::<scryer_core::C4ModelData>(&raw).ok());
+        let mut meta: TemplateMeta =
+            std::fs::read_to_string(dir.join(format!("{}.meta.json", name)))
+                .ok()
+                .and_then(|raw| serde_json::from_str(&raw).ok())
+                .unwrap_or_default();
+        if meta.title.is_empty() {
+            meta.title = name
+                .split(['-', '_'])
+                .filter(|w| !w.is_empty())
+                .map(|w| {
+                    let mut chars = w.chars();
+                    chars
+                        .next()
+                        .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+        if meta.description.is_empty() {
+            // Fall back to the first internal system's description
+            meta.description = model
+                .as_ref()
+                .and_then(|m| {
+                    m.nodes.iter().find(|n| {
+                        n.data.kind == scryer_core::C4Kind::System && n.data.external != Some(true)
+                    })
+                })
+                .map(|n| n.data.description.clone())
+                .unwrap_or_default();
+        }
+        meta.node_count = model.map(|m| m.nodes.len()).unwrap_or(0);
+        meta.name = name;
+        metas.push(meta);
+    }
+    Ok(metas)
+}
+
 #[tauri::command]
 fn load_template(app: tauri::AppHandle, name: String) -> Result<String, String> {
     let path = app.path().resolve(format!("templates/{}.scry", name), BaseDirectory::Resource)
@@ -257,9 +553,42 @@ fn load_template(app: tauri::AppHandle, name: String) -> Result<String, String>
     std::fs::read_to_string(&path).map_err(|e| e.to_string())
 }
 
+/// Create a global model from a bundled template in one step, so the watcher
+/// never sees the new file before it's registered as our own write. Returns
+/// the new model's JSON.
 #[tauri::command]
-fn get_ai_settings(state: tauri::State<'_, SettingsState>) -> Result<serde_json::Value, String> {
+fn create_from_template(
+    app: tauri::AppHandle,
+    template: String,
+    new_name: String,
+    self_writes: tauri::State<'_, SelfWrites>,
+) -> Result<String, String> {
+    let new_name = new_name
+        .trim()
+        .to_lowercase()
+        .replace(|c: char| !c.is_alphanumeric() && c != '-' && c != '_', "-");
+    if new_name.is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    let model_ref = scryer_core::ModelRef::Global(new_name.clone());
+    if model_ref.stored_path().exists() {
+        return Err(format!("Model '{}' already exists", new_name));
+    }
+    let data = load_template(app, template)?;
+    scryer_core::write_model_raw_at(&model_ref, &data)?;
+    if let Ok(written) = scryer_core::read_model_file(&model_ref.stored_path()) {
+        self_writes.record(&new_name, &written);
+    }
+    scryer_core::read_model_raw_at(&model_ref).map_err(String::from)
+}
+
+#[tauri::command]
+fn get_ai_settings(
+    state: tauri::State<'_, SettingsState>,
+    load_error: tauri::State<'_, SettingsError>,
+) -> Result<serde_json::Value, String> {
     let settings = state.0.lock().unwrap().clone();
+    let load_error = load_error.0.lock().unwrap().clone();
     let configured = scryer_core::ai_configured(&settings);
     // Mask API key — only send whether it's set
     Ok(serde_json::json!({
@@ -267,15 +596,51 @@ fn get_ai_settings(state: tauri::State<'_, SettingsState>) -> Result<serde_json:
         "model": settings.model,
         "hasKey": !settings.api_key.is_empty(),
         "configured": configured,
+        "editorCommand": settings.editor_command,
+        "loadError": load_error,
     }))
 }
 
+/// Key under which settings writes are recorded in `SelfWrites`.
+const SETTINGS_KEY: &str = "settings.json";
+
+/// Write settings to disk and record the write so the watcher doesn't
+/// reload (and re-announce) our own save.
+fn persist_settings(
+    settings: &scryer_core::AiSettings,
+    self_writes: &SelfWrites,
+    load_error: &SettingsError,
+) -> Result<(), String> {
+    scryer_core::write_settings(settings)?;
+    *load_error.0.lock().unwrap() = None;
+    if let Ok(written) = std::fs::read_to_string(scryer_core::models_dir().join(SETTINGS_KEY)) {
+        self_writes.record(SETTINGS_KEY, &written);
+    }
+    Ok(())
+}
+
+/// Save the editor command template. Empty clears it back to auto-detect.
+#[tauri::command]
+fn save_editor_command(
+    command: String,
+    state: tauri::State<'_, SettingsState>,
+    self_writes: tauri::State<'_, SelfWrites>,
+    load_error: tauri::State<'_, SettingsError>,
+) -> Result<(), String> {
+    let mut settings = state.0.lock().unwrap();
+    let command = command.trim();
+    settings.editor_command = (!command.is_empty()).then(|| command.to_string());
+    persist_settings(&settings, &self_writes, &load_error)
+}
+
 #[tauri::command]
 fn save_ai_settings(
     provider: String,
     api_key: String,
     model: String,
     state: tauri::State<'_, SettingsState>,
+    self_writes: tauri::State<'_, SelfWrites>,
+    load_error: tauri::State<'_, SettingsError>,
 ) -> Result<(), String> {
     let mut settings = state.0.lock().unwrap();
     settings.provider = provider;
@@ -284,7 +649,7 @@ fn save_ai_settings(
     if !api_key.is_empty() {
         settings.api_key = api_key;
     }
-    scryer_core::write_settings(&settings)
+    persist_settings(&settings, &self_writes, &load_error)
 }
 
 #[tauri::command]
@@ -296,6 +661,28 @@ async fn fetch_models(provider: String, api_key: Option<String>, state: tauri::S
     scryer_suggest::models::fetch_models(&provider, &key).await
 }
 
+/// Check the given provider/model/key by sending a trivial prompt.
+/// An empty key falls back to the saved one, matching `save_ai_settings`.
+#[tauri::command]
+async fn test_ai_connection(
+    provider: String,
+    api_key: Option<String>,
+    model: String,
+    state: tauri::State<'_, SettingsState>,
+) -> Result<(), String> {
+    let api_key = match api_key {
+        Some(k) if !k.is_empty() => k,
+        _ => state.0.lock().unwrap().api_key.clone(),
+    };
+    let settings = scryer_core::AiSettings {
+        provider,
+        api_key,
+        model,
+        ..Default::default()
+    };
+    scryer_suggest::engine::test_connection(&settings).await
+}
+
 #[tauri::command]
 async fn get_hints(data: String, state: tauri::State<'_, SettingsState>) -> Result<String, String> {
     let settings = state.0.lock().unwrap().clone();
@@ -310,8 +697,49 @@ async fn get_hints(data: String, state: tauri::State<'_, SettingsState>) -> Resu
     serde_json::to_string(&hints).map_err(|e| e.to_string())
 }
 
+/// Offline rule checks that need no AI, run on every edit by the advisor.
+#[tauri::command]
+fn lint_model(data: String) -> Result<String, String> {
+    let model: scryer_core::C4ModelData = serde_json::from_str(&data).map_err(|e| e.to_string())?;
+    serde_json::to_string(&scryer_suggest::lint::lint(&model)).map_err(|e| e.to_string())
+}
+
+/// Open a file or directory with the OS default handler: open on macOS,
+/// start on Windows, xdg-open on Linux.
+fn open_with_system(path: &str) -> Result<(), String> {
+    if cfg!(target_os = "windows") {
+        std::process::Command::new("cmd")
+            .args(["/C", "start", "", path])
+            .stdin(std::process::Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to open {path}: {e}"))?;
+    } else {
+        let fallback = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+        std::process::Command::new(fallback)
+            .arg(path)
+            .stdin(std::process::Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to open {path}: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Reveal the global models directory (`~/.scryer`) in the file manager.
+#[tauri::command]
+fn open_models_dir() -> Result<(), String> {
+    let dir = scryer_core::models_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    open_with_system(&dir.to_string_lossy())
+}
+
 #[tauri::command]
-fn open_in_editor(file: String, line: Option<u32>, project_path: Option<String>) -> Result<(), String> {
+fn open_in_editor(
+    file: String,
+    line: Option<u32>,
+    column: Option<u32>,
+    project_path: Option<String>,
+    state: tauri::State<'_, SettingsState>,
+) -> Result<(), String> {
     // Resolve absolute path
     let path = {
         let p = PathBuf::from(&file);
@@ -332,6 +760,13 @@ fn open_in_editor(file: String, line: Option<u32>, project_path: Option<String>)
 
     let path_str = path.to_string_lossy();
 
+    // A user-configured template wins outright — no TUI filtering, since
+    // the user opted in explicitly
+    let template = state.0.lock().unwrap().editor_command.clone();
+    if let Some(template) = template {
+        return spawn_editor_template(&template, &path_str, line, column);
+    }
+
     // Resolve editor: $VISUAL → $EDITOR → auto-detect → fallback
     // Skip TUI editors — we're a GUI app, can't spawn them
     let is_tui = |name: &str| {
@@ -346,7 +781,10 @@ fn open_in_editor(file: String, line: Option<u32>, project_path: Option<String>)
         .filter(|v| !is_tui(v))
         .or_else(|| std::env::var("EDITOR").ok().filter(|v| !is_tui(v)))
         .or_else(|| {
-            ["code", "cursor", "zed", "zeditor", "subl"]
+            [
+                "code", "cursor", "zed", "zeditor", "subl",
+                "idea", "goland", "pycharm", "webstorm", "rustrover",
+            ]
                 .iter()
                 .find(|name| which::which(name).is_ok())
                 .map(|s| s.to_string())
@@ -354,24 +792,7 @@ fn open_in_editor(file: String, line: Option<u32>, project_path: Option<String>)
 
     let editor = match editor {
         Some(e) => e,
-        None => {
-            // Fallback: open on macOS, start on Windows, xdg-open on Linux
-            if cfg!(target_os = "windows") {
-                std::process::Command::new("cmd")
-                    .args(["/C", "start", "", &*path_str])
-                    .stdin(std::process::Stdio::null())
-                    .spawn()
-                    .map_err(|e| format!("Failed to open file: {e}"))?;
-            } else {
-                let fallback = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
-                std::process::Command::new(fallback)
-                    .arg(&*path_str)
-                    .stdin(std::process::Stdio::null())
-                    .spawn()
-                    .map_err(|e| format!("Failed to open file: {e}"))?;
-            }
-            return Ok(());
-        }
+        None => return open_with_system(&path_str),
     };
 
     // Extract the binary name for line-number format lookup
@@ -380,33 +801,42 @@ fn open_in_editor(file: String, line: Option<u32>, project_path: Option<String>)
         .map(|n| n.to_string_lossy().to_string())
         .unwrap_or_else(|| editor.clone());
 
+    // `path:line[:col]` — column is only meaningful alongside a line
+    let location = match (line, column) {
+        (Some(l), Some(c)) => format!("{path_str}:{l}:{c}"),
+        (Some(l), None) => format!("{path_str}:{l}"),
+        _ => path_str.to_string(),
+    };
+
     let mut args: Vec<String> = Vec::new();
 
     match editor_name.as_str() {
         "code" | "cursor" => {
             args.push("--reuse-window".to_string());
-            if let Some(l) = line {
+            if line.is_some() {
                 args.push("--goto".to_string());
-                args.push(format!("{path_str}:{l}"));
-            } else {
-                args.push(path_str.to_string());
             }
+            args.push(location);
         }
         "zed" | "zeditor" => {
             // -a adds the file to the currently focused workspace
             args.push("-a".to_string());
-            if let Some(l) = line {
-                args.push(format!("{path_str}:{l}"));
-            } else {
-                args.push(path_str.to_string());
-            }
+            args.push(location);
         }
         "subl" => {
+            args.push(location);
+        }
+        "idea" | "goland" | "pycharm" | "webstorm" | "rustrover" => {
+            // JetBrains launchers take the position as flags before the path
             if let Some(l) = line {
-                args.push(format!("{path_str}:{l}"));
-            } else {
-                args.push(path_str.to_string());
+                args.push("--line".to_string());
+                args.push(l.to_string());
+                if let Some(c) = column {
+                    args.push("--column".to_string());
+                    args.push(c.to_string());
+                }
             }
+            args.push(path_str.to_string());
         }
         _ => {
             args.push(path_str.to_string());
@@ -422,6 +852,40 @@ fn open_in_editor(file: String, line: Option<u32>, project_path: Option<String>)
     Ok(())
 }
 
+/// Run an editor command template, substituting `{file}`, `{line}` and
+/// `{column}` (line and column default to 1). Without a `{file}`
+/// placeholder the path is appended as the last argument.
+fn spawn_editor_template(
+    template: &str,
+    path: &str,
+    line: Option<u32>,
+    column: Option<u32>,
+) -> Result<(), String> {
+    let line = line.unwrap_or(1).to_string();
+    let column = column.unwrap_or(1).to_string();
+    let mut parts = template.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| "Editor command is empty".to_string())?;
+    let mut args: Vec<String> = parts
+        .map(|p| {
+            p.replace("{file}", path)
+                .replace("{line}", &line)
+                .replace("{column}", &column)
+        })
+        .collect();
+    if !template.contains("{file}") {
+        args.push(path.to_string());
+    }
+
+    std::process::Command::new(program)
+        .args(&args)
+        .stdin(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to launch {program}: {e}"))?;
+    Ok(())
+}
+
 #[tauri::command]
 /// Check if a project has .mcp.json with a scryer entry.
 fn check_mcp_json(project_path: &str) -> bool {
@@ -441,6 +905,7 @@ const SCRYER_READ_TOOLS: &[&str] = &[
     "mcp__scryer__get_rules",
     "mcp__scryer__get_changes",
     "mcp__scryer__get_structure",
+    "mcp__scryer__get_source_map",
 ];
 
 /// Check if Claude Code has auto-approved scryer read tools in project settings.
@@ -633,7 +1098,7 @@ fn drift_baseline(model_ref: &scryer_core::ModelRef) -> Result<std::time::System
         }
     }
     // No sync marker yet — initialize from model file mtime
-    let scry_path = model_ref.model_path();
+    let scry_path = model_ref.stored_path();
     let model_mtime = std::fs::metadata(&scry_path)
         .and_then(|m| m.modified())
         .map_err(|e| e.to_string())?;
@@ -787,11 +1252,15 @@ fn create_blank_model(name: String, project_path: String) -> Result<String, Stri
         nodes: vec![],
         edges: vec![],
         starting_level: None,
+        starting_node: None,
         source_map: Default::default(),
         project_path: Some(project_path),
         ref_positions: Default::default(),
         groups: vec![],
         flows: vec![],
+        limits: None,
+        created_at: None,
+        updated_at: None,
     };
     scryer_core::write_model_at(&model_ref, &data)?;
     if let scryer_core::ModelRef::ProjectLocal(ref path) = model_ref {
@@ -998,15 +1467,33 @@ pub fn run() {
     #[cfg(target_os = "macos")]
     ensure_full_path();
 
-    let settings = scryer_core::read_settings();
+    // Diagnostics from scryer-suggest and scryer-acp; `SCRYER_LOG=debug`
+    // includes raw LLM output
+    let filter = tracing_subscriber::EnvFilter::try_from_env("SCRYER_LOG")
+        .or_else(|_| tracing_subscriber::EnvFilter::try_from_default_env())
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+
+    // A broken settings file starts the app with defaults, but the error is
+    // kept so the UI can say so instead of looking unconfigured.
+    let (settings, load_error) = match scryer_core::read_settings_result() {
+        Ok(settings) => (settings, None),
+        Err(e) => (scryer_core::AiSettings::default(), Some(e.to_string())),
+    };
     let settings_state = Arc::new(Mutex::new(settings));
 
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
         .manage(SettingsState(settings_state))
+        .manage(SettingsError(Arc::new(Mutex::new(load_error))))
         .manage(AcpState(Mutex::new(None)))
         .manage(SyncSnapshot(Mutex::new(None)))
+        .manage(SelfWrites::default())
+        .manage(ModelCache::default())
         .setup(move |app| {
             let handle = app.handle().clone();
             let dir = scryer_core::models_dir();
@@ -1014,21 +1501,22 @@ pub fn run() {
 
             // Track known model names so we can detect genuinely new models.
             // On Windows, atomic rename (temp + rename) fires Remove + Create instead
-            // of Modify. We intentionally keep names in the set on Remove so that a
-            // subsequent Create from an atomic rename is treated as a change, not a
-            // new model. True deletions are handled by list refresh in the frontend.
+            // of Modify, so a Remove only counts as a deletion if the file is
+            // actually gone when the event is handled.
             let mut known_models: HashSet<String> = std::fs::read_dir(&dir)
                 .into_iter()
                 .flatten()
                 .filter_map(|e| e.ok())
                 .filter_map(|e| {
-                    let p = e.path();
-                    if p.extension().map_or(true, |x| x != "scry") { return None; }
-                    let stem = p.file_stem()?.to_str()?;
-                    if stem.ends_with(".baseline") { return None; }
-                    Some(stem.to_string())
+                    let name = e.file_name().to_string_lossy().to_string();
+                    scryer_core::model_file_stem(&name).map(|n| n.to_string())
                 })
                 .collect();
+            let debouncer = Debouncer::default();
+            let self_writes = app.state::<SelfWrites>().inner().clone();
+            let cache = app.state::<ModelCache>().inner().clone();
+            let settings = app.state::<SettingsState>().0.clone();
+            let settings_error = app.state::<SettingsError>().0.clone();
 
             let mut global_watcher = recommended_watcher(move |res: Result<notify::Event, notify::Error>| {
                 let Ok(event) = res else { return };
@@ -1039,26 +1527,68 @@ pub fn run() {
                     return;
                 }
                 for path in &event.paths {
-                    if path.extension().map_or(true, |e| e != "scry") {
+                    // settings.json edited outside the app (or by another window)
+                    if path.file_name().is_some_and(|n| n == SETTINGS_KEY) {
+                        if matches!(event.kind, EventKind::Remove(_)) {
+                            continue;
+                        }
+                        let handle = handle.clone();
+                        let self_writes = self_writes.clone();
+                        let settings = settings.clone();
+                        let settings_error = settings_error.clone();
+                        let path = path.clone();
+                        debouncer.trigger(SETTINGS_KEY.to_string(), move || {
+                            if self_writes.is_own_write(SETTINGS_KEY, &path) {
+                                return;
+                            }
+                            // Keep the last good settings if the new file is broken
+                            match scryer_core::read_settings_result() {
+                                Ok(s) => {
+                                    *settings.lock().unwrap() = s;
+                                    *settings_error.lock().unwrap() = None;
+                                }
+                                Err(e) => *settings_error.lock().unwrap() = Some(e.to_string()),
+                            }
+                            let _ = handle.emit("settings-changed", ());
+                        });
                         continue;
                     }
-                    let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                    let Some(name) = path
+                        .file_name()
+                        .and_then(|s| s.to_str())
+                        .and_then(scryer_core::model_file_stem)
+                    else {
                         continue;
                     };
-                    if name.ends_with(".baseline") {
-                        continue;
-                    }
-                    // Skip Remove events — don't clear from known_models so that
-                    // Windows atomic rename (Remove + Create) won't falsely emit
-                    // model-created. The frontend refreshes the list to detect
-                    // true deletions.
                     if matches!(event.kind, EventKind::Remove(_)) {
+                        // Atomic rename on Windows re-creates the file immediately,
+                        // and switching to or from gzip removes the old variant
+                        if scryer_core::ModelRef::Global(name.to_string())
+                            .stored_path()
+                            .exists()
+                        {
+                            continue;
+                        }
+                        cache.invalidate(name);
+                        if known_models.remove(name) {
+                            let _ = handle.emit("model-deleted", name.to_string());
+                        }
                         continue;
                     }
                     if known_models.insert(name.to_string()) {
                         let _ = handle.emit("model-created", name.to_string());
                     }
-                    let _ = handle.emit("model-changed", name.to_string());
+                    let handle = handle.clone();
+                    let name = name.to_string();
+                    let self_writes = self_writes.clone();
+                    let cache = cache.clone();
+                    let path = path.clone();
+                    debouncer.trigger(name.clone(), move || {
+                        if !self_writes.is_own_write(&name, &path) {
+                            cache.invalidate(&name);
+                            let _ = handle.emit("model-changed", name);
+                        }
+                    });
                 }
             })
             .map_err(|e| e.to_string())?;
@@ -1081,15 +1611,27 @@ pub fn run() {
             is_codebase,
             rename_template,
             read_model,
+            get_recent_models,
+            search_models,
             write_model,
+            duplicate_model,
+            backup_models,
+            restore_models,
             delete_model,
+            export_svg,
             get_hints,
+            lint_model,
+            test_ai_connection,
             fetch_models,
             list_templates,
+            list_templates_meta,
             load_template,
+            create_from_template,
             get_ai_settings,
             save_ai_settings,
+            save_editor_command,
             open_in_editor,
+            open_models_dir,
             detect_ai_tools,
             setup_mcp_integration,
             check_drift,
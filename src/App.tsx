@@ -17,7 +17,8 @@ import { ContextPanel } from "./ContextPanel";
 import { SettingsPanel } from "./SettingsPanel";
 import { loadTheme, ThemeContext } from "./theme";
 import { CommandPalette } from "./CommandPalette";
-import { FlowScriptView } from "./FlowScriptView";
+import { FlowScriptView, renameStepMentions } from "./FlowScriptView";
+import { replaceMention } from "./MentionTextarea";
 import { GroupsDndProvider, GroupsMain } from "./GroupsView";
 import { C4Canvas } from "./C4Canvas";
 import { SyncBar } from "./SyncBar";
@@ -670,7 +671,7 @@ function Flow() {
     // Check if project already has a model
     const refStr = `project:${selected}`;
     try {
-      await invoke<string>("read_model", { name: refStr });
+      await invoke("read_model", { name: refStr });
       // Model exists — just load it
       storage.loadModel(refStr);
       return;
@@ -1205,6 +1206,38 @@ function Flow() {
               setNodes((nds) => nds.map((n) =>
                 n.id === hint.nodeId ? { ...n, data: { ...n.data, external: (hint.action as { type: "setExternal"; value: boolean }).value } } : n,
               ) as C4Node[]);
+            } else if (hint.action?.type === "rename") {
+              const name = hint.action.name;
+              const oldName = (nodes.find((n) => n.id === hint.nodeId)?.data as C4NodeData | undefined)?.name;
+              if (oldName === undefined) return;
+              // Carry @[Old Name] mentions over to the new name
+              setNodes((nds) => nds.map((n) => {
+                const data = n.data as C4NodeData;
+                const description = replaceMention(data.description, oldName, name);
+                if (n.id === hint.nodeId) return { ...n, data: { ...data, name, description } };
+                return description === data.description ? n : { ...n, data: { ...data, description } };
+              }) as C4Node[]);
+              setFlows((fls) => fls.map((f) => ({
+                ...f,
+                description: f.description && replaceMention(f.description, oldName, name),
+                steps: renameStepMentions(f.steps, oldName, name),
+              })));
+            } else if (hint.action?.type === "reverseEdge") {
+              const edgeId = hint.action.edgeId;
+              setEdges((eds) => {
+                const edge = eds.find((e) => e.id === edgeId);
+                if (!edge) return eds;
+                // Re-key for the new direction (edge-{source}-{target}, as the
+                // MCP server names edges) so a later edge the old way round
+                // doesn't collide with it
+                const base = `edge-${edge.target}-${edge.source}`;
+                const taken = new Set(eds.map((e) => e.id));
+                let id = base;
+                for (let n = 2; taken.has(id); n++) id = `${base}-${n}`;
+                return eds.map((e) =>
+                  e.id === edgeId ? { ...e, id, source: e.target, target: e.source } : e,
+                ) as C4Edge[];
+              });
             }
           }}
           onDismissHint={advisor.dismissHint}
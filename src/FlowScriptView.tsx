@@ -1,7 +1,7 @@
 import { useCallback, useMemo, useRef, useState } from "react";
 import { Plus, Trash2, GitBranch, GripVertical, FileText } from "lucide-react";
 import { invoke } from "@tauri-apps/api/core";
-import { MentionTextarea, type MentionItem } from "./MentionTextarea";
+import { MentionTextarea, replaceMention, type MentionItem } from "./MentionTextarea";
 import { DescriptionText, type MentionNodeInfo } from "./DescriptionText";
 import type {
   C4Node,
@@ -66,6 +66,18 @@ export function countAllSteps(steps: FlowStep[]): number {
   return count;
 }
 
+/** Rewrite `@[oldName]` mentions in step text and branch conditions, recursively */
+export function renameStepMentions(steps: FlowStep[], oldName: string, newName: string): FlowStep[] {
+  return steps.map((step) => ({
+    ...step,
+    description: step.description && replaceMention(step.description, oldName, newName),
+    branches: step.branches?.map((b) => ({
+      condition: replaceMention(b.condition, oldName, newName),
+      steps: renameStepMentions(b.steps, oldName, newName),
+    })),
+  }));
+}
+
 function computeNumbering(
   steps: FlowStep[],
   prefix: string,
@@ -378,7 +378,10 @@ function GroupCard({
   return (
     <div
       ref={setDropRef}
-      style={{ marginLeft: depth > 0 ? 16 : 0 }}
+      style={{
+        marginLeft: depth > 0 ? 16 : 0,
+        ...(group.color ? { borderLeftColor: group.color, borderLeftWidth: 3 } : {}),
+      }}
       className={`rounded-lg border transition-colors ${
         isOver && showDropCue
           ? "border-[var(--text)] bg-[var(--surface-active)]/40 ring-1 ring-[var(--text)]"
@@ -396,7 +399,11 @@ function GroupCard({
         >
           <GripVertical size={14} />
         </button>
-        <Folder size={14} className="mt-0.5 text-[var(--text-muted)] shrink-0" />
+        <Folder
+          size={14}
+          className="mt-0.5 text-[var(--text-muted)] shrink-0"
+          style={group.color ? { color: group.color } : undefined}
+        />
         <div className="flex-1 min-w-0">
           <input
             className="w-full bg-transparent outline-none text-sm font-semibold text-[var(--text)] placeholder-[var(--text-muted)]"
@@ -6,7 +6,7 @@ export interface MentionItem {
   /** If set, this value is inserted into text instead of name. Used for step IDs. */
   insertValue?: string;
   kind: "person" | "system" | "container" | "component" | "operation" | "process" | "model" | "step";
-  status?: "proposed" | "implemented" | "verified" | "vagrant";
+  status?: "proposed" | "in_progress" | "implemented" | "verified" | "vagrant";
   ref?: boolean;
 }
 
@@ -22,6 +22,11 @@ interface MentionTextareaProps {
   autoFocus?: boolean;
 }
 
+/** Rewrite every `@[oldName]` mention in `text` to `@[newName]` */
+export function replaceMention(text: string, oldName: string, newName: string): string {
+  return text.split(`@[${oldName}]`).join(`@[${newName}]`);
+}
+
 export function MentionTextarea({ value, onChange, mentionNames, placeholder, rows = 3, autoSize, className, maxLength, autoFocus }: MentionTextareaProps) {
   const textareaRef = useRef<HTMLTextAreaElement>(null);
   const [localValue, setLocalValue] = useState(value);
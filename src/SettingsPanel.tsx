@@ -31,9 +31,11 @@ type AiSettingsResponse = {
   model: string;
   hasKey: boolean;
   configured: boolean;
+  editorCommand?: string | null;
+  loadError?: string | null;
 };
 
-type SettingsTab = "ai" | "theme";
+type SettingsTab = "ai" | "editor" | "theme";
 
 // ── Theme sub-components ──
 
@@ -146,6 +148,17 @@ function AiReviewTab({
   const [modelList, setModelList] = useState<string[]>([]);
   const [modelsLoading, setModelsLoading] = useState(false);
   const [modelsError, setModelsError] = useState<string | null>(null);
+  const [testing, setTesting] = useState(false);
+  const [testResult, setTestResult] = useState<{ ok: boolean; message: string } | null>(null);
+
+  const testConnection = useCallback(() => {
+    setTesting(true);
+    setTestResult(null);
+    invoke("test_ai_connection", { provider, apiKey: apiKey || null, model })
+      .then(() => setTestResult({ ok: true, message: "Connection OK" }))
+      .catch((e) => setTestResult({ ok: false, message: String(e) }))
+      .finally(() => setTesting(false));
+  }, [provider, apiKey, model]);
 
   const loadModels = useCallback((prov: string, key?: string) => {
     const p = PROVIDERS.find((p) => p.value === prov);
@@ -231,8 +244,56 @@ function AiReviewTab({
         {modelsError && <p className="text-[10px] text-amber-500 mt-0.5">{modelsError}</p>}
       </div>
       {error && <p className="text-xs text-red-500">{error}</p>}
-      <Button variant={saved ? "ghost" : "primary"} size="md" disabled={saving || !model} onClick={onSave}>
-        {saving ? "Saving..." : saved ? "Saved" : "Save"}
+      {testResult && (
+        <p className={`text-xs ${testResult.ok ? "text-green-600 dark:text-green-400" : "text-red-500"}`}>
+          {testResult.message}
+        </p>
+      )}
+      <div className="flex gap-2">
+        <Button variant="ghost" size="md" disabled={testing || !model} onClick={testConnection}>
+          {testing ? "Testing..." : "Test connection"}
+        </Button>
+        <Button variant={saved ? "ghost" : "primary"} size="md" className="flex-1" disabled={saving || !model} onClick={onSave}>
+          {saving ? "Saving..." : saved ? "Saved" : "Save"}
+        </Button>
+      </div>
+    </div>
+  );
+}
+
+function EditorTab({ initial }: { initial: string }) {
+  const [command, setCommand] = useState(initial);
+  const [saved, setSaved] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => setCommand(initial), [initial]);
+
+  const save = useCallback(() => {
+    setError(null);
+    invoke("save_editor_command", { command })
+      .then(() => {
+        setSaved(true);
+        setTimeout(() => setSaved(false), 2000);
+      })
+      .catch((e) => setError(String(e)));
+  }, [command]);
+
+  return (
+    <div className="flex flex-col gap-3">
+      <p className="text-xs text-[var(--text-tertiary)]">
+        Command used to open source files. Use <code>{"{file}"}</code>, <code>{"{line}"}</code> and <code>{"{column}"}</code> as placeholders. Leave blank to auto-detect from $VISUAL, $EDITOR, or installed editors.
+      </p>
+      <div>
+        <label className="text-[10px] uppercase tracking-wider text-[var(--text-muted)]">Editor command</label>
+        <Input
+          value={command}
+          placeholder="emacsclient +{line} {file}"
+          onChange={(e) => setCommand(e.target.value)}
+        />
+      </div>
+      {error && <p className="text-xs text-red-500">{error}</p>}
+      <Button variant={saved ? "ghost" : "primary"} size="md" onClick={save}>
+        {saved ? "Saved" : "Save"}
       </Button>
     </div>
   );
@@ -357,6 +418,7 @@ function ThemeTab({
 
 const TABS: { id: SettingsTab; label: string }[] = [
   { id: "ai", label: "AI Review" },
+  { id: "editor", label: "Editor" },
   { id: "theme", label: "Theme" },
 ];
 
@@ -381,11 +443,14 @@ export function SettingsPanel({
   const [saving, setSaving] = useState(false);
   const [saved, setSaved] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [editorCommand, setEditorCommand] = useState("");
   useEffect(() => {
     invoke<AiSettingsResponse>("get_ai_settings").then((s) => {
       setProvider(s.provider || "openai");
       setModel(s.model || "gpt-5-nano");
       setHasExistingKey(s.hasKey);
+      setEditorCommand(s.editorCommand ?? "");
+      if (s.loadError) setError(`Settings file is broken — saving will replace it. ${s.loadError}`);
     }).catch(() => {});
   }, []);
 
@@ -457,6 +522,11 @@ export function SettingsPanel({
             />
           </div>
         )}
+        {tab === "editor" && (
+          <div className="p-4">
+            <EditorTab initial={editorCommand} />
+          </div>
+        )}
         {tab === "theme" && (
           <div className="flex-1 overflow-y-auto p-4">
             <ThemeTab theme={theme} onThemeChange={onThemeChange} />
@@ -1,8 +1,8 @@
 import { useCallback, useEffect, useRef, useState } from "react";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { invoke } from "@tauri-apps/api/core";
-import { open as openDialog } from "@tauri-apps/plugin-dialog";
-import { Minus, Square, X, Settings, Keyboard, FolderX, Menu, FolderOpen, Save } from "lucide-react";
+import { open as openDialog, save as saveDialog, ask, message } from "@tauri-apps/plugin-dialog";
+import { Minus, Square, X, Settings, Keyboard, FolderX, Menu, FolderOpen, Save, Archive, ArchiveRestore } from "lucide-react";
 import type { C4Kind, AiToolsState } from "./types";
 
 interface TopBarProps {
@@ -198,6 +198,39 @@ function AppMenu({ onClose, onOpenSettings, onOpenPalette, onCloseModel, onSaveA
     return () => window.removeEventListener("keydown", handler);
   }, [onClose]);
 
+  const backupModels = async () => {
+    onClose();
+    const path = await saveDialog({ title: "Back up models", defaultPath: "scryer-backup.zip", filters: [{ name: "Zip archive", extensions: ["zip"] }] });
+    if (!path) return;
+    try {
+      const count = await invoke<number>("backup_models", { path, includeBaselines: false });
+      await message(`Backed up ${count} model(s).`, { title: "Backup complete" });
+    } catch (e) {
+      await message(String(e), { title: "Backup failed", kind: "error" });
+    }
+  };
+
+  const restoreModels = async () => {
+    onClose();
+    const path = await openDialog({ title: "Restore models", filters: [{ name: "Zip archive", extensions: ["zip"] }] });
+    if (!path) return;
+    try {
+      let report = await invoke<{ restored: string[]; skipped: string[] }>("restore_models", { path, overwrite: false });
+      if (report.skipped.length > 0) {
+        const overwrite = await ask(
+          `${report.skipped.length} model(s) already exist: ${report.skipped.join(", ")}. Overwrite them?`,
+          { title: "Models already exist", kind: "warning" },
+        );
+        if (overwrite) {
+          report = await invoke<{ restored: string[]; skipped: string[] }>("restore_models", { path, overwrite: true });
+        }
+      }
+      await message(`Restored ${report.restored.length} model(s).`, { title: "Restore complete" });
+    } catch (e) {
+      await message(String(e), { title: "Restore failed", kind: "error" });
+    }
+  };
+
   const items: { label: string; icon: typeof Settings; shortcut?: string; onClick: () => void; disabled?: boolean; active?: boolean }[] = [
     { label: "Open model", icon: Keyboard, shortcut: "Ctrl+K", onClick: () => { onOpenPalette(); onClose(); } },
     { label: "Save as\u2026", icon: Save, onClick: () => {
@@ -209,6 +242,9 @@ function AppMenu({ onClose, onOpenSettings, onOpenPalette, onCloseModel, onSaveA
       onClose();
     }, disabled: !canSaveAs },
     { label: "Close model", icon: FolderX, onClick: () => { onCloseModel(); onClose(); }, disabled: !hasModel },
+    { label: "Open models folder", icon: FolderOpen, onClick: () => { invoke("open_models_dir").catch(() => {}); onClose(); } },
+    { label: "Back up models\u2026", icon: Archive, onClick: backupModels },
+    { label: "Restore models\u2026", icon: ArchiveRestore, onClick: restoreModels },
     { label: "Settings", icon: Settings, onClick: () => { onOpenSettings(); onClose(); } },
   ];
 
@@ -9,6 +9,7 @@ import {
 
 const STATUS_OPTIONS: { value: Status; label: string }[] = [
   { value: "proposed", label: "proposed" },
+  { value: "in_progress", label: "in progress" },
   { value: "implemented", label: "implemented" },
   { value: "verified", label: "verified" },
   { value: "vagrant", label: "vagrant" },
@@ -293,6 +294,13 @@ function PropertiesField({ node, indent, last }: { node: C4Node; indent: number;
                 placeholder="propertyName"
               />
             </Field>
+            <Field name="type" indent={indent + 2}>
+              <StrEdit
+                value={p.type ?? ""}
+                onChange={(v) => update(props.map((x, j) => j === i ? { ...x, type: v || undefined } : x))}
+                placeholder="String"
+              />
+            </Field>
             <Field name="description" indent={indent + 2} last>
               <StrEdit
                 value={p.description}
@@ -4,6 +4,7 @@ import { STATUS_COLORS } from "../statusColors";
 const STATUS_OPTIONS: { value: Status | undefined; label: string }[] = [
   { value: undefined, label: "None" },
   { value: "proposed", label: "Proposed" },
+  { value: "in_progress", label: "In progress" },
   { value: "implemented", label: "Implemented" },
   { value: "verified", label: "Verified" },
   { value: "vagrant", label: "Vagrant" },
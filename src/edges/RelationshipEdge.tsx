@@ -6,6 +6,7 @@ import { getThemedHex, ThemeContext } from "../theme";
 
 const STATUS_PRIORITY: Record<Status, number> = {
   proposed: 4,
+  in_progress: 3,
   implemented: 3,
   verified: 1,
   vagrant: 2,
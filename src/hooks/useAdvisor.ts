@@ -1,5 +1,6 @@
 import { useCallback, useEffect, useMemo, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import type { C4ModelData, C4Node, C4Edge, StartingLevel, SourceLocation, Hint } from "../types";
 
 interface UseAdvisorParams {
@@ -11,6 +12,7 @@ interface UseAdvisorParams {
 
 export function useAdvisor({ nodes, edges, startingLevel, sourceMap }: UseAdvisorParams) {
   const [hints, setHints] = useState<Record<string, Hint[]>>({});
+  const [lintHints, setLintHints] = useState<Hint[]>([]);
   const [hintLoading, setHintLoading] = useState(false);
   const [dismissedHints, setDismissedHints] = useState<Set<string>>(new Set());
 
@@ -18,11 +20,16 @@ export function useAdvisor({ nodes, edges, startingLevel, sourceMap }: UseAdviso
   const [aiEnabled, setAiEnabled] = useState(() => localStorage.getItem("scryer:aiEnabled") !== "false");
 
 
-  // Check AI settings on mount
+  // Check AI settings on mount, and again whenever settings.json changes on disk
   useEffect(() => {
-    invoke<{ configured: boolean }>("get_ai_settings")
-      .then((s) => setAiConfigured(s.configured))
-      .catch(() => setAiConfigured(false));
+    const check = () => {
+      invoke<{ configured: boolean }>("get_ai_settings")
+        .then((s) => setAiConfigured(s.configured))
+        .catch(() => setAiConfigured(false));
+    };
+    check();
+    const unlisten = listen("settings-changed", check);
+    return () => { unlisten.then((fn) => fn()); };
   }, []);
 
   // Persist AI enabled toggle
@@ -30,6 +37,21 @@ export function useAdvisor({ nodes, edges, startingLevel, sourceMap }: UseAdviso
     localStorage.setItem("scryer:aiEnabled", String(aiEnabled));
   }, [aiEnabled]);
 
+  // Offline lint needs no AI, so it runs on every edit (debounced)
+  useEffect(() => {
+    if (nodes.length === 0) {
+      setLintHints([]);
+      return;
+    }
+    const timer = setTimeout(() => {
+      const modelData: C4ModelData = { nodes, edges, startingLevel, sourceMap };
+      invoke<string>("lint_model", { data: JSON.stringify(modelData) })
+        .then((raw) => setLintHints(JSON.parse(raw)))
+        .catch(() => setLintHints([]));
+    }, 500);
+    return () => clearTimeout(timer);
+  }, [nodes, edges, startingLevel, sourceMap]);
+
   // Manually trigger AI review
   const fetchHints = useCallback(async () => {
     if (nodes.length === 0 || !aiConfigured || !aiEnabled) return;
@@ -49,16 +71,15 @@ export function useAdvisor({ nodes, edges, startingLevel, sourceMap }: UseAdviso
     setHintLoading(false);
   }, [nodes, edges, startingLevel, sourceMap, aiConfigured, aiEnabled]);
 
-  // Filter out dismissed hints
+  // Merge AI and lint hints, filtering out dismissed ones
   const activeHints = useMemo(() => {
-    if (dismissedHints.size === 0) return hints;
-    const filtered: Record<string, Hint[]> = {};
-    for (const [nodeId, nodeHints] of Object.entries(hints)) {
-      const kept = nodeHints.filter((h) => !dismissedHints.has(`${h.nodeId}:${h.message}`));
-      if (kept.length > 0) filtered[nodeId] = kept;
+    const merged: Record<string, Hint[]> = {};
+    for (const h of [...Object.values(hints).flat(), ...lintHints]) {
+      if (dismissedHints.has(`${h.nodeId}:${h.message}`)) continue;
+      (merged[h.nodeId] ??= []).push(h);
     }
-    return filtered;
-  }, [hints, dismissedHints]);
+    return merged;
+  }, [hints, lintHints, dismissedHints]);
 
   const dismissHint = useCallback((hint: Hint) => {
     setDismissedHints((prev) => new Set(prev).add(`${hint.nodeId}:${hint.message}`));
@@ -1,9 +1,21 @@
 import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import type { C4ModelData, C4Node, C4NodeData, C4Edge, StartingLevel, SourceLocation, Group, Contract, ContractItem, Flow, FlowStep, FlowTransition } from "../types";
+import type { C4ModelData, C4Node, C4NodeData, C4Edge, StartingLevel, SourceLocation, Group, Contract, ContractItem, Flow, FlowStep, FlowTransition, ModelLimits } from "../types";
 import { useToast } from "../Toast";
 
+/** Drill-down path (ancestor IDs, root first) to the view that shows `id`. */
+function pathToNode(nodes: C4Node[], id: string): string[] {
+  const byId = new Map(nodes.map((n) => [n.id, n]));
+  const path: string[] = [];
+  let cur = byId.get(id)?.parentId;
+  while (cur && !path.includes(cur)) {
+    path.unshift(cur);
+    cur = byId.get(cur)?.parentId;
+  }
+  return path;
+}
+
 /** Migrate old guidelines/string contract fields to ContractItem[] contract fields. */
 function migrateContract(raw: unknown): Contract {
   const empty: Contract = { expect: [], ask: [], never: [] };
@@ -77,7 +89,7 @@ export function parseModelData(raw: string): C4ModelData {
     // Migrate references→sources
     const sources = nodeData.sources ?? nodeData.references;
     // Strip invalid/old status values (e.g. "implemented", "changed", "deprecated" from older models)
-    const VALID_STATUSES = new Set(["proposed", "implemented", "verified", "vagrant"]);
+    const VALID_STATUSES = new Set(["proposed", "in_progress", "implemented", "verified", "vagrant"]);
     if (nodeData.status && !VALID_STATUSES.has(nodeData.status as string)) {
       nodeData.status = undefined;
     }
@@ -106,6 +118,7 @@ export function parseModelData(raw: string): C4ModelData {
     nodes,
     edges,
     startingLevel: data.startingLevel ?? "system",
+    startingNode: data.startingNode,
     sourceMap: data.sourceMap ?? {},
     projectPath: data.projectPath,
     refPositions: data.refPositions ?? {},
@@ -122,6 +135,9 @@ export function parseModelData(raw: string): C4ModelData {
         transitions: undefined,
       } as unknown as Flow;
     }),
+    limits: data.limits,
+    createdAt: data.createdAt,
+    updatedAt: data.updatedAt,
   };
 }
 
@@ -172,6 +188,10 @@ export function useModelStorage(
   const skipSave = useRef(false);
   const reloadTimer = useRef<ReturnType<typeof setTimeout>>(null);
   const lastKnownDisk = useRef<string>(""); // last JSON string we wrote or loaded from disk
+  const diskEtag = useRef<{ name: string; etag: string } | null>(null); // etag of that file, so saves don't clobber external changes
+  const createdAt = useRef<string | undefined>(undefined); // carried through saves so it isn't dropped
+  const limits = useRef<ModelLimits | undefined>(undefined); // same — not editable in the UI
+  const startingNode = useRef<string | undefined>(undefined); // same — set through MCP
   const [changedNodeIds, setChangedNodeIds] = useState<Set<string>>(new Set());
   const changeClearTimer = useRef<ReturnType<typeof setTimeout>>(null);
   // Diff tracking: when external (drift/MCP) reload changes existing nodes,
@@ -191,6 +211,8 @@ export function useModelStorage(
   nodesRef.current = nodes;
   const edgesRef = useRef(edges);
   edgesRef.current = edges;
+  // Set once resolveConflict is defined below — the save effect runs first
+  const resolveConflictRef = useRef<(name: string, json: string) => void>(() => {});
 
   // "Follow AI" — auto-navigate to where the AI made changes
   const [followAI, setFollowAIState] = useState(() => {
@@ -241,10 +263,16 @@ export function useModelStorage(
         const { _needsLayout, ...data } = n.data;
         return { ...n, data };
       });
-      const data: C4ModelData = { nodes: cleanNodes as C4Node[], edges: currentEdges, startingLevel, sourceMap, projectPath, refPositions, groups, flows };
+      const data: C4ModelData = { nodes: cleanNodes as C4Node[], edges: currentEdges, startingLevel, sourceMap, projectPath, refPositions, groups, flows, startingNode: startingNode.current, limits: limits.current, createdAt: createdAt.current ?? new Date().toISOString(), updatedAt: new Date().toISOString() };
       const json = JSON.stringify(data);
       lastKnownDisk.current = json;
-      invoke("write_model", { name: currentModel, data: json }).catch(() => toast("Failed to save model"));
+      const expectedEtag = diskEtag.current?.name === currentModel ? diskEtag.current.etag : null;
+      invoke<string>("write_model", { name: currentModel, data: json, expectedEtag })
+        .then((etag) => { diskEtag.current = { name: currentModel, etag }; })
+        .catch((e) => {
+          if (String(e).startsWith("Conflict")) resolveConflictRef.current(currentModel, json);
+          else toast("Failed to save model");
+        });
     }, 500);
     return () => {
       if (saveTimer.current) clearTimeout(saveTimer.current);
@@ -296,18 +324,22 @@ export function useModelStorage(
     setProjectPath(data.projectPath);
     setGroups(data.groups ?? []);
     setFlows(data.flows ?? []);
+    createdAt.current = data.createdAt;
+    limits.current = data.limits;
+    startingNode.current = data.startingNode;
   }, [setNodes, setEdges, setStartingLevel, setSourceMap, setProjectPath, setGroups, setFlows]);
 
   const loadModel = useCallback(async (name: string) => {
     try {
       // Auto-migrate global models with project_path to project-local
       const refStr = await invoke<string>("try_migrate_model", { name });
-      const raw = await invoke<string>("read_model", { name: refStr });
+      const { data: raw, etag } = await invoke<{ data: string; etag: string }>("read_model", { name: refStr });
       lastKnownDisk.current = raw;
+      diskEtag.current = { name: refStr, etag };
       const data = parseModelData(raw);
       applyModelData(data);
       setCurrentModel(refStr);
-      setExpandedPath([]);
+      setExpandedPath(data.startingNode ? pathToNode(data.nodes, data.startingNode) : []);
       setActiveFlowId(null);
       setRefPositions(data.refPositions ?? {});
       clearAllNodeDiffs(); // diffs from prior model are no longer relevant
@@ -320,7 +352,8 @@ export function useModelStorage(
 
   const reloadModel = useCallback(async (name: string) => {
     try {
-      const raw = await invoke<string>("read_model", { name });
+      const { data: raw, etag } = await invoke<{ data: string; etag: string }>("read_model", { name });
+      diskEtag.current = { name, etag };
       if (raw === lastKnownDisk.current) return;
       lastKnownDisk.current = raw;
       const data = parseModelData(raw);
@@ -495,6 +528,30 @@ export function useModelStorage(
     }
   }, [applyModelData, setRefPositions, setExpandedPath, setActiveFlowId, scheduleFitView]);
 
+  // A save was refused because the file changed on disk since we read it
+  // (typically an agent write). `json` is the refused local copy; it's kept
+  // until the user decides, even if a watcher reload replaces the canvas.
+  const resolveConflict = useCallback(async (name: string, json: string) => {
+    const { ask } = await import("@tauri-apps/plugin-dialog");
+    const keepMine = await ask(
+      "This model was changed on disk since you loaded it. Keep your version (overwriting those changes) or load the version on disk?",
+      { title: "Model changed on disk", kind: "warning", okLabel: "Keep mine", cancelLabel: "Load from disk" },
+    );
+    if (!keepMine) {
+      await reloadModel(name);
+      return;
+    }
+    try {
+      lastKnownDisk.current = json;
+      const etag = await invoke<string>("write_model", { name, data: json });
+      diskEtag.current = { name, etag };
+      applyModelData(parseModelData(json), true);
+    } catch {
+      toast("Failed to save model");
+    }
+  }, [reloadModel, applyModelData, toast]);
+  resolveConflictRef.current = resolveConflict;
+
   const deleteModel = useCallback(async (name: string) => {
     await invoke("delete_model", { name }).catch(() => toast("Failed to delete model"));
     if (currentModel === name) {
@@ -565,7 +622,9 @@ export function useModelStorage(
 
   const saveModelAs = useCallback(async (name: string) => {
     const data: C4ModelData = { nodes, edges, startingLevel, sourceMap, refPositions, groups, flows };
-    await invoke("write_model", { name, data: JSON.stringify(data) }).catch(() => toast("Failed to save model"));
+    await invoke<string>("write_model", { name, data: JSON.stringify(data) })
+      .then((etag) => { diskEtag.current = { name, etag }; })
+      .catch(() => toast("Failed to save model"));
     setCurrentModel(name);
     await refreshList();
   }, [nodes, edges, startingLevel, sourceMap, refPositions, groups, flows, refreshList, setCurrentModel]);
@@ -580,7 +639,7 @@ export function useModelStorage(
   }, [currentModel]);
 
   // File watcher: reload when external tools (MCP, etc.) modify model files.
-  // Handles both model-created and model-changed events.
+  // Handles model-created and model-changed events; model-deleted closes the model.
   // On Windows, atomic rename (write_model_raw) fires Remove + Create instead of
   // Modify, producing both events — the debounce collapses them into a single reload.
   // Self-writes are caught by the lastKnownDisk comparison in reloadModel.
@@ -596,12 +655,18 @@ export function useModelStorage(
     };
     const unlistenCreated = listen<string>("model-created", (e) => handler(e.payload));
     const unlistenChanged = listen<string>("model-changed", (e) => handler(e.payload));
+    // Deleted externally: close it instead of trying to reload a missing file
+    const unlistenDeleted = listen<string>("model-deleted", (e) => {
+      if (e.payload === currentModel) newModel();
+      refreshList();
+    });
     return () => {
       unlistenCreated.then((fn) => fn());
       unlistenChanged.then((fn) => fn());
+      unlistenDeleted.then((fn) => fn());
       if (reloadTimer.current) clearTimeout(reloadTimer.current);
     };
-  }, [currentModel, reloadModel, refreshList]);
+  }, [currentModel, reloadModel, refreshList, newModel]);
 
   return {
     loadModel,
@@ -21,6 +21,15 @@ export const STATUS_COLORS: Record<Status, {
     pillClass: "bg-blue-100 dark:bg-blue-900/50 text-blue-800 dark:text-blue-200",
     pillHoverClass: "hover:bg-blue-200 dark:hover:bg-blue-800/50",
   },
+  in_progress: {
+    strokeClass: "stroke-violet-500 dark:stroke-violet-400",
+    dimStrokeClass: "stroke-violet-500/70 dark:stroke-violet-400/40",
+    hex: "#8b5cf6",
+    label: "In progress",
+    dotClass: "bg-violet-500 dark:bg-violet-400",
+    pillClass: "bg-violet-100 dark:bg-violet-900/50 text-violet-800 dark:text-violet-200",
+    pillHoverClass: "hover:bg-violet-200 dark:hover:bg-violet-800/50",
+  },
   implemented: {
     strokeClass: "stroke-amber-500 dark:stroke-amber-400",
     dimStrokeClass: "stroke-amber-500/70 dark:stroke-amber-400/40",
@@ -53,6 +62,7 @@ export const STATUS_COLORS: Record<Status, {
 /** Map status → Tailwind color family used in the theme. */
 const STATUS_FAMILY: Record<Status, PaletteRole> = {
   proposed: "blue",
+  in_progress: "violet",
   implemented: "amber",
   verified: "emerald",
   vagrant: "red",
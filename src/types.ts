@@ -4,7 +4,7 @@ export type C4Kind = "person" | "system" | "container" | "component" | "operatio
 
 export type C4Shape = "rectangle" | "person" | "cylinder" | "pipe" | "trapezoid" | "bucket" | "hexagon";
 
-export type Status = "proposed" | "implemented" | "verified" | "vagrant";
+export type Status = "proposed" | "in_progress" | "implemented" | "verified" | "vagrant";
 
 export interface ContractImage {
   filename: string;
@@ -49,6 +49,8 @@ export type C4NodeData = {
   contract?: Contract;
   notes?: string[];
   properties?: ModelProperty[];
+  tags?: string[];
+  owner?: string;
   _reference?: boolean;
   _relationships?: { direction: "in" | "out"; label: string; method?: string }[];
   _operations?: { id: string; name: string }[];
@@ -58,6 +60,7 @@ export type C4NodeData = {
 export type C4EdgeData = {
   label: string;
   method?: string;
+  async?: boolean;
   _route?: { x: number; y: number }[];
   _bundleAngle?: number;
   [key: string]: unknown;
@@ -76,6 +79,7 @@ export type SourceLocation = {
 export interface ModelProperty {
   label: string;
   description: string;
+  type?: string;
 }
 
 export interface Group {
@@ -85,6 +89,8 @@ export interface Group {
   memberIds: string[];
   parentGroupId?: string;
   contract?: Contract;
+  /** Hex accent color, e.g. "#22c55e". */
+  color?: string;
 }
 
 export interface FlowBranch {
@@ -116,11 +122,22 @@ export interface C4ModelData {
   nodes: C4Node[];
   edges: C4Edge[];
   startingLevel?: StartingLevel;
+  /** Node to open focused on; wins over startingLevel. */
+  startingNode?: string;
   sourceMap?: Record<string, SourceLocation[]>;
   projectPath?: string;
   refPositions?: Record<string, { x: number; y: number }>;
   groups?: Group[];
   flows?: Flow[];
+  limits?: ModelLimits;
+  createdAt?: string;
+  updatedAt?: string;
+}
+
+/** Per-model size limit overrides. 0 removes a limit. */
+export interface ModelLimits {
+  maxDescription?: number;
+  maxCodeDescription?: number;
 }
 
 export type StartingLevel = "system" | "container" | "component";
@@ -135,7 +152,9 @@ export interface Hint {
 
 export type HintAction =
   | { type: "setShape"; shape: string }
-  | { type: "setExternal"; value: boolean };
+  | { type: "setExternal"; value: boolean }
+  | { type: "rename"; name: string }
+  | { type: "reverseEdge"; edgeId: string };
 
 // AI coding tool integration state
 export type AiToolsState = {